@@ -0,0 +1 @@
+ALTER TABLE servers ADD COLUMN auto_restart INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1 @@
+ALTER TABLE launcher_instances ADD COLUMN restart_on_crash INTEGER NOT NULL DEFAULT 0;
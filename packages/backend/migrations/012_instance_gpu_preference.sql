@@ -0,0 +1 @@
+ALTER TABLE launcher_instances ADD COLUMN gpu_preference TEXT;
@@ -0,0 +1 @@
+ALTER TABLE launcher_instances ADD COLUMN isolation TEXT NOT NULL DEFAULT 'shared';
@@ -0,0 +1 @@
+ALTER TABLE launcher_instances ADD COLUMN path_prepend TEXT;
@@ -25,6 +25,24 @@ import { logger } from "./utils/logger.js";
 
 export const app = express();
 
+function readBackendVersion(): string | null {
+  try {
+    const pkgPath = path.resolve(
+      path.dirname(fileURLToPath(import.meta.url)),
+      "..",
+      "package.json",
+    );
+    const pkg = JSON.parse(fs.readFileSync(pkgPath, "utf-8")) as {
+      version?: string;
+    };
+    return pkg.version ?? null;
+  } catch {
+    return null;
+  }
+}
+
+const backendVersion = readBackendVersion();
+
 // ACME challenge route — must be before any auth middleware (publicly accessible)
 app.use(acmeRouter);
 
@@ -39,7 +57,11 @@ app.use("/api/auth", authRateLimit);
 app.use("/api/log", clientLogsRouter);
 
 app.get("/api/health", (_req, res) => {
-  res.json({ status: "ok", timestamp: new Date().toISOString() });
+  res.json({
+    status: "ok",
+    version: backendVersion,
+    timestamp: new Date().toISOString(),
+  });
 });
 
 app.use("/api/auth", authRouter);
@@ -100,6 +122,7 @@ app.use(
       res.status(err.statusCode).json({
         error: err.message,
         code: err.code,
+        ...(err.details !== undefined && { details: err.details }),
       });
       return;
     }
@@ -14,6 +14,7 @@ import { setupTLS } from "./services/tls.js";
 import { setupPortForwarding, removePortForwarding } from "./services/upnp.js";
 import { cleanupExpiredSessions } from "./services/session.js";
 import { cleanupOldAttempts } from "./services/brute-force.js";
+import { configureOutboundProxy } from "./utils/proxy.js";
 import type { Server as HttpServer } from "node:http";
 import type { WebSocketServer } from "ws";
 
@@ -69,6 +70,8 @@ export async function startServer(
   let p = port ?? config.port;
   const h = host ?? config.host;
 
+  configureOutboundProxy();
+
   const tlsResult = await setupTLS(config.tls, config.dataDir);
 
   let httpServer: HttpServer;
@@ -99,8 +102,11 @@ export async function startServer(
   const proto = tlsResult ? "https" : "http";
   logger.info(`Backend server running at ${proto}://${h}:${actualPort}`);
 
+  // Written only once listening, and renamed into place so a reader polling
+  // for it never sees a half-written file
   const portFile = path.join(config.dataDir, "backend.port");
-  fs.writeFileSync(portFile, String(actualPort), "utf-8");
+  fs.writeFileSync(`${portFile}.tmp`, String(actualPort), "utf-8");
+  fs.renameSync(`${portFile}.tmp`, portFile);
 
   // Machine-readable line for Electron main process to parse
   console.log(`__BACKEND_PORT__=${actualPort}`);
@@ -109,6 +115,18 @@ export async function startServer(
   return { httpServer, wss, actualPort };
 }
 
+/**
+ * Re-read what can change without a restart: proxy settings and the extra
+ * CA bundle. Everything else is either env-derived at startup (port, data
+ * dirs, TLS) or read fresh from the database on each request (settings).
+ * Returns the names of what was reloaded.
+ */
+export function reloadConfig(): string[] {
+  configureOutboundProxy({ reload: true });
+  logger.info("Reloaded outbound network config");
+  return ["outbound network (proxy, extra CA certificates)"];
+}
+
 /**
  * Auto-start servers that have autoStart enabled.
  * Should be called after the Express server is ready.
@@ -225,6 +243,11 @@ async function main() {
     forceExit();
     shutdown();
   });
+  if (process.platform !== "win32") {
+    process.on("SIGHUP", () => {
+      reloadConfig();
+    });
+  }
 }
 
 if (isStandaloneEntry) {
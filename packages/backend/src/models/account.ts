@@ -1,7 +1,7 @@
 import type { LauncherAccount } from "@mc-server-manager/shared";
 import { nanoid } from "nanoid";
 import { getDb } from "../services/database.js";
-import { NotFoundError } from "../utils/errors.js";
+import { ConflictError, NotFoundError } from "../utils/errors.js";
 
 interface AccountRow {
   id: string;
@@ -69,10 +69,86 @@ export function createAccount(params: CreateAccountParams): LauncherAccount {
   return getAccountById(id);
 }
 
+export interface UpdateAccountParams {
+  uuid?: string;
+  username?: string;
+}
+
+/** Re-point an account at a different profile (e.g. after a migration). */
+export function updateAccount(
+  id: string,
+  params: UpdateAccountParams,
+): LauncherAccount {
+  const db = getDb();
+  getAccountById(id);
+
+  if (params.uuid !== undefined) {
+    const existing = db
+      .prepare("SELECT id FROM launcher_accounts WHERE uuid = ? AND id != ?")
+      .get(params.uuid, id) as { id: string } | undefined;
+    if (existing) {
+      throw new ConflictError(
+        `Another account already uses profile ${params.uuid}`,
+      );
+    }
+  }
+
+  db.prepare(
+    `
+    UPDATE launcher_accounts
+    SET uuid = COALESCE(@uuid, uuid), username = COALESCE(@username, username)
+    WHERE id = @id
+  `,
+  ).run({
+    id,
+    uuid: params.uuid ?? null,
+    username: params.username ?? null,
+  });
+
+  return getAccountById(id);
+}
+
 export function deleteAccount(id: string): void {
   const db = getDb();
   getAccountById(id);
-  db.prepare("DELETE FROM launcher_accounts WHERE id = ?").run(id);
+  db.transaction(() => {
+    db.prepare("DELETE FROM launcher_accounts WHERE id = ?").run(id);
+    db.prepare("DELETE FROM settings WHERE key = ? AND value = ?").run(
+      DEFAULT_ACCOUNT_KEY,
+      id,
+    );
+  })();
+}
+
+/** Settings-table key holding the account pre-selected for launches */
+const DEFAULT_ACCOUNT_KEY = "defaultAccountId";
+
+/** The user's default account, or null if unset or since deleted. */
+export function getDefaultAccount(): LauncherAccount | null {
+  const db = getDb();
+  const row = db
+    .prepare(
+      `SELECT a.* FROM settings s
+       JOIN launcher_accounts a ON a.id = s.value
+       WHERE s.key = ?`,
+    )
+    .get(DEFAULT_ACCOUNT_KEY) as AccountRow | undefined;
+  return row ? rowToAccount(row) : null;
+}
+
+/** Set (or clear, with null) the default account. Throws if it doesn't exist. */
+export function setDefaultAccount(id: string | null): LauncherAccount | null {
+  const db = getDb();
+  if (id === null) {
+    db.prepare("DELETE FROM settings WHERE key = ?").run(DEFAULT_ACCOUNT_KEY);
+    return null;
+  }
+
+  const account = getAccountById(id);
+  db.prepare(
+    "INSERT INTO settings (key, value) VALUES (?, ?) ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+  ).run(DEFAULT_ACCOUNT_KEY, id);
+  return account;
 }
 
 export function updateAccountLastUsed(id: string): void {
@@ -1,4 +1,7 @@
 import type {
+  GpuPreference,
+  InstanceIsolation,
+  JavaAgent,
   LauncherInstance,
   UpdateInstanceRequest,
   VersionType,
@@ -31,9 +34,14 @@ interface InstanceRow {
   resolution_height: number | null;
   jvm_args: string | null;
   game_args: string | null;
+  java_agents: string | null;
+  path_prepend: string | null;
   icon: string | null;
   last_played: string | null;
   total_playtime: number;
+  restart_on_crash: number; // SQLite stores booleans as 0/1
+  gpu_preference: string | null;
+  isolation: string;
   created_at: string;
   updated_at: string;
 }
@@ -54,9 +62,14 @@ function rowToInstance(row: InstanceRow): LauncherInstance {
     resolutionHeight: row.resolution_height,
     jvmArgs: JSON.parse(row.jvm_args || "[]") as string[],
     gameArgs: JSON.parse(row.game_args || "[]") as string[],
+    javaAgents: JSON.parse(row.java_agents || "[]") as JavaAgent[],
+    pathPrepend: JSON.parse(row.path_prepend || "[]") as string[],
     icon: row.icon,
     lastPlayed: row.last_played,
     totalPlaytime: row.total_playtime,
+    restartOnCrash: row.restart_on_crash === 1,
+    gpuPreference: row.gpu_preference as GpuPreference | null,
+    isolation: row.isolation as InstanceIsolation,
     createdAt: row.created_at,
     updatedAt: row.updated_at,
   };
@@ -166,6 +179,14 @@ export function updateInstance(
     setClauses.push("game_args = @gameArgs");
     values.gameArgs = JSON.stringify(params.gameArgs);
   }
+  if (params.javaAgents !== undefined) {
+    setClauses.push("java_agents = @javaAgents");
+    values.javaAgents = JSON.stringify(params.javaAgents);
+  }
+  if (params.pathPrepend !== undefined) {
+    setClauses.push("path_prepend = @pathPrepend");
+    values.pathPrepend = JSON.stringify(params.pathPrepend);
+  }
   if (params.icon !== undefined) {
     setClauses.push("icon = @icon");
     values.icon = params.icon;
@@ -174,6 +195,18 @@ export function updateInstance(
     setClauses.push("java_path = @javaPath");
     values.javaPath = params.javaPath;
   }
+  if (params.restartOnCrash !== undefined) {
+    setClauses.push("restart_on_crash = @restartOnCrash");
+    values.restartOnCrash = params.restartOnCrash ? 1 : 0;
+  }
+  if (params.gpuPreference !== undefined) {
+    setClauses.push("gpu_preference = @gpuPreference");
+    values.gpuPreference = params.gpuPreference;
+  }
+  if (params.isolation !== undefined) {
+    setClauses.push("isolation = @isolation");
+    values.isolation = params.isolation;
+  }
   if (params.loader !== undefined) {
     setClauses.push("loader = @loader");
     values.loader = params.loader;
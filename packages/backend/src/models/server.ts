@@ -14,6 +14,7 @@ interface ServerRow {
   jvm_args: string;
   port: number;
   auto_start: number; // SQLite stores booleans as 0/1
+  auto_restart: number;
   created_at: string;
   updated_at: string;
 }
@@ -33,6 +34,7 @@ function rowToServer(row: ServerRow): Server {
     jvmArgs: row.jvm_args,
     port: row.port,
     autoStart: row.auto_start === 1,
+    autoRestart: row.auto_restart === 1,
     createdAt: row.created_at,
     updatedAt: row.updated_at,
   };
@@ -58,6 +60,7 @@ export interface UpdateServerParams {
   jvmArgs?: string;
   javaPath?: string;
   autoStart?: boolean;
+  autoRestart?: boolean;
   jarPath?: string;
 }
 
@@ -145,6 +148,10 @@ export function updateServer(id: string, params: UpdateServerParams): Server {
     setClauses.push('auto_start = @autoStart');
     values.autoStart = params.autoStart ? 1 : 0;
   }
+  if (params.autoRestart !== undefined) {
+    setClauses.push('auto_restart = @autoRestart');
+    values.autoRestart = params.autoRestart ? 1 : 0;
+  }
   if (params.jarPath !== undefined) {
     setClauses.push('jar_path = @jarPath');
     values.jarPath = params.jarPath;
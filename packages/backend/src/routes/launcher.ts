@@ -2,7 +2,13 @@ import { Router } from "express";
 import { z } from "zod";
 import * as instanceService from "../services/instance-service.js";
 import * as accountModel from "../models/account.js";
-import { detectAllJavaInstallations, downloadJava } from "../services/java.js";
+import {
+  cancelJavaDownload,
+  detectAllJavaInstallations,
+  downloadJava,
+  getAvailableJavaVersions,
+  warmJava,
+} from "../services/java.js";
 import { VersionService } from "../services/version-service.js";
 import {
   startPrepare,
@@ -38,8 +44,15 @@ const updateInstanceSchema = z.object({
   resolutionHeight: z.number().int().positive().nullable().optional(),
   jvmArgs: z.array(z.string()).optional(),
   gameArgs: z.array(z.string()).optional(),
+  javaAgents: z
+    .array(z.object({ path: z.string().min(1), options: z.string() }))
+    .optional(),
+  pathPrepend: z.array(z.string().min(1)).optional(),
   icon: z.string().nullable().optional(),
   javaPath: z.string().nullable().optional(),
+  restartOnCrash: z.boolean().optional(),
+  gpuPreference: z.enum(["integrated", "discrete"]).nullable().optional(),
+  isolation: z.enum(["shared", "isolated"]).optional(),
 });
 
 const createAccountSchema = z.object({
@@ -112,10 +125,23 @@ launcherRouter.get("/versions", async (req, res, next) => {
   }
 });
 
+launcherRouter.get("/versions/summary", async (req, res, next) => {
+  try {
+    const includeSnapshots = req.query.includeSnapshots === "true";
+    res.json(await versionService.getVersionSummaries(includeSnapshots));
+  } catch (err) {
+    next(err);
+  }
+});
+
 launcherRouter.post("/prepare/:id", (req, res, next) => {
   try {
     const instance = instanceService.getInstanceById(req.params.id);
-    const job = startPrepare(instance.id, instance.mcVersion);
+    const job = startPrepare(
+      instance.id,
+      instance.mcVersion,
+      instance.isolation,
+    );
 
     logger.info(
       { jobId: job.id, instanceId: instance.id, mcVersion: instance.mcVersion },
@@ -172,6 +198,27 @@ launcherRouter.get("/accounts", (_req, res, next) => {
   }
 });
 
+const setDefaultAccountSchema = z.object({
+  accountId: z.string().nullable(),
+});
+
+launcherRouter.get("/accounts/default", (_req, res, next) => {
+  try {
+    res.json(accountModel.getDefaultAccount());
+  } catch (err) {
+    next(err);
+  }
+});
+
+launcherRouter.put("/accounts/default", (req, res, next) => {
+  try {
+    const { accountId } = validate(setDefaultAccountSchema, req.body);
+    res.json(accountModel.setDefaultAccount(accountId));
+  } catch (err) {
+    next(err);
+  }
+});
+
 launcherRouter.post("/accounts", (req, res, next) => {
   try {
     const body = validate(createAccountSchema, req.body);
@@ -183,6 +230,20 @@ launcherRouter.post("/accounts", (req, res, next) => {
   }
 });
 
+const updateAccountSchema = z.object({
+  uuid: z.string().min(1).optional(),
+  username: z.string().min(1).optional(),
+});
+
+launcherRouter.patch("/accounts/:id", (req, res, next) => {
+  try {
+    const body = validate(updateAccountSchema, req.body);
+    res.json(accountModel.updateAccount(req.params.id, body));
+  } catch (err) {
+    next(err);
+  }
+});
+
 launcherRouter.delete("/accounts/:id", (req, res, next) => {
   try {
     accountModel.deleteAccount(req.params.id);
@@ -194,6 +255,8 @@ launcherRouter.delete("/accounts/:id", (req, res, next) => {
 
 const downloadJavaSchema = z.object({
   version: z.number().int().min(8).max(99),
+  /** On ARM, accept an x64 build when no native one exists */
+  allowEmulated: z.boolean().optional(),
 });
 
 launcherRouter.get("/java", async (_req, res, next) => {
@@ -205,13 +268,57 @@ launcherRouter.get("/java", async (_req, res, next) => {
   }
 });
 
+launcherRouter.get("/java/available", async (_req, res, next) => {
+  try {
+    res.json(await getAvailableJavaVersions());
+  } catch (err) {
+    next(err);
+  }
+});
+
+const warmJavaSchema = z.object({
+  javaPath: z.string().min(1),
+});
+
+launcherRouter.post("/java/warm", async (req, res, next) => {
+  try {
+    const { javaPath } = validate(warmJavaSchema, req.body);
+    res.json(await warmJava(javaPath));
+  } catch (err) {
+    next(err);
+  }
+});
+
 launcherRouter.post("/java/download", async (req, res, next) => {
   try {
     const body = validate(downloadJavaSchema, req.body);
 
-    const installation = await downloadJava(body.version, config.dataDir);
+    const installation = await downloadJava(body.version, config.dataDir, {
+      allowEmulated: body.allowEmulated,
+    });
     res.json(installation);
   } catch (err) {
     next(err);
   }
 });
+
+launcherRouter.delete("/java/download/:version", (req, res, next) => {
+  try {
+    const { version } = validate(downloadJavaSchema, {
+      version: Number(req.params.version),
+    });
+
+    if (!cancelJavaDownload(version)) {
+      throw new AppError(
+        `No download in progress for Java ${version}`,
+        409,
+        "NOT_CANCELLABLE",
+      );
+    }
+
+    logger.info({ version }, "Java download cancelled by user");
+    res.json({ message: "Java download cancelled", version });
+  } catch (err) {
+    next(err);
+  }
+});
@@ -139,6 +139,27 @@ describe("POST /api/servers", () => {
   });
 });
 
+describe("GET /api/servers/free-port", () => {
+  it("skips ports already assigned to a server", async () => {
+    // 25580 is taken by the port conflict test above
+    const res = await supertest(app)
+      .get("/api/servers/free-port?start=25580&count=10")
+      .set("Authorization", `Bearer ${owner.token}`);
+
+    expect(res.status).toBe(200);
+    expect(res.body.port).toBeGreaterThan(25580);
+    expect(res.body.port).toBeLessThan(25590);
+  });
+
+  it("returns 400 for a start port below 1024", async () => {
+    const res = await supertest(app)
+      .get("/api/servers/free-port?start=80")
+      .set("Authorization", `Bearer ${owner.token}`);
+
+    expect(res.status).toBe(400);
+  });
+});
+
 describe("GET /api/servers/:id", () => {
   it("returns 200 with server data for a valid id", async () => {
     const body = buildCreateServerRequest({
@@ -226,6 +247,28 @@ describe("PATCH /api/servers/:id", () => {
   });
 });
 
+describe("PUT /api/servers/:id/properties", () => {
+  it("returns 400 with the issues for an invalid value", async () => {
+    const body = buildCreateServerRequest({
+      name: "Invalid Properties",
+      port: 25575,
+    });
+    const createRes = await supertest(app)
+      .post("/api/servers")
+      .set("Authorization", `Bearer ${owner.token}`)
+      .send(body);
+    const serverId = createRes.body.id;
+
+    const res = await supertest(app)
+      .put(`/api/servers/${serverId}/properties`)
+      .set("Authorization", `Bearer ${owner.token}`)
+      .send({ properties: { "server-port": "70000" } });
+
+    expect(res.status).toBe(400);
+    expect(res.body.code).toBe("INVALID_PROPERTIES");
+  });
+});
+
 describe("DELETE /api/servers/:id", () => {
   it("deletes a server and returns 204", async () => {
     const body = buildCreateServerRequest({
@@ -16,15 +16,20 @@ import {
   createServerSchema,
   updateServerSchema,
   updatePropertiesSchema,
+  stopServerSchema,
+  stopAllServersSchema,
+  freePortQuerySchema,
 } from "./validation.js";
 import { AppError, ConflictError } from "../utils/errors.js";
 import { validate } from "../utils/validation.js";
 import { logger } from "../utils/logger.js";
+import { findFreePort } from "../utils/ports.js";
 import { setupServerDirectory } from "../services/server-setup.js";
 import { serverManager } from "../services/server-manager.js";
 import {
   readServerProperties,
   writeServerProperties,
+  InvalidPropertiesError,
   PROPERTY_GROUPS,
 } from "../services/properties.js";
 import {
@@ -57,6 +62,28 @@ serversRouter.get("/", requireAuth, (req, res) => {
   res.json(enriched);
 });
 
+/**
+ * GET /api/servers/free-port — First port from `start` that no server is
+ * configured with and nothing on this machine is bound to. Pass `udp=true`
+ * to also require the UDP port (query) to be free.
+ */
+serversRouter.get("/free-port", requireAuth, async (req, res, next) => {
+  try {
+    const { start, count, udp } = validate(freePortQuerySchema, req.query);
+    const port = await findFreePort(start, count, {
+      udp,
+      isReserved: (p) => isPortInUse(p),
+    });
+    if (port === null) {
+      const last = Math.min(start + count - 1, 65535);
+      throw new ConflictError(`No free port between ${start} and ${last}`);
+    }
+    res.json({ port });
+  } catch (err) {
+    next(err);
+  }
+});
+
 /**
  * GET /api/servers/:id — Get a single server (enriched with runtime status)
  */
@@ -189,6 +216,25 @@ serversRouter.delete(
 // Server Lifecycle Routes
 // ============================================================
 
+/**
+ * POST /api/servers/stop-all — Stop every running server
+ * Optional body: { graceful } (default true; false kills immediately).
+ * Responds with one outcome per server once they've all stopped.
+ */
+serversRouter.post(
+  "/stop-all",
+  requireAuth,
+  requireAdminOrOwner,
+  async (req, res, next) => {
+    try {
+      const { graceful } = validate(stopAllServersSchema, req.body ?? {});
+      res.json(await serverManager.stopAll({ graceful }));
+    } catch (err) {
+      next(err);
+    }
+  },
+);
+
 /**
  * POST /api/servers/:id/start — Start the server
  */
@@ -208,6 +254,7 @@ serversRouter.post(
 
 /**
  * POST /api/servers/:id/stop — Graceful stop
+ * Optional body: { timeoutSecs } before the server is force-killed.
  */
 serversRouter.post(
   "/:id/stop",
@@ -215,7 +262,8 @@ serversRouter.post(
   requireServerPermission("can_start"),
   (req, res, next) => {
     try {
-      const result = serverManager.stop(req.params.id as string);
+      const { timeoutSecs } = validate(stopServerSchema, req.body ?? {});
+      const result = serverManager.stop(req.params.id as string, timeoutSecs);
       res.json(result);
     } catch (err) {
       next(err);
@@ -350,7 +398,9 @@ serversRouter.put(
       const existing = readServerProperties(server.directory);
       const merged = { ...existing, ...body.properties };
 
-      writeServerProperties(server.directory, merged);
+      // Only the submitted keys are validated, so pre-existing legacy values
+      // in the file don't block unrelated edits.
+      writeServerProperties(server.directory, merged, body.properties);
 
       const status = serverManager.getStatus(server.id);
       const serverRunning =
@@ -364,6 +414,12 @@ serversRouter.put(
         serverRunning,
       });
     } catch (err) {
+      if (err instanceof InvalidPropertiesError) {
+        next(
+          new AppError(err.message, 400, "INVALID_PROPERTIES", err.issues),
+        );
+        return;
+      }
       next(err);
     }
   },
@@ -1,10 +1,13 @@
 import { Router } from "express";
 import os from "node:os";
 import { z } from "zod";
-import type { SystemInfo } from "@mc-server-manager/shared";
+import type { DiskSpaceInfo, SystemInfo } from "@mc-server-manager/shared";
+import { config } from "../config.js";
 import { detectJava, validateJavaPath } from "../services/java.js";
 import { getAllSettings, updateSettings } from "../services/settings.js";
+import { getFreeDiskSpace } from "../utils/disk-space.js";
 import { logger } from "../utils/logger.js";
+import { recommendRam } from "../utils/ram-recommendation.js";
 import { validate } from "../utils/validation.js";
 
 export const systemRouter = Router();
@@ -23,6 +26,38 @@ systemRouter.get("/info", (_req, res) => {
   res.json(info);
 });
 
+/**
+ * GET /api/system/ram-recommendation — Default min/max heap for a new instance.
+ * Optional query: mcVersion, loader (any loader counts as modded)
+ */
+systemRouter.get("/ram-recommendation", (req, res) => {
+  const mcVersion =
+    typeof req.query.mcVersion === "string" ? req.query.mcVersion : undefined;
+  const loader = typeof req.query.loader === "string" ? req.query.loader : "";
+  res.json(
+    recommendRam(os.totalmem() / 1024 / 1024, os.freemem() / 1024 / 1024, {
+      mcVersion,
+      modded: loader !== "" && loader !== "none",
+    }),
+  );
+});
+
+/**
+ * GET /api/system/disk-space — Free bytes on the volume holding the data dir
+ */
+systemRouter.get("/disk-space", async (_req, res, next) => {
+  try {
+    const info: DiskSpaceInfo = {
+      path: config.dataDir,
+      freeBytes: await getFreeDiskSpace(config.dataDir),
+    };
+    res.json(info);
+  } catch (err) {
+    logger.error({ err }, "Failed to query disk space");
+    next(err);
+  }
+});
+
 /**
  * GET /api/system/java — Detect Java installation
  * Query params:
@@ -65,6 +100,9 @@ systemRouter.patch("/settings", (req, res, next) => {
       maxConsoleLines: z.number().optional(),
       curseforgeApiKey: z.string().optional(),
       showOverridePreview: z.boolean().optional(),
+      desktopNotifications: z.boolean().optional(),
+      maxConcurrentGames: z.number().int().min(0).max(64).optional(),
+      crashNotifications: z.boolean().optional(),
     });
     const body = validate(settingsUpdateSchema, req.body);
     const updated = updateSettings(body);
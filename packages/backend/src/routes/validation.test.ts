@@ -2,6 +2,8 @@ import {
   createServerSchema,
   updateServerSchema,
   updatePropertiesSchema,
+  stopServerSchema,
+  stopAllServersSchema,
 } from "./validation.js";
 
 describe("Validation Schemas", () => {
@@ -249,4 +251,48 @@ describe("Validation Schemas", () => {
       }
     });
   });
+
+  describe("stopServerSchema", () => {
+    it("empty body passes", () => {
+      expect(stopServerSchema.safeParse({}).success).toBe(true);
+    });
+
+    it("whole-second timeout passes", () => {
+      const result = stopServerSchema.safeParse({ timeoutSecs: 120 });
+      expect(result.success).toBe(true);
+      if (result.success) {
+        expect(result.data.timeoutSecs).toBe(120);
+      }
+    });
+
+    it("zero timeout fails", () => {
+      expect(stopServerSchema.safeParse({ timeoutSecs: 0 }).success).toBe(
+        false,
+      );
+    });
+
+    it("fractional timeout fails", () => {
+      expect(stopServerSchema.safeParse({ timeoutSecs: 1.5 }).success).toBe(
+        false,
+      );
+    });
+
+    it("timeout above 600 fails", () => {
+      expect(stopServerSchema.safeParse({ timeoutSecs: 601 }).success).toBe(
+        false,
+      );
+    });
+  });
+
+  describe("stopAllServersSchema", () => {
+    it("empty body passes", () => {
+      expect(stopAllServersSchema.safeParse({}).success).toBe(true);
+    });
+
+    it("non-boolean graceful fails", () => {
+      expect(stopAllServersSchema.safeParse({ graceful: "yes" }).success).toBe(
+        false,
+      );
+    });
+  });
 });
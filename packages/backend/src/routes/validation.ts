@@ -50,6 +50,7 @@ export const updateServerSchema = z.object({
   jvmArgs: z.string().optional(),
   javaPath: z.string().optional(),
   autoStart: z.boolean().optional(),
+  autoRestart: z.boolean().optional(),
   jarPath: z.string().optional(),
 });
 
@@ -60,6 +61,42 @@ export const updatePropertiesSchema = z.object({
   ),
 });
 
+export const stopServerSchema = z.object({
+  timeoutSecs: z
+    .number()
+    .int("Stop timeout must be a whole number of seconds")
+    .min(1, "Stop timeout must be at least 1 second")
+    .max(600, "Stop timeout must be 600 seconds or less")
+    .optional(),
+});
+
+export const stopAllServersSchema = z.object({
+  graceful: z.boolean().optional(),
+});
+
+export const freePortQuerySchema = z.object({
+  start: z.coerce
+    .number()
+    .int("Start port must be an integer")
+    .min(1024, "Start port must be 1024 or higher")
+    .max(65535, "Start port must be 65535 or lower")
+    .optional()
+    .default(25565),
+  count: z.coerce
+    .number()
+    .int("Count must be an integer")
+    .min(1, "Count must be at least 1")
+    .max(1000, "Count must be 1000 or less")
+    .optional()
+    .default(100),
+  udp: z
+    .enum(["true", "false"])
+    .optional()
+    .transform((v) => v === "true"),
+});
+
 export type CreateServerBody = z.infer<typeof createServerSchema>;
 export type UpdateServerBody = z.infer<typeof updateServerSchema>;
 export type UpdatePropertiesBody = z.infer<typeof updatePropertiesSchema>;
+export type StopServerBody = z.infer<typeof stopServerSchema>;
+export type StopAllServersBody = z.infer<typeof stopAllServersSchema>;
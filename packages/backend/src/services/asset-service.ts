@@ -27,8 +27,12 @@ export class AssetService {
   private indexesDir: string;
   private objectsDir: string;
 
-  constructor(private dataDir: string) {
-    this.assetsDir = join(dataDir, "launcher", "assets");
+  /** `launcherDir` overrides `<dataDir>/launcher`, for isolated instances */
+  constructor(
+    private dataDir: string,
+    launcherDir = join(dataDir, "launcher"),
+  ) {
+    this.assetsDir = join(launcherDir, "assets");
     this.indexesDir = join(this.assetsDir, "indexes");
     this.objectsDir = join(this.assetsDir, "objects");
     mkdirSync(this.indexesDir, { recursive: true });
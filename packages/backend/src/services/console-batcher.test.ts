@@ -0,0 +1,70 @@
+import { ConsoleBatcher } from "./console-batcher.js";
+
+function entry(line: string) {
+  return { line, timestamp: "2024-01-01T00:00:00.000Z" };
+}
+
+describe("ConsoleBatcher", () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it("flushes queued lines once per interval", () => {
+    const onFlush = vi.fn();
+    const batcher = new ConsoleBatcher(onFlush, 100);
+
+    batcher.push("s1", entry("a"));
+    batcher.push("s1", entry("b"));
+    expect(onFlush).not.toHaveBeenCalled();
+
+    vi.advanceTimersByTime(100);
+    expect(onFlush).toHaveBeenCalledTimes(1);
+    expect(onFlush).toHaveBeenCalledWith("s1", [entry("a"), entry("b")], 0);
+  });
+
+  it("keeps servers in separate batches", () => {
+    const onFlush = vi.fn();
+    const batcher = new ConsoleBatcher(onFlush, 100);
+
+    batcher.push("s1", entry("a"));
+    batcher.push("s2", entry("b"));
+    vi.advanceTimersByTime(100);
+
+    expect(onFlush).toHaveBeenCalledWith("s1", [entry("a")], 0);
+    expect(onFlush).toHaveBeenCalledWith("s2", [entry("b")], 0);
+  });
+
+  it("drops the oldest lines past the cap and adds a marker", () => {
+    const onFlush = vi.fn();
+    const batcher = new ConsoleBatcher(onFlush, 100, 3);
+
+    for (let i = 1; i <= 5; i++) {
+      batcher.push("s1", entry(`line ${i}`));
+    }
+    batcher.flush();
+
+    const [, lines, dropped] = onFlush.mock.calls[0];
+    expect(dropped).toBe(2);
+    expect(lines.map((l: { line: string }) => l.line)).toEqual([
+      "[2 lines dropped]",
+      "line 3",
+      "line 4",
+      "line 5",
+    ]);
+  });
+
+  it("sends nothing after dispose", () => {
+    const onFlush = vi.fn();
+    const batcher = new ConsoleBatcher(onFlush, 100);
+
+    batcher.push("s1", entry("a"));
+    batcher.dispose();
+    vi.advanceTimersByTime(100);
+
+    expect(onFlush).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,94 @@
+/**
+ * Coalesces console lines into per-server batches flushed on a timer.
+ *
+ * A chatty modpack can print tens of thousands of lines a second; sending
+ * each as its own WebSocket message (and store update on the client) stalls
+ * the UI. Lines are queued per server and flushed every `intervalMs`. If a
+ * server outruns the queue cap, the oldest queued lines are dropped and the
+ * batch reports how many. The server's own log file on disk is unaffected.
+ */
+
+import type { ConsoleLine } from "./console-buffer.js";
+
+/** How often pending lines are flushed. */
+const DEFAULT_FLUSH_INTERVAL_MS = 100;
+
+/** Max lines queued per server between flushes before dropping the oldest. */
+const DEFAULT_MAX_PENDING_LINES = 2_000;
+
+export type ConsoleBatchHandler = (
+  serverId: string,
+  lines: ConsoleLine[],
+  dropped: number,
+) => void;
+
+interface PendingBatch {
+  lines: ConsoleLine[];
+  dropped: number;
+}
+
+export class ConsoleBatcher {
+  private pending = new Map<string, PendingBatch>();
+  private timer: ReturnType<typeof setTimeout> | null = null;
+
+  constructor(
+    private onFlush: ConsoleBatchHandler,
+    private intervalMs: number = DEFAULT_FLUSH_INTERVAL_MS,
+    private maxPending: number = DEFAULT_MAX_PENDING_LINES,
+  ) {}
+
+  /** Queue a line; it goes out with the next flush. */
+  push(serverId: string, entry: ConsoleLine): void {
+    let batch = this.pending.get(serverId);
+    if (!batch) {
+      batch = { lines: [], dropped: 0 };
+      this.pending.set(serverId, batch);
+    }
+
+    batch.lines.push(entry);
+    // Trim in chunks so a flood doesn't shift the array on every line
+    if (batch.lines.length >= this.maxPending * 2) {
+      const excess = batch.lines.length - this.maxPending;
+      batch.lines.splice(0, excess);
+      batch.dropped += excess;
+    }
+
+    if (!this.timer) {
+      this.timer = setTimeout(() => this.flush(), this.intervalMs);
+    }
+  }
+
+  /** Send everything queued now. */
+  flush(): void {
+    if (this.timer) {
+      clearTimeout(this.timer);
+      this.timer = null;
+    }
+
+    const batches = this.pending;
+    this.pending = new Map();
+    for (const [serverId, batch] of batches) {
+      let { lines, dropped } = batch;
+      if (lines.length > this.maxPending) {
+        dropped += lines.length - this.maxPending;
+        lines = lines.slice(lines.length - this.maxPending);
+      }
+      if (dropped > 0) {
+        lines.unshift({
+          line: `[${dropped} lines dropped]`,
+          timestamp: lines[0].timestamp,
+        });
+      }
+      this.onFlush(serverId, lines, dropped);
+    }
+  }
+
+  /** Drop anything queued and stop the timer. */
+  dispose(): void {
+    if (this.timer) {
+      clearTimeout(this.timer);
+      this.timer = null;
+    }
+    this.pending.clear();
+  }
+}
@@ -0,0 +1,40 @@
+import { classifyServerCrash } from "./crash-analyzer.js";
+
+describe("classifyServerCrash", () => {
+  it("detects out-of-memory errors", () => {
+    const lines = [
+      "[12:00:00] [Server thread/INFO]: Preparing spawn area: 42%",
+      'Exception in thread "Server thread" java.lang.OutOfMemoryError: Java heap space',
+    ];
+    expect(classifyServerCrash(lines)).toBe("out_of_memory");
+  });
+
+  it("detects port conflicts", () => {
+    const lines = [
+      "[12:00:00] [Server thread/WARN]: **** FAILED TO BIND TO PORT!",
+      "[12:00:00] [Server thread/WARN]: The exception was: java.net.BindException: Address already in use",
+    ];
+    expect(classifyServerCrash(lines)).toBe("port_in_use");
+  });
+
+  it("detects corrupt world data", () => {
+    const lines = [
+      "[12:00:00] [Server thread/ERROR]: Failed to load level data",
+      "java.util.zip.ZipException: invalid distance too far back",
+    ];
+    expect(classifyServerCrash(lines)).toBe("corrupt_world");
+  });
+
+  it("returns unknown when no signature matches", () => {
+    const lines = ["[12:00:00] [Server thread/INFO]: Stopping server"];
+    expect(classifyServerCrash(lines)).toBe("unknown");
+  });
+
+  it("only scans the tail of the output", () => {
+    const lines = [
+      "java.lang.OutOfMemoryError: Java heap space",
+      ...Array.from({ length: 500 }, (_, i) => `line ${i}`),
+    ];
+    expect(classifyServerCrash(lines)).toBe("unknown");
+  });
+});
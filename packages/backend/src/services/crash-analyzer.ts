@@ -0,0 +1,48 @@
+/**
+ * Crash analyzer — classifies why a Minecraft server exited unexpectedly.
+ *
+ * Scans the tail of the console output for well-known failure signatures
+ * so the UI can show an actionable cause instead of a bare exit code.
+ */
+
+import type { ServerCrashCause } from "@mc-server-manager/shared";
+
+/** Number of trailing console lines scanned for crash signatures. */
+export const CRASH_SCAN_LINES = 200;
+
+/**
+ * Signatures checked in order — the first match wins.
+ * Port conflicts are checked before OOM because a failed bind can cascade
+ * into unrelated errors further down the log.
+ */
+const CRASH_SIGNATURES: Array<{ cause: ServerCrashCause; pattern: RegExp }> = [
+  {
+    cause: "port_in_use",
+    pattern:
+      /FAILED TO BIND TO PORT|Address already in use|java\.net\.BindException/i,
+  },
+  {
+    cause: "out_of_memory",
+    pattern:
+      /java\.lang\.OutOfMemoryError|Could not reserve enough space for .*object heap/i,
+  },
+  {
+    cause: "corrupt_world",
+    pattern:
+      /Failed to load level|Exception reading .*level\.dat|Couldn't load chunk|Failed to read level data|Corrupted chunk|RegionFileStorage|Failed to load data for .*region/i,
+  },
+];
+
+/**
+ * Classify a crash from the last lines of server output.
+ * Returns "unknown" when no known signature is found.
+ */
+export function classifyServerCrash(lines: string[]): ServerCrashCause {
+  const tail = lines.slice(-CRASH_SCAN_LINES);
+  for (const { cause, pattern } of CRASH_SIGNATURES) {
+    if (tail.some((line) => pattern.test(line))) {
+      return cause;
+    }
+  }
+  return "unknown";
+}
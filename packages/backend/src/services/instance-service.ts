@@ -41,6 +41,11 @@ function getInstanceDir(instanceId: string): string {
   return path.join(config.dataDir, "launcher", "instances", instanceId);
 }
 
+/** Where an isolated instance keeps its own assets, libraries and natives. */
+export function isolatedLauncherDir(instanceId: string): string {
+  return path.join(config.dataDir, "launcher", "isolated", instanceId);
+}
+
 export function listInstances(): LauncherInstance[] {
   return instanceModel.getAllInstances();
 }
@@ -91,6 +96,7 @@ export function deleteInstance(id: string): void {
 
   const instanceDir = getInstanceDir(id);
   fs.rmSync(instanceDir, { recursive: true, force: true });
+  fs.rmSync(isolatedLauncherDir(id), { recursive: true, force: true });
 
   instanceModel.deleteInstance(id);
 
@@ -0,0 +1,140 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import {
+  discoverJavaBinsInDir,
+  getJavaMajorVersion,
+  looksLikeJavaDir,
+  parseJavaVendor,
+  parseJavaVersion,
+} from "./java.js";
+
+const binName = os.platform() === "win32" ? "java.exe" : "java";
+
+function makeJdk(root: string, name: string): string {
+  const bin = path.join(root, name, "bin");
+  fs.mkdirSync(bin, { recursive: true });
+  const java = path.join(bin, binName);
+  fs.writeFileSync(java, "");
+  return java;
+}
+
+describe("looksLikeJavaDir", () => {
+  it("accepts common JDK directory names", () => {
+    for (const name of [
+      "java-17-openjdk-amd64",
+      "jdk-21.0.2+13",
+      "jre1.8.0_381",
+      "temurin-17.jdk",
+      "openjdk@17",
+      "zulu-21.jdk",
+      "amazon-corretto-17.jdk",
+      "graalvm-ce-java17",
+      "21.0.2-tem",
+    ]) {
+      expect(looksLikeJavaDir(name)).toBe(true);
+    }
+  });
+
+  it("rejects hidden and unrelated directories", () => {
+    for (const name of [
+      ".cache",
+      ".jdk-staging",
+      "node",
+      "python@3.12",
+      "git",
+    ]) {
+      expect(looksLikeJavaDir(name)).toBe(false);
+    }
+  });
+});
+
+describe("discoverJavaBinsInDir", () => {
+  let root: string;
+
+  beforeEach(() => {
+    root = fs.mkdtempSync(path.join(os.tmpdir(), "java-discovery-"));
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+    fs.rmSync(root, { recursive: true, force: true });
+  });
+
+  it("finds JDKs without statting unrelated directories", () => {
+    const expected = [
+      makeJdk(root, "java-17-openjdk-amd64"),
+      makeJdk(root, "jdk-21"),
+    ];
+    // A Homebrew-sized prefix full of non-Java formulae
+    for (let i = 0; i < 500; i++) {
+      fs.mkdirSync(path.join(root, `formula-${i}`));
+    }
+    fs.mkdirSync(path.join(root, ".hidden-jdk", "bin"), { recursive: true });
+
+    const existsSpy = vi.spyOn(fs, "existsSync");
+    const statSpy = vi.spyOn(fs, "statSync");
+    const found = discoverJavaBinsInDir(root);
+
+    expect(found.sort()).toEqual(expected.sort());
+    // At most two probes (macOS + standard layout) per JDK-looking entry
+    expect(existsSpy.mock.calls.length).toBeLessThanOrEqual(4);
+    const probed = [...existsSpy.mock.calls, ...statSpy.mock.calls].map(
+      ([p]) => String(p),
+    );
+    expect(probed.some((p) => p.includes("formula-"))).toBe(false);
+    expect(probed.some((p) => p.includes(".hidden-jdk"))).toBe(false);
+  });
+});
+
+describe("parseJavaVersion", () => {
+  it("reads the standard quoted version line", () => {
+    const output = [
+      'openjdk version "21.0.1" 2023-10-17',
+      "OpenJDK Runtime Environment (build 21.0.1+12-29)",
+      "OpenJDK 64-Bit Server VM (build 21.0.1+12-29, mixed mode, sharing)",
+    ].join("\n");
+    expect(parseJavaVersion(output)).toBe("21.0.1");
+  });
+
+  it("skips a banner printed before the version", () => {
+    const output = [
+      "Picked up JAVA_TOOL_OPTIONS: -Dfile.encoding=UTF-8 -Xss4M",
+      'java version "1.8.0_392"',
+      "Java(TM) SE Runtime Environment (build 1.8.0_392-b08)",
+    ].join("\r\n");
+    const version = parseJavaVersion(output);
+    expect(version).toBe("1.8.0_392");
+    expect(getJavaMajorVersion(version!)).toBe(8);
+  });
+
+  it("handles GraalVM output and vendor", () => {
+    const output = [
+      'openjdk version "21.0.2" 2024-01-16',
+      "OpenJDK Runtime Environment GraalVM CE 21.0.2+13.1 (build 21.0.2+13-jvmci-23.1-b30)",
+      "OpenJDK 64-Bit Server VM GraalVM CE 21.0.2+13.1 (build 21.0.2+13-jvmci-23.1-b30, mixed mode, sharing)",
+    ].join("\n");
+    expect(parseJavaVersion(output)).toBe("21.0.2");
+    expect(parseJavaVendor(output)).toBe("GraalVM");
+  });
+
+  it("prefers the Java version over GraalVM's own in unquoted output", () => {
+    const output = [
+      "GraalVM 22.3.1 Java 17 CE (Java Version 17.0.6+10-jvmci-22.3-b13)",
+      "Native Image builder",
+    ].join("\n");
+    const version = parseJavaVersion(output);
+    expect(version).toBe("17.0.6");
+    expect(getJavaMajorVersion(version!)).toBe(17);
+  });
+
+  it("falls back to a bare version number", () => {
+    expect(parseJavaVersion("openjdk 21 2023-09-19\nOpenJDK Runtime")).toBe(
+      "21",
+    );
+  });
+
+  it("returns null when there's no version", () => {
+    expect(parseJavaVersion("Error: could not find libjava.so")).toBeNull();
+  });
+});
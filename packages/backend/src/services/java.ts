@@ -5,9 +5,16 @@ import path from "node:path";
 import os from "node:os";
 import { pipeline } from "node:stream/promises";
 import { createWriteStream } from "node:fs";
-import type { JavaInfo, JavaInstallation } from "@mc-server-manager/shared";
+import type {
+  AvailableJavaVersion,
+  JavaInfo,
+  JavaInstallation,
+  JavaProbeError,
+  JavaWarmupResult,
+} from "@mc-server-manager/shared";
 import { logger } from "../utils/logger.js";
 import { AppError, ValidationError } from "../utils/errors.js";
+import { TTLCache } from "../utils/cache.js";
 
 const execFileAsync = promisify(execFile);
 
@@ -61,12 +68,36 @@ function getCommonJavaLocations(): string[] {
  *   openjdk version "21.0.1" 2023-10-17
  *   OpenJDK Runtime Environment (build 21.0.1+12-29)
  *   OpenJDK 64-Bit Server VM (build 21.0.1+12-29, mixed mode, sharing)
+ *
+ * Wrappers and some distributions print a banner first (e.g. "Picked up
+ * JAVA_TOOL_OPTIONS: ...") or an unquoted version, as older GraalVM does:
+ *   GraalVM 22.3.1 Java 17 CE (Java Version 17.0.6+10-jvmci-22.3-b13)
+ * so every line is checked, best-matching pattern first.
  */
-function parseJavaVersion(output: string): string | null {
-  // Match patterns like: "21.0.1", "17.0.9", "1.8.0_392"
-  const match = output.match(/version\s+"([^"]+)"/);
-  if (match) {
-    return match[1];
+export function parseJavaVersion(output: string): string | null {
+  const lines = output.split(/\r?\n/);
+  const patterns = [
+    // version "21.0.1" — the standard form; some wrappers use single quotes
+    /\bversion\s+["']([^"']+)["']/i,
+    // Java Version 17.0.6+10
+    /\bversion\s+(\d+(?:\.\d+)*(?:_\d+)?)/i,
+  ];
+  for (const pattern of patterns) {
+    for (const line of lines) {
+      const match = line.match(pattern);
+      if (match) return match[1];
+    }
+  }
+
+  // Last resort: a bare version number on a line naming the JVM, like
+  // `java --version`'s "openjdk 21 2023-09-19". Dates and "64-Bit" don't
+  // match because the token must end at a space, "+", "," or ")".
+  for (const line of lines) {
+    if (!/\b(?:openjdk|java|jdk|jre)\b/i.test(line)) continue;
+    const match = line.match(
+      /(?:^|[\s(])(\d+(?:\.\d+)+(?:_\d+)?|\d{1,2})(?=[\s+,)]|$)/,
+    );
+    if (match) return match[1];
   }
   return null;
 }
@@ -110,20 +141,60 @@ async function probeJava(javaPath: string): Promise<JavaInfo | null> {
 }
 
 /**
- * Try to resolve the real path of 'java' on PATH using `which` (Unix) or `where` (Windows).
+ * List every 'java' on PATH, in PATH order, using `which -a` (Unix) or
+ * `where` (Windows). The first entry is often a shim/stub, so callers should
+ * consider all of them.
  */
-async function findJavaOnPath(): Promise<string | null> {
-  const cmd = os.platform() === "win32" ? "where" : "which";
+async function findJavaOnPath(): Promise<string[]> {
+  const isWindows = os.platform() === "win32";
+  const cmd = isWindows ? "where" : "which";
+  const args = isWindows ? ["java"] : ["-a", "java"];
   try {
-    const { stdout } = await execFileAsync(cmd, ["java"], { timeout: 5_000 });
-    const resolved = stdout.trim().split("\n")[0].trim();
-    if (resolved && fs.existsSync(resolved)) {
-      return resolved;
-    }
+    const { stdout } = await execFileAsync(cmd, args, { timeout: 5_000 });
+    return stdout
+      .split(/\r?\n/)
+      .map((line) => line.trim())
+      .filter((line) => line && fs.existsSync(line));
   } catch (err) {
     logger.debug({ err }, "Java not found on PATH via which/where");
   }
-  return null;
+  return [];
+}
+
+/**
+ * Resolve the java binary for a JAVA_HOME value.
+ *
+ * JAVA_HOME is often a symlink or set one directory off (the JDK 8 `jre`
+ * subfolder, the `bin` dir itself, or a macOS `.jdk` bundle root), so resolve
+ * it and probe the common alternate layouts before giving up.
+ */
+function resolveJavaHomeBinary(javaHome: string): string | null {
+  let home: string;
+  try {
+    home = fs.realpathSync(javaHome);
+  } catch (err) {
+    logger.debug({ err, javaHome }, "JAVA_HOME does not exist");
+    return null;
+  }
+
+  const binName = javaBinName();
+  const candidates = [
+    path.join(home, "bin", binName),
+    path.join(home, "jre", "bin", binName),
+    path.join(home, "Contents", "Home", "bin", binName),
+    // JAVA_HOME pointing at <jdk>/bin or <jdk>/jre
+    path.join(home, binName),
+    path.join(path.dirname(home), "bin", binName),
+  ];
+
+  const found = candidates.find((candidate) => fs.existsSync(candidate));
+  if (found && found !== candidates[0]) {
+    logger.debug(
+      { javaHome, resolved: found },
+      "Resolved misconfigured JAVA_HOME",
+    );
+  }
+  return found ?? null;
 }
 
 /**
@@ -136,12 +207,8 @@ async function findJavaOnPath(): Promise<string | null> {
 export async function detectJava(): Promise<JavaInfo> {
   // 1. Check JAVA_HOME
   const javaHome = process.env.JAVA_HOME;
-  if (javaHome) {
-    const javaBin = path.join(
-      javaHome,
-      "bin",
-      os.platform() === "win32" ? "java.exe" : "java",
-    );
+  const javaBin = javaHome ? resolveJavaHomeBinary(javaHome) : null;
+  if (javaBin) {
     const info = await probeJava(javaBin);
     if (info) {
       logger.debug(
@@ -153,8 +220,7 @@ export async function detectJava(): Promise<JavaInfo> {
   }
 
   // 2. Check PATH
-  const pathJava = await findJavaOnPath();
-  if (pathJava) {
+  for (const pathJava of await findJavaOnPath()) {
     const info = await probeJava(pathJava);
     if (info) {
       logger.debug(
@@ -181,18 +247,116 @@ export async function detectJava(): Promise<JavaInfo> {
   return { found: false, path: null, version: null };
 }
 
+/**
+ * Run `java -version` once so the binary and its core libraries are in the OS
+ * page cache before the real launch. Returns how long the cold start took.
+ */
+export async function warmJava(javaPath: string): Promise<JavaWarmupResult> {
+  const started = performance.now();
+  try {
+    await execFileAsync(javaPath, ["-version"], { timeout: 30_000 });
+  } catch (err) {
+    throw new AppError(
+      `Failed to warm up Java at ${javaPath}: ${(err as Error).message}`,
+      400,
+      "JAVA_WARMUP_FAILED",
+    );
+  }
+  const coldStartMs = Math.round(performance.now() - started);
+  logger.debug({ path: javaPath, coldStartMs }, "Java warmed up");
+  return { path: javaPath, coldStartMs };
+}
+
+function javaProbeFailure(
+  javaPath: string,
+  error: JavaProbeError,
+  errorMessage: string,
+): JavaInfo {
+  return { found: false, path: javaPath, version: null, error, errorMessage };
+}
+
 /**
  * Validate a specific java binary path provided by the user.
+ * Failures are classified (missing, not executable, non-zero exit, not
+ * Java-looking output) so the UI can suggest the right fix.
  */
 export async function validateJavaPath(javaPath: string): Promise<JavaInfo> {
-  const info = await probeJava(javaPath);
-  if (info) {
-    return info;
+  // Bare command names ("java") are resolved via PATH by execFile
+  const isPath = javaPath.includes("/") || javaPath.includes("\\");
+  if (isPath) {
+    let stats: fs.Stats;
+    try {
+      stats = fs.statSync(javaPath);
+    } catch {
+      return javaProbeFailure(
+        javaPath,
+        "not_found",
+        `No file exists at ${javaPath}`,
+      );
+    }
+    if (stats.isDirectory()) {
+      return javaProbeFailure(
+        javaPath,
+        "not_found",
+        `${javaPath} is a directory — point to the java binary inside its bin folder`,
+      );
+    }
+  }
+
+  let stderr: string;
+  try {
+    ({ stderr } = await execFileAsync(javaPath, ["-version"], {
+      timeout: 10_000,
+    }));
+  } catch (err) {
+    const e = err as NodeJS.ErrnoException & {
+      code?: string | number;
+      stderr?: string;
+    };
+    if (e.code === "ENOENT") {
+      return javaProbeFailure(
+        javaPath,
+        "not_found",
+        `${javaPath} was not found`,
+      );
+    }
+    if (e.code === "EACCES" || e.code === "EPERM") {
+      return javaProbeFailure(
+        javaPath,
+        "not_executable",
+        os.platform() === "win32"
+          ? `${javaPath} can't be executed — check its permissions`
+          : `${javaPath} isn't executable — try: chmod +x "${javaPath}"`,
+      );
+    }
+    if (typeof e.code === "number") {
+      const detail = e.stderr?.trim().split("\n")[0];
+      return javaProbeFailure(
+        javaPath,
+        "nonzero_exit",
+        `${javaPath} -version exited with code ${e.code}${detail ? `: ${detail}` : ""}`,
+      );
+    }
+    logger.debug({ err, path: javaPath }, "Java probe failed");
+    return javaProbeFailure(
+      javaPath,
+      "nonzero_exit",
+      `Failed to run ${javaPath}: ${e.message}`,
+    );
   }
-  return { found: false, path: javaPath, version: null };
+
+  const version = parseJavaVersion(stderr);
+  if (!version) {
+    return javaProbeFailure(
+      javaPath,
+      "unparseable",
+      `${javaPath} ran but doesn't look like Java (no version in its output)`,
+    );
+  }
+  return { found: true, path: javaPath, version };
 }
 
-function parseJavaVendor(stderr: string): string {
+export function parseJavaVendor(stderr: string): string {
   if (stderr.includes("Eclipse Adoptium") || stderr.includes("Temurin")) {
     return "Eclipse Adoptium";
   } else if (stderr.includes("Oracle") || stderr.includes("Java(TM)")) {
@@ -258,9 +422,24 @@ function javaBinName(): string {
   return os.platform() === "win32" ? "java.exe" : "java";
 }
 
-function discoverJavaBinsInDir(dir: string): string[] {
+/**
+ * Directory names that can plausibly hold a JDK/JRE. Vendor names cover
+ * distributions that don't include "jdk"/"java" (e.g. Homebrew's
+ * `openjdk@17`, `zulu-21`); a leading digit covers version-named dirs
+ * like SDKMAN's `21.0.2-tem`.
+ */
+const JDK_DIR_NAME_PATTERN =
+  /jdk|jre|java|jvm|temurin|adopt|hotspot|openj9|semeru|zulu|corretto|graal|liberica|sapmachine|dragonwell|kona|microsoft|oracle|jbr|^\d/i;
+
+/** Cheap name-only check so discovery doesn't stat irrelevant folders. */
+export function looksLikeJavaDir(name: string): boolean {
+  return !name.startsWith(".") && JDK_DIR_NAME_PATTERN.test(name);
+}
+
+export function discoverJavaBinsInDir(dir: string): string[] {
   const results: string[] = [];
   const binName = javaBinName();
+  const isMac = os.platform() === "darwin";
 
   let entries: fs.Dirent[];
   try {
@@ -271,14 +450,22 @@ function discoverJavaBinsInDir(dir: string): string[] {
   }
 
   for (const entry of entries) {
-    if (!entry.isDirectory()) continue;
+    if (!entry.isDirectory() || !looksLikeJavaDir(entry.name)) continue;
     const entryPath = path.join(dir, entry.name);
 
     // macOS: <name>.jdk/Contents/Home/bin/java
-    const macosJava = path.join(entryPath, "Contents", "Home", "bin", binName);
-    if (fs.existsSync(macosJava)) {
-      results.push(macosJava);
-      continue;
+    if (isMac) {
+      const macosJava = path.join(
+        entryPath,
+        "Contents",
+        "Home",
+        "bin",
+        binName,
+      );
+      if (fs.existsSync(macosJava)) {
+        results.push(macosJava);
+        continue;
+      }
     }
 
     // Standard: <jdk-dir>/bin/java
@@ -320,15 +507,14 @@ export async function detectAllJavaInstallations(
   // 1. JAVA_HOME
   const javaHome = process.env.JAVA_HOME;
   if (javaHome) {
-    const bin = path.join(javaHome, "bin", javaBinName());
-    if (fs.existsSync(bin)) {
+    const bin = resolveJavaHomeBinary(javaHome);
+    if (bin) {
       await tryAdd(bin);
     }
   }
 
   // 2. PATH
-  const pathJava = await findJavaOnPath();
-  if (pathJava) {
+  for (const pathJava of await findJavaOnPath()) {
     await tryAdd(pathJava);
   }
 
@@ -428,17 +614,245 @@ function getAdoptiumPlatform(): { os: string; arch: string } {
   return { os: adoptiumOs, arch: adoptiumArch };
 }
 
+const ADOPTIUM_ASSETS_URL = "https://api.adoptium.net/v3/assets/latest";
+
+/** OSes that can run an x64 JDK on ARM (Windows on ARM, macOS Rosetta). */
+const EMULATES_X64_OS = new Set(["windows", "mac"]);
+
+/**
+ * Whether Adoptium has a Temurin JDK for this version/OS/arch. Null if the
+ * lookup itself failed — callers then just try the download.
+ */
+async function hasAdoptiumBuild(
+  version: number,
+  adoptiumOs: string,
+  adoptiumArch: string,
+  signal: AbortSignal,
+): Promise<boolean | null> {
+  const url = new URL(`${ADOPTIUM_ASSETS_URL}/${version}/hotspot`);
+  url.searchParams.set("os", adoptiumOs);
+  url.searchParams.set("architecture", adoptiumArch);
+  url.searchParams.set("image_type", "jdk");
+  url.searchParams.set("vendor", "eclipse");
+  try {
+    const res = await fetch(url, { signal });
+    if (!res.ok) return null;
+    const assets = (await res.json()) as unknown;
+    return Array.isArray(assets) && assets.length > 0;
+  } catch (err) {
+    if (signal.aborted) throw javaDownloadCancelled(version);
+    logger.debug({ err, version }, "Adoptium availability check failed");
+    return null;
+  }
+}
+
+/**
+ * Pick the arch to download. Many Java majors have no aarch64 build for
+ * Windows (and Java 8 has none for macOS), which used to surface as a bare
+ * 404. In that case the x64 build is used if `allowEmulated` is set and the
+ * OS can emulate it; otherwise a NO_NATIVE_BUILD error says exactly what's
+ * missing and whether the x64 build would work. macOS always falls back,
+ * since Rosetta runs x64 Java transparently.
+ */
+async function resolveDownloadArch(
+  version: number,
+  adoptiumOs: string,
+  adoptiumArch: string,
+  allowEmulated: boolean,
+  signal: AbortSignal,
+): Promise<{ arch: string; emulated: boolean }> {
+  if (adoptiumArch !== "aarch64") {
+    return { arch: adoptiumArch, emulated: false };
+  }
+  const native = await hasAdoptiumBuild(version, adoptiumOs, "aarch64", signal);
+  if (native !== false) {
+    return { arch: adoptiumArch, emulated: false };
+  }
+
+  const x64Available =
+    EMULATES_X64_OS.has(adoptiumOs) &&
+    (await hasAdoptiumBuild(version, adoptiumOs, "x64", signal)) !== false;
+  if (x64Available && (allowEmulated || adoptiumOs === "mac")) {
+    return { arch: "x64", emulated: true };
+  }
+
+  throw new AppError(
+    x64Available
+      ? `No ARM64 build of Java ${version} is available for ${adoptiumOs}. ` +
+          "The x64 build is available and runs under emulation (slower); " +
+          "retry with allowEmulated to download it."
+      : `No ARM64 build of Java ${version} is available for ${adoptiumOs}`,
+    404,
+    "NO_NATIVE_BUILD",
+    { version, arch: adoptiumArch, x64Available },
+  );
+}
+
+/** In-flight Java downloads, keyed by major version. */
+const activeJavaDownloads = new Map<number, AbortController>();
+
+/**
+ * Abort an in-flight Java download. The pending downloadJava() call rejects
+ * with a CANCELLED error after cleaning up its temp file and runtime dir.
+ * Returns false if no download for that version is running.
+ */
+export function cancelJavaDownload(version: number): boolean {
+  const controller = activeJavaDownloads.get(version);
+  if (!controller) return false;
+  controller.abort();
+  return true;
+}
+
 export async function downloadJava(
   version: number,
   dataDir: string,
+  options: { allowEmulated?: boolean } = {},
 ): Promise<JavaInstallation> {
-  const { os: adoptiumOs, arch: adoptiumArch } = getAdoptiumPlatform();
+  if (activeJavaDownloads.has(version)) {
+    throw new AppError(
+      `Java ${version} is already being downloaded`,
+      409,
+      "DOWNLOAD_IN_PROGRESS",
+    );
+  }
+
+  const controller = new AbortController();
+  activeJavaDownloads.set(version, controller);
+  try {
+    return await runJavaDownload(
+      version,
+      dataDir,
+      options.allowEmulated ?? false,
+      controller.signal,
+    );
+  } finally {
+    activeJavaDownloads.delete(version);
+  }
+}
+
+const ADOPTIUM_RELEASES_URL =
+  "https://api.adoptium.net/v3/info/available_releases";
+
+interface AdoptiumAvailableReleases {
+  available_releases: number[];
+  available_lts_releases: number[];
+}
+
+const availableVersionsCache = new TTLCache<AvailableJavaVersion[]>(
+  60 * 60 * 1000,
+);
+
+/**
+ * Feature versions Adoptium can serve, newest first, with LTS flagged.
+ * Cached for an hour.
+ */
+export function getAvailableJavaVersions(): Promise<AvailableJavaVersion[]> {
+  return availableVersionsCache.get(async () => {
+    const res = await fetch(ADOPTIUM_RELEASES_URL);
+    if (!res.ok) {
+      throw new AppError(
+        `Failed to fetch available Java versions: ${res.status} ${res.statusText}`,
+        502,
+        "UPSTREAM_ERROR",
+      );
+    }
+    const data = (await res.json()) as AdoptiumAvailableReleases;
+    const lts = new Set(data.available_lts_releases);
+    return [...data.available_releases]
+      .sort((a, b) => b - a)
+      .map((version) => ({ version, lts: lts.has(version) }));
+  });
+}
+
+function notAnArchive(version: number, reason: string): AppError {
+  return new AppError(
+    `Unexpected response downloading Java ${version}, not an archive (${reason})`,
+    502,
+    "UPSTREAM_ERROR",
+  );
+}
+
+/**
+ * Adoptium occasionally serves an HTML error page with a 200, which would
+ * otherwise surface as a confusing extraction failure.
+ */
+function assertArchiveMagic(
+  file: string,
+  version: number,
+  kind: "zip" | "gzip",
+): void {
+  const header = Buffer.alloc(2);
+  const fd = fs.openSync(file, "r");
+  let bytesRead: number;
+  try {
+    bytesRead = fs.readSync(fd, header, 0, 2, 0);
+  } finally {
+    fs.closeSync(fd);
+  }
+  const ok =
+    bytesRead === 2 &&
+    (kind === "zip"
+      ? header[0] === 0x50 && header[1] === 0x4b
+      : header[0] === 0x1f && header[1] === 0x8b);
+  if (!ok) {
+    throw notAnArchive(version, `expected ${kind} data`);
+  }
+}
 
+async function fetchJavaBinary(
+  version: number,
+  adoptiumOs: string,
+  adoptiumArch: string,
+  emulated: boolean,
+  signal: AbortSignal,
+): Promise<Response> {
   const url = `https://api.adoptium.net/v3/binary/latest/${version}/ga/${adoptiumOs}/${adoptiumArch}/jdk/hotspot/normal/eclipse`;
 
-  logger.info({ version, url }, "Downloading Java from Adoptium");
+  logger.info({ version, url, emulated }, "Downloading Java from Adoptium");
+
+  try {
+    return await fetch(url, { redirect: "follow", signal });
+  } catch (err) {
+    if (signal.aborted) throw javaDownloadCancelled(version);
+    throw err;
+  }
+}
 
-  const response = await fetch(url, { redirect: "follow" });
+async function runJavaDownload(
+  version: number,
+  dataDir: string,
+  allowEmulated: boolean,
+  signal: AbortSignal,
+): Promise<JavaInstallation> {
+  const { os: adoptiumOs, arch: nativeArch } = getAdoptiumPlatform();
+  const { arch: adoptiumArch, emulated: emulatedArch } =
+    await resolveDownloadArch(
+      version,
+      adoptiumOs,
+      nativeArch,
+      allowEmulated,
+      signal,
+    );
+
+  let emulated = emulatedArch;
+  let response = await fetchJavaBinary(
+    version,
+    adoptiumOs,
+    adoptiumArch,
+    emulated,
+    signal,
+  );
+  // The availability check can be inconclusive or stale; on Apple Silicon
+  // a missing arm64 binary still has an x64 one that runs under Rosetta
+  if (
+    response.status === 404 &&
+    adoptiumOs === "mac" &&
+    adoptiumArch === "aarch64"
+  ) {
+    logger.info({ version }, "No arm64 Java binary, retrying with x64");
+    emulated = true;
+    response = await fetchJavaBinary(version, adoptiumOs, "x64", true, signal);
+  }
   if (!response.ok) {
     throw new AppError(
       `Failed to download Java ${version}: HTTP ${response.status}`,
@@ -453,6 +867,10 @@ export async function downloadJava(
       "UPSTREAM_ERROR",
     );
   }
+  const contentType = response.headers.get("content-type") ?? "";
+  if (/^(text\/|application\/json)/i.test(contentType)) {
+    throw notAnArchive(version, `content-type ${contentType}`);
+  }
 
   const runtimeDir = path.join(
     dataDir,
@@ -460,6 +878,8 @@ export async function downloadJava(
     "runtime",
     `java-${version}`,
   );
+  // Only remove the runtime dir on cancel if this download created it
+  const runtimeDirExisted = fs.existsSync(runtimeDir);
   fs.mkdirSync(runtimeDir, { recursive: true });
 
   const isWindows = os.platform() === "win32";
@@ -474,17 +894,22 @@ export async function downloadJava(
     const nodeStream = Readable.fromWeb(
       response.body as import("node:stream/web").ReadableStream,
     );
-    await pipeline(nodeStream, fileStream);
+    await pipeline(nodeStream, fileStream, { signal });
+
+    assertArchiveMagic(tmpFile, version, isWindows ? "zip" : "gzip");
 
     logger.info({ tmpFile }, "Download complete, extracting");
 
     if (isWindows) {
+      // adm-zip extraction is synchronous, so this is the last cancel point
+      if (signal.aborted) throw javaDownloadCancelled(version);
       const AdmZip = (await import("adm-zip")).default;
       const zip = new AdmZip(tmpFile);
       zip.extractAllTo(runtimeDir, true);
     } else {
       await execFileAsync("tar", ["xzf", tmpFile, "-C", runtimeDir], {
         timeout: 120_000,
+        signal,
       });
     }
 
@@ -505,10 +930,18 @@ export async function downloadJava(
       }
     }
 
+    const emulationNote = emulated
+      ? {
+          emulated: true,
+          ...(adoptiumOs === "mac" && { translated: true }),
+          note: `No ARM64 build of Java ${version} exists; this x64 build runs under emulation and will be slower.`,
+        }
+      : {};
+
     const installation = await probeJavaInstallation(javaBinary);
     if (installation) {
       logger.info({ installation }, "Java downloaded and verified");
-      return installation;
+      return { ...installation, ...emulationNote };
     }
 
     return {
@@ -516,7 +949,22 @@ export async function downloadJava(
       path: javaBinary,
       vendor: "Eclipse Adoptium",
       fullVersion: `${version}.0.0`,
+      ...emulationNote,
     };
+  } catch (err) {
+    if (!signal.aborted) throw err;
+
+    if (!runtimeDirExisted) {
+      try {
+        fs.rmSync(runtimeDir, { recursive: true, force: true });
+      } catch (rmErr) {
+        logger.debug(
+          { err: rmErr, runtimeDir },
+          "Failed to remove partial Java runtime",
+        );
+      }
+    }
+    throw javaDownloadCancelled(version);
   } finally {
     try {
       fs.unlinkSync(tmpFile);
@@ -528,3 +976,12 @@ export async function downloadJava(
     }
   }
 }
+
+function javaDownloadCancelled(version: number): AppError {
+  logger.info({ version }, "Java download cancelled");
+  return new AppError(
+    `Java ${version} download was cancelled`,
+    409,
+    "CANCELLED",
+  );
+}
@@ -45,8 +45,12 @@ const DOWNLOAD_CONCURRENCY = 10;
 export class LibraryService {
   private librariesDir: string;
 
-  constructor(private dataDir: string) {
-    this.librariesDir = join(dataDir, "launcher", "libraries");
+  /** `launcherDir` overrides `<dataDir>/launcher`, for isolated instances */
+  constructor(
+    private dataDir: string,
+    launcherDir = join(dataDir, "launcher"),
+  ) {
+    this.librariesDir = join(launcherDir, "libraries");
     mkdirSync(this.librariesDir, { recursive: true });
   }
 
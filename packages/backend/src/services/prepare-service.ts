@@ -1,9 +1,13 @@
 import { nanoid } from "nanoid";
 import path from "node:path";
-import type { PrepareJob } from "@mc-server-manager/shared";
+import type {
+  InstanceIsolation,
+  PrepareJob,
+} from "@mc-server-manager/shared";
 import { VersionService } from "./version-service.js";
 import { AssetService } from "./asset-service.js";
 import { LibraryService } from "./library-service.js";
+import { isolatedLauncherDir } from "./instance-service.js";
 import { config } from "../config.js";
 import { ConflictError } from "../utils/errors.js";
 import { logger } from "../utils/logger.js";
@@ -24,6 +28,7 @@ export function getPrepareJob(jobId: string): PrepareJob | undefined {
 export function startPrepare(
   instanceId: string,
   mcVersion: string,
+  isolation: InstanceIsolation = "shared",
 ): PrepareJob {
   const existingJobId = activeInstancePrepares.get(instanceId);
   if (existingJobId) {
@@ -35,6 +40,7 @@ export function startPrepare(
     ) {
       throw new ConflictError(
         `A prepare job is already in progress for this instance (job: ${existingJobId})`,
+        { jobId: existingJobId },
       );
     }
     activeInstancePrepares.delete(instanceId);
@@ -60,7 +66,7 @@ export function startPrepare(
   const abortController = new AbortController();
   abortControllers.set(jobId, abortController);
 
-  runPrepare(job, abortController.signal).catch((err) => {
+  runPrepare(job, isolation, abortController.signal).catch((err) => {
     if (abortController.signal.aborted) {
       logger.info({ jobId }, "Prepare job was cancelled");
       job.phase = "failed";
@@ -105,9 +111,25 @@ export function cleanupOldPrepareJobs(): void {
   }
 }
 
-async function runPrepare(job: PrepareJob, signal: AbortSignal): Promise<void> {
+async function runPrepare(
+  job: PrepareJob,
+  isolation: InstanceIsolation,
+  signal: AbortSignal,
+): Promise<void> {
   if (signal.aborted) throw new Error("Cancelled");
 
+  // The game jar stays shared either way: it's Mojang's, byte for byte
+  const isolated = isolation === "isolated";
+  const launcherDir = isolated
+    ? isolatedLauncherDir(job.instanceId)
+    : path.join(config.dataDir, "launcher");
+  const libraries = isolated
+    ? new LibraryService(config.dataDir, launcherDir)
+    : libraryService;
+  const assets = isolated
+    ? new AssetService(config.dataDir, launcherDir)
+    : assetService;
+
   // Phase: version (5% of overall progress)
   job.phase = "version";
   job.phaseCurrent = 0;
@@ -136,7 +158,7 @@ async function runPrepare(job: PrepareJob, signal: AbortSignal): Promise<void> {
   job.phaseCurrent = 0;
   job.phaseTotal = 0;
 
-  const classpath = await libraryService.downloadLibraries(
+  const classpath = await libraries.downloadLibraries(
     versionJson,
     (current, total) => {
       job.phaseCurrent = current;
@@ -155,7 +177,7 @@ async function runPrepare(job: PrepareJob, signal: AbortSignal): Promise<void> {
   job.phaseCurrent = 0;
   job.phaseTotal = 0;
 
-  await assetService.downloadAssets(
+  await assets.downloadAssets(
     versionJson,
     (current, total) => {
       job.phaseCurrent = current;
@@ -167,13 +189,12 @@ async function runPrepare(job: PrepareJob, signal: AbortSignal): Promise<void> {
 
   if (signal.aborted) throw new Error("Cancelled");
 
-  const launcherDir = path.join(config.dataDir, "launcher");
   const nativesDir = path.join(
     launcherDir,
     "natives",
     `${job.instanceId}-prepare`,
   );
-  await libraryService.extractNatives(versionJson, nativesDir);
+  await libraries.extractNatives(versionJson, nativesDir);
 
   const assetIndexObj = versionJson.assetIndex as { id: string } | undefined;
   const assetIndex = assetIndexObj?.id ?? (versionJson.assets as string) ?? "";
@@ -4,7 +4,7 @@
  * Responsibilities:
  *  - Spawn the Java process with correct args
  *  - Capture stdout/stderr into a ConsoleBuffer
- *  - Emit events: 'console', 'status', 'players'
+ *  - Emit events: 'console', 'status', 'players', 'crash'
  *  - Write commands to stdin
  *  - Detect "running" state by parsing the "Done" log line
  *  - Detect player join/leave from stdout
@@ -46,8 +46,8 @@ const DEFAULT_RUNNING_TIMEOUT_MS = 120_000;
 /** Default command sent to stdin for graceful stop. */
 const DEFAULT_STOP_COMMAND = "stop";
 
-/** Grace period (ms) after sending stop command before we escalate to SIGTERM. */
-const GRACEFUL_STOP_TIMEOUT_MS = 30_000;
+/** Default grace period (s) after sending stop command before we escalate to SIGTERM. */
+export const DEFAULT_STOP_TIMEOUT_SECS = 30;
 
 /** Time (ms) after SIGTERM before we escalate to SIGKILL. */
 const SIGTERM_TIMEOUT_MS = 10_000;
@@ -69,6 +69,9 @@ export interface ServerProcessEvents {
   console: (serverId: string, entry: ConsoleLine) => void;
   status: (serverId: string, status: ServerStatus) => void;
   players: (serverId: string, players: string[]) => void;
+  crash: (serverId: string, code: number | null, signal: string | null) => void;
+  /** The graceful stop timed out and the process is being killed. */
+  forceKill: (serverId: string, timeoutSecs: number) => void;
 }
 
 export declare interface ServerProcess {
@@ -247,9 +250,10 @@ export class ServerProcess extends EventEmitter {
   }
 
   /**
-   * Graceful stop: send "stop" command, wait for exit, then SIGTERM, then SIGKILL.
+   * Graceful stop: send "stop" command, wait up to `timeoutSecs` for exit,
+   * then SIGTERM, then SIGKILL.
    */
-  stop(): void {
+  stop(timeoutSecs = DEFAULT_STOP_TIMEOUT_SECS): void {
     if (this._status !== "running" && this._status !== "starting") {
       throw new ConflictError(
         `Cannot stop server ${this.serverId}: current status is "${this._status}"`,
@@ -276,12 +280,13 @@ export class ServerProcess extends EventEmitter {
     this.stopGraceTimer = setTimeout(() => {
       if (this.isAlive) {
         logger.warn(
-          { serverId: this.serverId },
+          { serverId: this.serverId, timeoutSecs },
           "Graceful stop timed out — sending SIGTERM",
         );
+        this.emit("forceKill", this.serverId, timeoutSecs);
         this.escalateToSigterm();
       }
-    }, GRACEFUL_STOP_TIMEOUT_MS);
+    }, timeoutSecs * 1000);
   }
 
   /**
@@ -378,6 +383,12 @@ export class ServerProcess extends EventEmitter {
       );
       this.emit("console", this.serverId, entry);
       this.setStatus("crashed");
+
+      // A zero exit outside of stop() means someone typed "stop" into the
+      // console — treat it as a crash for status purposes, but not as a failure.
+      if (code !== 0) {
+        this.emit("crash", this.serverId, code, signal);
+      }
     }
   }
 
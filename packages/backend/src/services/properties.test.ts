@@ -1,4 +1,16 @@
-import { parseProperties, serializeProperties } from "./properties.js";
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import {
+  InvalidPropertiesError,
+  parseProperties,
+  serializeProperties,
+  validateServerProperties,
+  writeServerProperties,
+} from "./properties.js";
+import {
+  writeServerProperties as writeDefaultServerProperties,
+} from "./server-setup.js";
 
 describe("Properties Parser", () => {
   describe("parseProperties", () => {
@@ -174,4 +186,94 @@ another-unknown=test`;
       expect(reparsed["another-unknown"]).toBe("test");
     });
   });
+
+  describe("validateServerProperties", () => {
+    it("accepts valid values", () => {
+      const issues = validateServerProperties({
+        "server-port": "25565",
+        "query.port": "25565",
+        "max-players": "0",
+        gamemode: "creative",
+        difficulty: "hard",
+        "white-list": "true",
+        "online-mode": "false",
+      });
+      expect(issues).toEqual([]);
+    });
+
+    it("rejects out-of-range and non-numeric ports", () => {
+      const issues = validateServerProperties({
+        "server-port": "70000",
+        "query.port": "abc",
+      });
+      expect(issues.map((i) => i.key)).toEqual(["server-port", "query.port"]);
+    });
+
+    it("rejects negative max-players", () => {
+      const issues = validateServerProperties({ "max-players": "-1" });
+      expect(issues).toHaveLength(1);
+      expect(issues[0].key).toBe("max-players");
+    });
+
+    it("rejects unknown enum values but accepts legacy numeric ones", () => {
+      expect(validateServerProperties({ gamemode: "god" })).toHaveLength(1);
+      expect(validateServerProperties({ difficulty: "2" })).toEqual([]);
+    });
+
+    it("rejects non-boolean flags", () => {
+      const issues = validateServerProperties({ "white-list": "yes" });
+      expect(issues[0].message).toContain("white-list");
+    });
+
+    it("passes unknown keys through untouched", () => {
+      const issues = validateServerProperties({
+        "custom-mod-property": "anything goes",
+      });
+      expect(issues).toEqual([]);
+    });
+  });
+
+  describe("writeServerProperties", () => {
+    let serverDir: string;
+
+    beforeEach(() => {
+      serverDir = fs.mkdtempSync(path.join(os.tmpdir(), "mc-test-props-"));
+    });
+
+    afterEach(() => {
+      fs.rmSync(serverDir, { recursive: true, force: true });
+    });
+
+    const propsFile = () => path.join(serverDir, "server.properties");
+
+    it("refuses invalid values without touching the file", () => {
+      fs.writeFileSync(propsFile(), "server-port=25565\n");
+
+      expect(() =>
+        writeServerProperties(serverDir, { "server-port": "99999" }),
+      ).toThrow(InvalidPropertiesError);
+      expect(fs.readFileSync(propsFile(), "utf-8")).toBe(
+        "server-port=25565\n",
+      );
+    });
+
+    it("only validates the changed values when given", () => {
+      writeServerProperties(
+        serverDir,
+        { gamemode: "legacy-mod-mode", motd: "Hello" },
+        { motd: "Hello" },
+      );
+      expect(parseProperties(fs.readFileSync(propsFile(), "utf-8"))).toEqual({
+        gamemode: "legacy-mod-mode",
+        motd: "Hello",
+      });
+    });
+
+    it("is enforced by the server setup writer too", () => {
+      expect(() =>
+        writeDefaultServerProperties(serverDir, { "max-players": "-5" }),
+      ).toThrow(InvalidPropertiesError);
+      expect(fs.existsSync(propsFile())).toBe(false);
+    });
+  });
 });
@@ -11,7 +11,7 @@
 
 import fs from 'node:fs';
 import path from 'node:path';
-import type { PropertyGroup } from '@mc-server-manager/shared';
+import type { PropertyGroup, PropertyValidationIssue } from '@mc-server-manager/shared';
 import { logger } from '../utils/logger.js';
 
 // ============================================================
@@ -437,14 +437,101 @@ export function readServerProperties(serverDir: string): Record<string, string>
 /**
  * Write a full set of properties to server.properties.
  * Overwrites the existing file entirely.
+ *
+ * Throws InvalidPropertiesError, before touching the file, if any value in
+ * `changed` is malformed. It defaults to all of `props`; pass only the edited
+ * values so legacy values already in the file don't block unrelated edits.
  */
-export function writeServerProperties(serverDir: string, props: Record<string, string>): void {
+export function writeServerProperties(
+  serverDir: string,
+  props: Record<string, string>,
+  changed: Record<string, string> = props,
+): void {
+  assertValidServerProperties(changed);
   const propsPath = path.join(serverDir, 'server.properties');
   const content = serializeProperties(props);
   fs.writeFileSync(propsPath, content, 'utf-8');
   logger.info({ path: propsPath, keys: Object.keys(props).length }, 'server.properties updated');
 }
 
+// ============================================================
+// Validation
+// ============================================================
+
+const GAMEMODES = ['survival', 'creative', 'adventure', 'spectator'];
+const DIFFICULTIES = ['peaceful', 'easy', 'normal', 'hard'];
+
+/** Pre-1.14 servers wrote gamemode/difficulty as 0-3; still accepted by the game. */
+const LEGACY_ENUM_INDEX = /^[0-3]$/;
+
+function checkPort(value: string): string | null {
+  if (!/^\d+$/.test(value)) return 'must be a port number';
+  const port = parseInt(value, 10);
+  if (port < 1 || port > 65535) return 'must be between 1 and 65535';
+  return null;
+}
+
+function checkNonNegativeInt(value: string): string | null {
+  return /^\d+$/.test(value) ? null : 'must be a non-negative integer';
+}
+
+function checkEnum(allowed: string[]) {
+  return (value: string): string | null => {
+    if (allowed.includes(value) || LEGACY_ENUM_INDEX.test(value)) return null;
+    return `must be one of: ${allowed.join(', ')}`;
+  };
+}
+
+function checkBoolean(value: string): string | null {
+  return value === 'true' || value === 'false' ? null : 'must be "true" or "false"';
+}
+
+/**
+ * Per-key validators for values that break the server when malformed.
+ * Keys not listed here (including unknown/mod keys) are passed through as-is.
+ */
+const PROPERTY_VALIDATORS: Record<string, (value: string) => string | null> = {
+  'server-port': checkPort,
+  'query.port': checkPort,
+  'rcon.port': checkPort,
+  'max-players': checkNonNegativeInt,
+  gamemode: checkEnum(GAMEMODES),
+  difficulty: checkEnum(DIFFICULTIES),
+  'white-list': checkBoolean,
+  'online-mode': checkBoolean,
+};
+
+/**
+ * Validate property values before writing them to disk.
+ * Returns one issue per invalid key; an empty array means the update is safe.
+ */
+export function validateServerProperties(props: Record<string, string>): PropertyValidationIssue[] {
+  const issues: PropertyValidationIssue[] = [];
+  for (const [key, value] of Object.entries(props)) {
+    const check = PROPERTY_VALIDATORS[key];
+    if (!check) continue;
+    const message = check(value.trim());
+    if (message) {
+      issues.push({ key, value, message: `${key} ${message}` });
+    }
+  }
+  return issues;
+}
+
+/** Thrown when property values fail validation; nothing is written. */
+export class InvalidPropertiesError extends Error {
+  constructor(public issues: PropertyValidationIssue[]) {
+    super(issues.map((i) => i.message).join('; '));
+    this.name = 'InvalidPropertiesError';
+  }
+}
+
+/** Throw InvalidPropertiesError if validateServerProperties finds any issue. */
+export function assertValidServerProperties(props: Record<string, string>): void {
+  const issues = validateServerProperties(props);
+  if (issues.length > 0) throw new InvalidPropertiesError(issues);
+}
+
 /**
  * Check if a property key is in our known metadata definitions.
  */
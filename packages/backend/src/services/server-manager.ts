@@ -11,20 +11,34 @@
  *  - Port conflict pre-check (OS-level) before starting
  *  - Graceful shutdown of all running servers
  *  - Enriching Server records with runtime status
+ *  - Crash classification and opt-in auto-restart with capped backoff
  */
 
-import net from "node:net";
 import type {
   Server,
+  ServerCrashCause,
   ServerStatus,
+  ServerStopOutcome,
   ServerWithStatus,
 } from "@mc-server-manager/shared";
 import { getServerById } from "../models/server.js";
 import { ServerProcess, type ProcessConfig } from "./process.js";
 import type { ConsoleLine } from "./console-buffer.js";
+import { classifyServerCrash } from "./crash-analyzer.js";
+import { readServerProperties } from "./properties.js";
 import { getProvider } from "../providers/registry.js";
 import { logger } from "../utils/logger.js";
 import { AppError } from "../utils/errors.js";
+import { isTcpPortFree, isUdpPortFree } from "../utils/ports.js";
+
+/** Details about an unexpected server exit, passed to onExit listeners. */
+export interface ServerExitInfo {
+  exitCode: number | null;
+  signal: string | null;
+  cause: ServerCrashCause;
+  restartScheduled: boolean;
+  restartAttempt: number | null;
+}
 
 class ServerManager {
   /** Active processes keyed by server ID. */
@@ -46,19 +60,52 @@ class ServerManager {
   private playersListeners: Array<
     (serverId: string, players: string[]) => void
   > = [];
+  private exitListeners: Array<
+    (serverId: string, info: ServerExitInfo) => void
+  > = [];
+  private forceKillListeners: Array<
+    (serverId: string, timeoutSecs: number) => void
+  > = [];
+
+  /** Timestamps of recent auto-restart attempts, keyed by server ID. */
+  private restartAttempts = new Map<string, number[]>();
+
+  /** Pending auto-restart timers, keyed by server ID. */
+  private pendingRestarts = new Map<string, ReturnType<typeof setTimeout>>();
 
   // --- Event registration for external consumers (WebSocket layer) ---
+  // Each returns an unsubscribe function so the WS layer can be torn down
+  // and re-attached (desktop backend restart) without leaking listeners.
+
+  onConsole(
+    listener: (serverId: string, entry: ConsoleLine) => void,
+  ): () => void {
+    return addListener(this.consoleListeners, listener);
+  }
 
-  onConsole(listener: (serverId: string, entry: ConsoleLine) => void): void {
-    this.consoleListeners.push(listener);
+  onStatus(
+    listener: (serverId: string, status: ServerStatus) => void,
+  ): () => void {
+    return addListener(this.statusListeners, listener);
   }
 
-  onStatus(listener: (serverId: string, status: ServerStatus) => void): void {
-    this.statusListeners.push(listener);
+  onPlayers(
+    listener: (serverId: string, players: string[]) => void,
+  ): () => void {
+    return addListener(this.playersListeners, listener);
   }
 
-  onPlayers(listener: (serverId: string, players: string[]) => void): void {
-    this.playersListeners.push(listener);
+  onExit(
+    listener: (serverId: string, info: ServerExitInfo) => void,
+  ): () => void {
+    return addListener(this.exitListeners, listener);
+  }
+
+  /** Fires when a graceful stop times out and the process has to be killed. */
+  onForceKill(
+    listener: (serverId: string, timeoutSecs: number) => void,
+  ): () => void {
+    return addListener(this.forceKillListeners, listener);
   }
 
   // --- Provisioning management ---
@@ -148,6 +195,9 @@ class ServerManager {
    * Start a server. Reads config from DB, validates via provider, spawns the process.
    */
   async start(serverId: string): Promise<ServerWithStatus> {
+    // A manual start supersedes any scheduled auto-restart
+    this.cancelPendingRestart(serverId);
+
     // Block if provisioning
     if (this.provisioningServers.has(serverId)) {
       throw new AppError(
@@ -181,14 +231,19 @@ class ServerManager {
       throw new AppError(validationError, 400, "INVALID_INSTALLATION");
     }
 
-    // Check for OS-level port conflict
-    const portAvailable = await this.checkPortAvailable(server.port);
-    if (!portAvailable) {
-      throw new AppError(
-        `Port ${server.port} is already in use on this system`,
-        409,
-        "PORT_IN_USE",
-      );
+    // Check for OS-level port conflicts on every port the server will bind
+    for (const { label, port, protocol } of this.getBoundPorts(server)) {
+      const available =
+        protocol === "udp"
+          ? await isUdpPortFree(port)
+          : await isTcpPortFree(port);
+      if (!available) {
+        throw new AppError(
+          `${label} port ${port} is already in use on this system`,
+          409,
+          "PORT_IN_USE",
+        );
+      }
     }
 
     // Build launch config from provider
@@ -220,9 +275,10 @@ class ServerManager {
   }
 
   /**
-   * Graceful stop: sends stop command, waits for exit, escalates to SIGTERM/SIGKILL.
+   * Graceful stop: sends stop command, waits up to `timeoutSecs` for exit
+   * (default DEFAULT_STOP_TIMEOUT_SECS), escalates to SIGTERM/SIGKILL.
    */
-  stop(serverId: string): ServerWithStatus {
+  stop(serverId: string, timeoutSecs?: number): ServerWithStatus {
     if (this.provisioningServers.has(serverId)) {
       throw new AppError(
         "Server is currently being provisioned. Cannot stop.",
@@ -234,6 +290,10 @@ class ServerManager {
     const server = getServerById(serverId);
     const proc = this.processes.get(serverId);
 
+    // Stopping a crashed server cancels its pending auto-restart
+    this.cancelPendingRestart(serverId);
+    this.restartAttempts.delete(serverId);
+
     if (!proc || (proc.status !== "running" && proc.status !== "starting")) {
       throw new AppError(
         `Server "${server.name}" is not running`,
@@ -242,7 +302,7 @@ class ServerManager {
       );
     }
 
-    proc.stop();
+    proc.stop(timeoutSecs);
     return this.enrichWithStatus(server);
   }
 
@@ -285,6 +345,8 @@ class ServerManager {
     const server = getServerById(serverId);
     const proc = this.processes.get(serverId);
 
+    this.cancelPendingRestart(serverId);
+
     if (!proc || !proc.isAlive) {
       throw new AppError(
         `Server "${server.name}" has no active process to kill`,
@@ -320,68 +382,115 @@ class ServerManager {
    * Returns a promise that resolves when all servers have stopped.
    */
   async shutdownAll(): Promise<void> {
+    await this.stopAll({ graceful: true });
+    logger.info("All servers shut down");
+  }
+
+  /**
+   * Stop every running server at once and report how each one went.
+   * Graceful stops send `stop` and are force-killed if they outlast the
+   * stop timeout; otherwise servers are killed straight away. A failure on
+   * one server doesn't affect the others. Pending auto-restarts are
+   * cancelled.
+   */
+  async stopAll(
+    options: { graceful?: boolean } = {},
+  ): Promise<ServerStopOutcome[]> {
+    const graceful = options.graceful ?? true;
+    for (const serverId of [...this.pendingRestarts.keys()]) {
+      this.cancelPendingRestart(serverId);
+    }
+
     const running = [...this.processes.entries()].filter(
       ([, proc]) => proc.status === "running" || proc.status === "starting",
     );
 
     if (running.length === 0) {
-      logger.info("No running servers to shut down");
-      return;
+      logger.info("No running servers to stop");
+      return [];
     }
 
     logger.info(
-      { count: running.length },
-      "Shutting down all running servers...",
+      { count: running.length, graceful },
+      "Stopping all running servers...",
     );
 
-    const stopPromises = running.map(([id, proc]) => {
-      return new Promise<void>((resolve) => {
-        const onStatus = (_serverId: string, status: ServerStatus) => {
-          if (status === "stopped" || status === "crashed") {
-            proc.off("status", onStatus);
-            resolve();
-          }
-        };
-        proc.on("status", onStatus);
+    return Promise.all(
+      running.map(([id, proc]) => this.stopAndWait(id, proc, graceful)),
+    );
+  }
+
+  // --- Internal helpers ---
+
+  private stopAndWait(
+    serverId: string,
+    proc: ServerProcess,
+    graceful: boolean,
+  ): Promise<ServerStopOutcome> {
+    const started = Date.now();
+    let name = serverId;
+    try {
+      name = getServerById(serverId).name;
+    } catch {
+      // Deleted while running — report it by id
+    }
+
+    return new Promise((resolve) => {
+      let forceKilled = !graceful;
+      let timer: ReturnType<typeof setTimeout> | undefined;
+
+      const finish = (
+        outcome: ServerStopOutcome["outcome"],
+        error?: string,
+      ) => {
+        clearTimeout(timer);
+        proc.off("status", onStatus);
+        proc.off("forceKill", onForceKill);
+        resolve({
+          serverId,
+          name,
+          outcome,
+          durationMs: Date.now() - started,
+          ...(error ? { error } : {}),
+        });
+      };
+      const onStatus = (_serverId: string, status: ServerStatus) => {
+        if (status === "stopped" || status === "crashed") {
+          finish(forceKilled ? "killed" : "stopped");
+        }
+      };
+      const onForceKill = () => {
+        forceKilled = true;
+      };
+      proc.on("status", onStatus);
+      proc.on("forceKill", onForceKill);
 
+      try {
+        if (graceful) proc.stop();
+        else proc.kill();
+      } catch (err) {
+        logger.warn({ err, serverId }, "Error stopping server");
+        finish("failed", err instanceof Error ? err.message : String(err));
+        return;
+      }
+
+      // Safety net in case the process's own force-kill never lands
+      timer = setTimeout(() => {
+        if (!proc.isAlive) {
+          finish(forceKilled ? "killed" : "stopped");
+          return;
+        }
+        logger.warn({ serverId }, "Force-killing server that didn't stop");
         try {
-          proc.stop();
+          proc.kill();
+          finish("killed", "Did not stop in time");
         } catch (err) {
-          logger.warn(
-            { err, serverId: id },
-            "Error during graceful server stop",
-          );
-          resolve();
+          finish("failed", err instanceof Error ? err.message : String(err));
         }
-
-        // Safety timeout per server
-        setTimeout(() => {
-          proc.off("status", onStatus);
-          if (proc.isAlive) {
-            logger.warn(
-              { serverId: id },
-              "Force-killing server during shutdown",
-            );
-            try {
-              proc.kill();
-            } catch (err) {
-              logger.debug(
-                { err, serverId: id },
-                "Force-kill failed — process likely already dead",
-              );
-            }
-          }
-          resolve();
-        }, 45_000);
-      });
+      }, STOP_ALL_TIMEOUT_MS);
     });
-
-    await Promise.all(stopPromises);
-    logger.info("All servers shut down");
   }
 
-  // --- Internal helpers ---
-
   /**
    * Wire up a ServerProcess's events to our broadcast system.
    */
@@ -415,27 +524,178 @@ class ServerManager {
         }
       }
     });
+
+    proc.on("forceKill", (serverId, timeoutSecs) => {
+      for (const listener of this.forceKillListeners) {
+        try {
+          listener(serverId, timeoutSecs);
+        } catch (err) {
+          logger.error({ err }, "Error in force-kill listener");
+        }
+      }
+    });
+
+    proc.on("crash", (_serverId, code, signal) => {
+      this.handleCrash(proc, code, signal);
+    });
   }
 
   /**
-   * Check if a port is available by attempting to bind to it briefly.
+   * Classify a crash, schedule an auto-restart if enabled, and notify listeners.
    */
-  private checkPortAvailable(port: number): Promise<boolean> {
-    return new Promise((resolve) => {
-      const server = net.createServer();
-      server.once("error", () => {
-        resolve(false);
+  private handleCrash(
+    proc: ServerProcess,
+    code: number | null,
+    signal: string | null,
+  ): void {
+    const serverId = proc.serverId;
+    const cause = classifyServerCrash(
+      proc.getConsoleHistory().map((entry) => entry.line),
+    );
+
+    let autoRestart = false;
+    try {
+      autoRestart = getServerById(serverId).autoRestart;
+    } catch {
+      // Server was deleted while running — nothing to restart
+    }
+
+    let restartAttempt: number | null = null;
+    // Retrying a port conflict can't succeed, so don't burn attempts on it
+    if (autoRestart && cause !== "port_in_use") {
+      restartAttempt = this.scheduleAutoRestart(serverId);
+    }
+
+    logger.warn(
+      { serverId, code, signal, cause, restartAttempt },
+      "Server crashed",
+    );
+
+    const info: ServerExitInfo = {
+      exitCode: code,
+      signal,
+      cause,
+      restartScheduled: restartAttempt !== null,
+      restartAttempt,
+    };
+    for (const listener of this.exitListeners) {
+      try {
+        listener(serverId, info);
+      } catch (err) {
+        logger.error({ err }, "Error in exit listener");
+      }
+    }
+  }
+
+  /**
+   * Schedule a restart with exponential backoff.
+   * Returns the attempt number, or null if the retry cap has been reached.
+   */
+  private scheduleAutoRestart(serverId: string): number | null {
+    const now = Date.now();
+    const recent = (this.restartAttempts.get(serverId) ?? []).filter(
+      (t) => now - t < AUTO_RESTART_WINDOW_MS,
+    );
+
+    if (recent.length >= AUTO_RESTART_MAX_ATTEMPTS) {
+      this.restartAttempts.set(serverId, recent);
+      logger.error(
+        { serverId, attempts: recent.length },
+        "Auto-restart limit reached — leaving server stopped",
+      );
+      return null;
+    }
+
+    recent.push(now);
+    this.restartAttempts.set(serverId, recent);
+
+    const attempt = recent.length;
+    const delay = AUTO_RESTART_BASE_DELAY_MS * 2 ** (attempt - 1);
+
+    this.cancelPendingRestart(serverId);
+    const timer = setTimeout(() => {
+      this.pendingRestarts.delete(serverId);
+      logger.info({ serverId, attempt }, "Auto-restarting crashed server");
+      // start() clears pending restarts but must keep the attempt history
+      this.start(serverId).catch((err) => {
+        logger.error({ err, serverId }, "Auto-restart failed");
       });
-      server.once("listening", () => {
-        server.close(() => resolve(true));
+    }, delay);
+    this.pendingRestarts.set(serverId, timer);
+
+    logger.info({ serverId, attempt, delayMs: delay }, "Auto-restart scheduled");
+    return attempt;
+  }
+
+  private cancelPendingRestart(serverId: string): void {
+    const timer = this.pendingRestarts.get(serverId);
+    if (timer) {
+      clearTimeout(timer);
+      this.pendingRestarts.delete(serverId);
+    }
+  }
+
+  /**
+   * Ports the server will bind on start, read from server.properties.
+   * Falls back to the stored port when the file is missing or has no
+   * server-port (e.g. before first launch generates it).
+   */
+  private getBoundPorts(
+    server: Server,
+  ): { label: string; port: number; protocol: "tcp" | "udp" }[] {
+    const props = readServerProperties(server.directory);
+    const parsePort = (value: string | undefined, fallback: number) => {
+      const port = value ? parseInt(value, 10) : NaN;
+      return Number.isInteger(port) && port > 0 && port <= 65535
+        ? port
+        : fallback;
+    };
+
+    const gamePort = parsePort(props["server-port"], server.port);
+    const ports: { label: string; port: number; protocol: "tcp" | "udp" }[] = [
+      { label: "Server", port: gamePort, protocol: "tcp" },
+    ];
+    if (props["enable-rcon"] === "true") {
+      // Vanilla default is 25575
+      const rconPort = parsePort(props["rcon.port"], 25575);
+      if (rconPort !== gamePort) {
+        ports.push({ label: "RCON", port: rconPort, protocol: "tcp" });
+      }
+    }
+    if (props["enable-query"] === "true") {
+      // Query is UDP and defaults to the game port
+      ports.push({
+        label: "Query",
+        port: parsePort(props["query.port"], gamePort),
+        protocol: "udp",
       });
-      server.listen(port, "0.0.0.0");
-    });
+    }
+    return ports;
   }
 }
 
+function addListener<T>(list: T[], listener: T): () => void {
+  list.push(listener);
+  return () => {
+    const idx = list.indexOf(listener);
+    if (idx !== -1) list.splice(idx, 1);
+  };
+}
+
 /** Timeout for restart wait (matches process.ts constants + buffer). */
 const GRACEFUL_STOP_TIMEOUT_MS = 30_000;
 
+/** Per-server cap on a stop-all before the server is killed outright. */
+const STOP_ALL_TIMEOUT_MS = 45_000;
+
+/** Max auto-restarts within AUTO_RESTART_WINDOW_MS before giving up. */
+const AUTO_RESTART_MAX_ATTEMPTS = 3;
+
+/** Sliding window for counting auto-restart attempts (10 minutes). */
+const AUTO_RESTART_WINDOW_MS = 10 * 60 * 1000;
+
+/** Delay before the first auto-restart; doubles on each subsequent attempt. */
+const AUTO_RESTART_BASE_DELAY_MS = 5_000;
+
 /** Singleton instance. */
 export const serverManager = new ServerManager();
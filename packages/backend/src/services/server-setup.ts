@@ -1,6 +1,7 @@
 import fs from 'node:fs';
 import path from 'node:path';
 import { logger } from '../utils/logger.js';
+import { assertValidServerProperties } from './properties.js';
 
 /**
  * Write eula.txt accepting the Minecraft EULA.
@@ -64,12 +65,14 @@ const DEFAULT_PROPERTIES: Record<string, string> = {
 
 /**
  * Write a default server.properties file.
- * Accepts optional overrides (e.g., port from the server config).
+ * Accepts optional overrides (e.g., port from the server config), which must
+ * pass property validation or InvalidPropertiesError is thrown.
  */
 export function writeServerProperties(
   serverDir: string,
   overrides: Record<string, string> = {}
 ): void {
+  assertValidServerProperties(overrides);
   const props = { ...DEFAULT_PROPERTIES, ...overrides };
   const propsPath = path.join(serverDir, 'server.properties');
 
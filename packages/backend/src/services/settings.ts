@@ -13,6 +13,9 @@ const SETTING_KEYS = [
   "maxConsoleLines",
   "curseforgeApiKey",
   "showOverridePreview",
+  "desktopNotifications",
+  "maxConcurrentGames",
+  "crashNotifications",
 ] as const;
 
 const DEFAULTS: AppSettings = {
@@ -22,6 +25,9 @@ const DEFAULTS: AppSettings = {
   maxConsoleLines: 1000,
   curseforgeApiKey: "",
   showOverridePreview: false,
+  desktopNotifications: true,
+  maxConcurrentGames: 0,
+  crashNotifications: true,
 };
 
 /**
@@ -50,6 +56,15 @@ export function getAllSettings(): AppSettings {
     showOverridePreview: stored.showOverridePreview
       ? stored.showOverridePreview === "true"
       : DEFAULTS.showOverridePreview,
+    desktopNotifications: stored.desktopNotifications
+      ? stored.desktopNotifications === "true"
+      : DEFAULTS.desktopNotifications,
+    maxConcurrentGames: stored.maxConcurrentGames
+      ? parseInt(stored.maxConcurrentGames, 10) || DEFAULTS.maxConcurrentGames
+      : DEFAULTS.maxConcurrentGames,
+    crashNotifications: stored.crashNotifications
+      ? stored.crashNotifications === "true"
+      : DEFAULTS.crashNotifications,
   };
 }
 
@@ -5,10 +5,12 @@ import { join } from "node:path";
 import { Readable } from "node:stream";
 import { pipeline } from "node:stream/promises";
 import { createWriteStream } from "node:fs";
-import type {
-  MinecraftVersion,
-  VersionManifest,
-  VersionType,
+import {
+  summarizeMcVersions,
+  type McVersionSummary,
+  type MinecraftVersion,
+  type VersionManifest,
+  type VersionType,
 } from "@mc-server-manager/shared";
 import { AppError, NotFoundError } from "../utils/errors.js";
 import { logger } from "../utils/logger.js";
@@ -60,6 +62,14 @@ export class VersionService {
     return manifest.versions.filter((v) => v.type === type);
   }
 
+  /** Id, type and release time only, for the instance editor. */
+  async getVersionSummaries(
+    includeSnapshots: boolean,
+  ): Promise<McVersionSummary[]> {
+    const manifest = await this.getManifest();
+    return summarizeMcVersions(manifest.versions, includeSnapshots);
+  }
+
   async downloadVersionJson(
     versionId: string,
     signal?: AbortSignal,
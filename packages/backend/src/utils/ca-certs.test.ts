@@ -0,0 +1,43 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { rootCertificates } from "node:tls";
+import { loadExtraCaCerts, parseCaBundle } from "./ca-certs.js";
+
+const VALID = rootCertificates[0];
+const BROKEN =
+  "-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydGlmaWNhdGU=\n-----END CERTIFICATE-----";
+
+describe("parseCaBundle", () => {
+  it("splits a bundle and keeps certificates that parse", () => {
+    const bundle = parseCaBundle(`# corp root\n${VALID}\n${BROKEN}\n`);
+    expect(bundle.certs).toEqual([VALID]);
+    expect(bundle.invalid).toBe(1);
+  });
+
+  it("finds nothing in non-PEM input", () => {
+    expect(parseCaBundle("hello")).toEqual({ certs: [], invalid: 0 });
+  });
+});
+
+describe("loadExtraCaCerts", () => {
+  it("returns null when unset or unreadable", () => {
+    expect(loadExtraCaCerts({})).toBeNull();
+    expect(
+      loadExtraCaCerts({ MC_EXTRA_CA_CERTS: "/nonexistent/ca.pem" }),
+    ).toBeNull();
+  });
+
+  it("adds the bundle's certificates to the default roots", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "ca-certs-"));
+    const file = path.join(dir, "ca.pem");
+    fs.writeFileSync(file, `${VALID}\n${BROKEN}\n`);
+    try {
+      const ca = loadExtraCaCerts({ MC_EXTRA_CA_CERTS: file });
+      expect(ca).toHaveLength(rootCertificates.length + 1);
+      expect(ca?.at(-1)).toBe(VALID);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
@@ -0,0 +1,71 @@
+/**
+ * Extra root certificates for TLS-inspecting corporate proxies, which
+ * re-sign every HTTPS response with their own CA. NODE_EXTRA_CA_CERTS only
+ * works if set before the process starts, which a desktop app launched from
+ * a menu can't arrange, so MC_EXTRA_CA_CERTS is read here instead.
+ */
+
+import { X509Certificate } from "node:crypto";
+import fs from "node:fs";
+import { rootCertificates } from "node:tls";
+import { logger } from "./logger.js";
+
+const PEM_CERTIFICATE =
+  /-----BEGIN CERTIFICATE-----[\s\S]*?-----END CERTIFICATE-----/g;
+
+export interface CaBundle {
+  /** PEM blocks that parsed as X.509 certificates */
+  certs: string[];
+  /** PEM blocks that didn't */
+  invalid: number;
+}
+
+/** Split a PEM bundle into its certificates, checking each one parses. */
+export function parseCaBundle(pem: string): CaBundle {
+  const bundle: CaBundle = { certs: [], invalid: 0 };
+  for (const block of pem.match(PEM_CERTIFICATE) ?? []) {
+    try {
+      new X509Certificate(block);
+      bundle.certs.push(block);
+    } catch {
+      bundle.invalid++;
+    }
+  }
+  return bundle;
+}
+
+/**
+ * Node's default roots plus the certificates in MC_EXTRA_CA_CERTS, or null
+ * when the variable is unset or yields nothing usable (so the defaults stay
+ * untouched). Problems are logged, never thrown — a bad path shouldn't stop
+ * the app for users who don't need it.
+ */
+export function loadExtraCaCerts(
+  env: NodeJS.ProcessEnv = process.env,
+): string[] | null {
+  const file = env.MC_EXTRA_CA_CERTS?.trim();
+  if (!file) return null;
+
+  let pem: string;
+  try {
+    pem = fs.readFileSync(file, "utf-8");
+  } catch (err) {
+    logger.warn(
+      { file, error: err instanceof Error ? err.message : String(err) },
+      "Could not read MC_EXTRA_CA_CERTS",
+    );
+    return null;
+  }
+
+  const { certs, invalid } = parseCaBundle(pem);
+  if (invalid > 0) {
+    logger.warn({ file, invalid }, "Skipping unparseable certificates");
+  }
+  if (certs.length === 0) {
+    logger.warn({ file }, "MC_EXTRA_CA_CERTS contains no usable certificates");
+    return null;
+  }
+  logger.info({ file, count: certs.length }, "Added extra CA certificates");
+  // Passing `ca` replaces the default roots rather than adding to them
+  return [...rootCertificates, ...certs];
+}
@@ -0,0 +1,35 @@
+/**
+ * Free disk space lookup, shared by anything that writes large files
+ * (Java runtimes, server jars, world backups).
+ */
+
+import fs from "node:fs";
+import path from "node:path";
+import { AppError } from "./errors.js";
+
+/**
+ * Return the bytes available to this process on the volume holding `dir`.
+ * If `dir` doesn't exist yet, the nearest existing ancestor is queried
+ * (it lives on the same volume in all practical setups).
+ */
+export async function getFreeDiskSpace(dir: string): Promise<number> {
+  let target = path.resolve(dir);
+  while (!fs.existsSync(target)) {
+    const parent = path.dirname(target);
+    if (parent === target) break;
+    target = parent;
+  }
+
+  try {
+    const stats = await fs.promises.statfs(target);
+    // bavail (not bfree) excludes blocks reserved for root
+    return stats.bavail * stats.bsize;
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    throw new AppError(
+      `Could not determine free disk space for ${target}: ${message}`,
+      500,
+      "DISK_QUERY_FAILED",
+    );
+  }
+}
@@ -7,6 +7,8 @@ export class AppError extends Error {
     message: string,
     public statusCode: number = 500,
     public code?: string,
+    /** Optional structured payload returned alongside the message (e.g. per-field errors). */
+    public details?: unknown,
   ) {
     super(message);
     this.name = "AppError";
@@ -28,8 +30,8 @@ export class ValidationError extends AppError {
 }
 
 export class ConflictError extends AppError {
-  constructor(message: string) {
-    super(message, 409, "CONFLICT");
+  constructor(message: string, details?: unknown) {
+    super(message, 409, "CONFLICT", details);
     this.name = "ConflictError";
   }
 }
@@ -0,0 +1,70 @@
+/**
+ * OS-level port availability checks, shared by the pre-start conflict check
+ * and free-port suggestions for new servers.
+ */
+
+import dgram from "node:dgram";
+import net from "node:net";
+
+/**
+ * Check if a TCP port is available by attempting to bind to it briefly.
+ */
+export function isTcpPortFree(port: number): Promise<boolean> {
+  return new Promise((resolve) => {
+    const server = net.createServer();
+    server.once("error", () => {
+      resolve(false);
+    });
+    server.once("listening", () => {
+      server.close(() => resolve(true));
+    });
+    server.listen(port, "0.0.0.0");
+  });
+}
+
+/**
+ * Check if a UDP port is available by attempting to bind to it briefly.
+ */
+export function isUdpPortFree(port: number): Promise<boolean> {
+  return new Promise((resolve) => {
+    const socket = dgram.createSocket("udp4");
+    socket.once("error", () => {
+      try {
+        socket.close();
+      } catch {
+        // Socket never bound; nothing to close
+      }
+      resolve(false);
+    });
+    socket.once("listening", () => {
+      socket.close(() => resolve(true));
+    });
+    socket.bind(port, "0.0.0.0");
+  });
+}
+
+export interface FindFreePortOptions {
+  /** Also require the UDP port (query) to be free */
+  udp?: boolean;
+  /** Ports to skip without probing, e.g. ones assigned to stopped servers */
+  isReserved?: (port: number) => boolean;
+}
+
+/**
+ * First port in [start, start + count) that is free to bind, or null if
+ * none is. Probes one port at a time; each bind is released immediately.
+ */
+export async function findFreePort(
+  start: number,
+  count: number,
+  options: FindFreePortOptions = {},
+): Promise<number | null> {
+  const end = Math.min(start + count, 65536);
+  for (let port = start; port < end; port++) {
+    if (options.isReserved?.(port)) continue;
+    if (!(await isTcpPortFree(port))) continue;
+    if (options.udp && !(await isUdpPortFree(port))) continue;
+    return port;
+  }
+  return null;
+}
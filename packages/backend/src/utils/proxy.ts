@@ -0,0 +1,487 @@
+/**
+ * Outbound proxy support. Node's global fetch ignores the usual proxy
+ * environment variables, so corporate-proxy users couldn't authenticate or
+ * download anything. Installing an undici dispatcher covers every fetch in
+ * the process — including the Electron main process, which calls this
+ * itself at startup. The same dispatcher carries any extra CA certificates.
+ * HTTP(S) proxies go through undici's proxy agent; SOCKS5 proxies through a
+ * connector that opens the tunnel itself, since undici has no SOCKS support.
+ */
+
+import dns from "node:dns/promises";
+import net from "node:net";
+import tls from "node:tls";
+import {
+  Agent,
+  EnvHttpProxyAgent,
+  buildConnector,
+  setGlobalDispatcher,
+} from "undici";
+import { loadExtraCaCerts } from "./ca-certs.js";
+import { logger } from "./logger.js";
+
+/** The local backend must never be reached through a proxy */
+const ALWAYS_NO_PROXY = ["localhost", "127.0.0.1", "::1"];
+
+/** socks5h resolves hostnames on the proxy, socks5 resolves them locally */
+const SOCKS_PROTOCOLS = new Set(["socks5:", "socks5h:"]);
+
+const PROXY_PROTOCOLS = new Set(["http:", "https:", ...SOCKS_PROTOCOLS]);
+
+/** Cap on connecting to the proxy plus its handshake */
+const TUNNEL_TIMEOUT_MS = 30_000;
+
+/** Cap on a CONNECT response header, which is normally a few lines */
+const MAX_CONNECT_RESPONSE_BYTES = 16 * 1024;
+
+/** RFC 1928 reply codes */
+const SOCKS_REPLY_ERRORS: Record<number, string> = {
+  1: "general failure",
+  2: "connection not allowed by ruleset",
+  3: "network unreachable",
+  4: "host unreachable",
+  5: "connection refused",
+  6: "TTL expired",
+  7: "command not supported",
+  8: "address type not supported",
+};
+
+export interface ProxyConfig {
+  httpProxy?: string;
+  httpsProxy?: string;
+  noProxy: string;
+}
+
+function envValue(
+  env: NodeJS.ProcessEnv,
+  name: string,
+): string | undefined {
+  const value = env[name] ?? env[name.toLowerCase()];
+  return value?.trim() || undefined;
+}
+
+function validateProxyUrl(name: string, value: string): string | undefined {
+  let url: URL;
+  try {
+    url = new URL(value);
+  } catch {
+    logger.warn({ variable: name }, "Ignoring invalid proxy URL");
+    return undefined;
+  }
+  if (!PROXY_PROTOCOLS.has(url.protocol)) {
+    logger.warn(
+      { variable: name, protocol: url.protocol },
+      "Ignoring unsupported proxy protocol (supported: http, https, socks5, socks5h)",
+    );
+    return undefined;
+  }
+  return url.toString();
+}
+
+/** Strip credentials so proxy URLs can be logged */
+function redactProxyUrl(value: string | undefined): string | undefined {
+  if (!value) return value;
+  const url = new URL(value);
+  if (url.username || url.password) {
+    url.username = "***";
+    url.password = "";
+  }
+  return url.toString();
+}
+
+/**
+ * Read HTTPS_PROXY / HTTP_PROXY / ALL_PROXY and NO_PROXY (either case).
+ * ALL_PROXY is the fallback for whichever scheme has no dedicated proxy.
+ * Any of them may be an http(s):// or socks5(h):// URL.
+ * Returns null when no usable proxy is configured.
+ */
+export function resolveProxyConfig(
+  env: NodeJS.ProcessEnv = process.env,
+): ProxyConfig | null {
+  const read = (name: string) => {
+    const value = envValue(env, name);
+    return value ? validateProxyUrl(name, value) : undefined;
+  };
+
+  const allProxy = read("ALL_PROXY");
+  const httpProxy = read("HTTP_PROXY") ?? allProxy;
+  const httpsProxy = read("HTTPS_PROXY") ?? allProxy;
+  if (!httpProxy && !httpsProxy) return null;
+
+  const userNoProxy = (envValue(env, "NO_PROXY") ?? "")
+    .split(",")
+    .map((entry) => entry.trim())
+    .filter(Boolean);
+  const noProxy = [...new Set([...userNoProxy, ...ALWAYS_NO_PROXY])].join(",");
+
+  return { httpProxy, httpsProxy, noProxy };
+}
+
+export function isSocksProxy(value: string | undefined): boolean {
+  return value !== undefined && SOCKS_PROTOCOLS.has(new URL(value).protocol);
+}
+
+/**
+ * Whether a NO_PROXY list (as built by resolveProxyConfig) covers the
+ * host. Entries match the host and its subdomains, with or without a
+ * leading `.` or `*.`; `host:port` entries only match that port; `*`
+ * matches everything.
+ */
+export function matchesNoProxy(
+  hostname: string,
+  port: number,
+  noProxy: string,
+): boolean {
+  const host = hostname.replace(/^\[|\]$/g, "").toLowerCase();
+  return noProxy.split(",").some((raw) => {
+    const entry = raw.trim().toLowerCase();
+    if (!entry) return false;
+    if (entry === "*") return true;
+
+    let entryHost = entry;
+    let entryPort: number | undefined;
+    const bracketed = /^\[(.+)\](?::(\d+))?$/.exec(entry);
+    if (bracketed) {
+      entryHost = bracketed[1];
+      entryPort = bracketed[2] ? Number(bracketed[2]) : undefined;
+    } else if (entry.split(":").length === 2) {
+      // One colon is host:port; more is a bare IPv6 address
+      const [name, entryPortText] = entry.split(":");
+      entryHost = name;
+      entryPort = Number(entryPortText);
+    }
+    if (entryPort !== undefined && entryPort !== port) return false;
+
+    entryHost = entryHost.replace(/^\*?\./, "");
+    return host === entryHost || host.endsWith(`.${entryHost}`);
+  });
+}
+
+/** Resolve once the socket is connected (or its TLS handshake is done) */
+function whenConnected<T extends net.Socket>(
+  socket: T,
+  event: "connect" | "secureConnect",
+): Promise<T> {
+  return new Promise((resolve, reject) => {
+    const onError = (err: Error) => reject(err);
+    socket.once("error", onError);
+    socket.once(event, () => {
+      socket.off("error", onError);
+      resolve(socket);
+    });
+  });
+}
+
+function startTls(
+  socket: net.Socket,
+  servername: string,
+  ca: string[] | undefined,
+): Promise<tls.TLSSocket> {
+  return whenConnected(
+    tls.connect({
+      socket,
+      // SNI must not carry an IP address
+      servername: net.isIP(servername) ? undefined : servername,
+      ca,
+      ALPNProtocols: ["http/1.1"],
+    }),
+    "secureConnect",
+  );
+}
+
+/**
+ * Read exactly `length` bytes of a handshake. Anything after them stays
+ * buffered on the socket for whoever reads it next.
+ */
+function readExactly(socket: net.Socket, length: number): Promise<Buffer> {
+  return new Promise((resolve, reject) => {
+    const cleanup = () => {
+      socket.off("readable", tryRead);
+      socket.off("end", onEnd);
+      socket.off("error", onError);
+    };
+    const tryRead = () => {
+      const chunk = socket.read(length) as Buffer | null;
+      if (chunk === null) return;
+      cleanup();
+      // At EOF, read() hands over whatever is left even if it's short
+      if (chunk.length < length) {
+        reject(new Error("Proxy closed the connection during the handshake"));
+      } else {
+        resolve(chunk);
+      }
+    };
+    const onEnd = () => {
+      cleanup();
+      reject(new Error("Proxy closed the connection during the handshake"));
+    };
+    const onError = (err: Error) => {
+      cleanup();
+      reject(err);
+    };
+    socket.on("readable", tryRead);
+    socket.on("end", onEnd);
+    socket.on("error", onError);
+    tryRead();
+  });
+}
+
+/** The 16 bytes of an IPv6 address, for a SOCKS5 ATYP 4 request */
+export function ipv6ToBytes(address: string): Buffer {
+  let text = address.split("%")[0];
+  const embeddedV4 = /(\d+)\.(\d+)\.(\d+)\.(\d+)$/.exec(text);
+  if (embeddedV4) {
+    const [a, b, c, d] = embeddedV4.slice(1).map(Number);
+    text =
+      text.slice(0, embeddedV4.index) +
+      `${((a << 8) | b).toString(16)}:${((c << 8) | d).toString(16)}`;
+  }
+  const [head, tail] = text.split("::");
+  const headGroups = head ? head.split(":") : [];
+  const tailGroups = tail ? tail.split(":") : [];
+  const groups =
+    tail === undefined
+      ? headGroups
+      : [
+          ...headGroups,
+          ...Array<string>(8 - headGroups.length - tailGroups.length).fill(
+            "0",
+          ),
+          ...tailGroups,
+        ];
+  const bytes = Buffer.alloc(16);
+  groups.forEach((group, i) => bytes.writeUInt16BE(parseInt(group, 16), i * 2));
+  return bytes;
+}
+
+/** SOCKS5 ATYP + address; only socks5h hands the hostname to the proxy */
+async function encodeSocksAddress(
+  host: string,
+  remoteDns: boolean,
+): Promise<Buffer> {
+  if (!net.isIP(host) && remoteDns) {
+    const name = Buffer.from(host);
+    if (name.length > 255) throw new Error(`Hostname too long: ${host}`);
+    return Buffer.concat([Buffer.from([0x03, name.length]), name]);
+  }
+  const address = net.isIP(host) ? host : (await dns.lookup(host)).address;
+  if (net.isIPv4(address)) {
+    return Buffer.from([0x01, ...address.split(".").map(Number)]);
+  }
+  return Buffer.concat([Buffer.from([0x04]), ipv6ToBytes(address)]);
+}
+
+/** RFC 1928 CONNECT, with RFC 1929 username/password auth if given */
+async function socks5Handshake(
+  socket: net.Socket,
+  proxy: URL,
+  host: string,
+  port: number,
+): Promise<void> {
+  const username = Buffer.from(decodeURIComponent(proxy.username));
+  const password = Buffer.from(decodeURIComponent(proxy.password));
+  const methods = username.length > 0 ? [0x00, 0x02] : [0x00];
+  socket.write(Buffer.from([0x05, methods.length, ...methods]));
+
+  const [version, method] = await readExactly(socket, 2);
+  if (version !== 0x05) throw new Error("Proxy is not a SOCKS5 server");
+  if (method === 0x02 && username.length > 0) {
+    if (username.length > 255 || password.length > 255) {
+      throw new Error("SOCKS username and password must be 255 bytes or less");
+    }
+    socket.write(
+      Buffer.concat([
+        Buffer.from([0x01, username.length]),
+        username,
+        Buffer.from([password.length]),
+        password,
+      ]),
+    );
+    const [, status] = await readExactly(socket, 2);
+    if (status !== 0x00) {
+      throw new Error("SOCKS proxy rejected the username or password");
+    }
+  } else if (method !== 0x00) {
+    throw new Error(
+      username.length > 0
+        ? "SOCKS proxy doesn't accept username/password authentication"
+        : "SOCKS proxy requires authentication; add credentials to the proxy URL",
+    );
+  }
+
+  const portBytes = Buffer.alloc(2);
+  portBytes.writeUInt16BE(port);
+  socket.write(
+    Buffer.concat([
+      Buffer.from([0x05, 0x01, 0x00]),
+      await encodeSocksAddress(host, proxy.protocol === "socks5h:"),
+      portBytes,
+    ]),
+  );
+
+  const reply = await readExactly(socket, 4);
+  if (reply[1] !== 0x00) {
+    const reason = SOCKS_REPLY_ERRORS[reply[1]] ?? `error ${reply[1]}`;
+    throw new Error(
+      `SOCKS proxy couldn't connect to ${host}:${port}: ${reason}`,
+    );
+  }
+  // The bound address is of no use to us, but must be consumed
+  const addressLength =
+    reply[3] === 0x01
+      ? 4
+      : reply[3] === 0x04
+        ? 16
+        : (await readExactly(socket, 1))[0];
+  await readExactly(socket, addressLength + 2);
+}
+
+/** HTTP CONNECT, for an HTTP proxy alongside a SOCKS one */
+async function httpConnectHandshake(
+  socket: net.Socket,
+  proxy: URL,
+  host: string,
+  port: number,
+): Promise<void> {
+  const target = net.isIPv6(host) ? `[${host}]:${port}` : `${host}:${port}`;
+  const username = decodeURIComponent(proxy.username);
+  const password = decodeURIComponent(proxy.password);
+  const credentials = username
+    ? Buffer.from(`${username}:${password}`).toString("base64")
+    : null;
+  socket.write(
+    `CONNECT ${target} HTTP/1.1\r\nHost: ${target}\r\n` +
+      (credentials ? `Proxy-Authorization: Basic ${credentials}\r\n` : "") +
+      "\r\n",
+  );
+
+  // Byte at a time so nothing past the header is taken off the socket
+  let header = "";
+  while (!header.endsWith("\r\n\r\n")) {
+    if (header.length > MAX_CONNECT_RESPONSE_BYTES) {
+      throw new Error("Proxy sent an oversized CONNECT response");
+    }
+    header += (await readExactly(socket, 1)).toString("latin1");
+  }
+  const status = /^HTTP\/1\.[01] (\d{3})/.exec(header)?.[1];
+  if (status !== "200") {
+    throw new Error(
+      `Proxy refused to connect to ${target} (${header.split("\r\n")[0]})`,
+    );
+  }
+}
+
+/**
+ * Connect to `host:port` through a proxy and return the raw tunnel. The
+ * caller adds TLS for https targets.
+ */
+export async function openProxyTunnel(
+  proxy: URL,
+  host: string,
+  port: number,
+  ca?: string[],
+): Promise<net.Socket> {
+  const socks = SOCKS_PROTOCOLS.has(proxy.protocol);
+  const proxyHost = proxy.hostname.replace(/^\[|\]$/g, "");
+  const defaultPort = socks ? 1080 : proxy.protocol === "https:" ? 443 : 80;
+  const proxyPort = Number(proxy.port) || defaultPort;
+
+  const socket =
+    proxy.protocol === "https:"
+      ? await whenConnected(
+          tls.connect({ host: proxyHost, port: proxyPort, ca }),
+          "secureConnect",
+        )
+      : await whenConnected(net.connect(proxyPort, proxyHost), "connect");
+  socket.setTimeout(TUNNEL_TIMEOUT_MS, () =>
+    socket.destroy(new Error(`Timed out connecting through ${proxyHost}`)),
+  );
+
+  try {
+    if (socks) await socks5Handshake(socket, proxy, host, port);
+    else await httpConnectHandshake(socket, proxy, host, port);
+  } catch (err) {
+    socket.destroy();
+    throw err;
+  }
+  socket.setTimeout(0);
+  return socket;
+}
+
+/**
+ * undici connector that tunnels each connection through the proxy for its
+ * scheme, or connects directly for NO_PROXY hosts and unproxied schemes.
+ * Used whenever a SOCKS proxy is configured; an HTTP proxy for the other
+ * scheme is then reached with CONNECT.
+ */
+function createTunnelConnector(
+  proxy: ProxyConfig,
+  ca: string[] | undefined,
+): buildConnector.connector {
+  const direct = buildConnector(ca ? { ca } : {});
+  return (options, callback) => {
+    const secure = options.protocol === "https:";
+    const port = Number(options.port) || (secure ? 443 : 80);
+    const proxyUrl = secure ? proxy.httpsProxy : proxy.httpProxy;
+    if (!proxyUrl || matchesNoProxy(options.hostname, port, proxy.noProxy)) {
+      direct(options, callback);
+      return;
+    }
+
+    openProxyTunnel(new URL(proxyUrl), options.hostname, port, ca)
+      .then((socket) =>
+        secure
+          ? startTls(socket, options.servername || options.hostname, ca)
+          : socket,
+      )
+      .then(
+        (socket) => callback(null, socket),
+        (err: Error) => callback(err, null),
+      );
+  };
+}
+
+let configured = false;
+
+/**
+ * Route all outbound fetches through the configured proxy and trust any
+ * MC_EXTRA_CA_CERTS roots. Idempotent unless `reload` is set, which
+ * re-reads both (e.g. after the CA bundle was replaced) and goes back to a
+ * plain dispatcher if neither is configured any more.
+ */
+export function configureOutboundProxy(
+  options: { reload?: boolean } = {},
+): void {
+  if (configured && !options.reload) return;
+  const reloading = configured;
+  configured = true;
+
+  const proxy = resolveProxyConfig();
+  const ca = loadExtraCaCerts();
+  if (!proxy) {
+    if (ca) setGlobalDispatcher(new Agent({ connect: { ca } }));
+    else if (reloading) setGlobalDispatcher(new Agent());
+    return;
+  }
+
+  if (isSocksProxy(proxy.httpProxy) || isSocksProxy(proxy.httpsProxy)) {
+    setGlobalDispatcher(
+      new Agent({ connect: createTunnelConnector(proxy, ca ?? undefined) }),
+    );
+  } else {
+    // connect: direct (NO_PROXY) requests; requestTls: requests tunnelled
+    // through the proxy; proxyTls: an https:// proxy itself
+    const tlsOptions = ca
+      ? { connect: { ca }, requestTls: { ca }, proxyTls: { ca } }
+      : {};
+    setGlobalDispatcher(new EnvHttpProxyAgent({ ...proxy, ...tlsOptions }));
+  }
+  logger.info(
+    {
+      httpProxy: redactProxyUrl(proxy.httpProxy),
+      httpsProxy: redactProxyUrl(proxy.httpsProxy),
+      noProxy: proxy.noProxy,
+    },
+    "Using outbound proxy",
+  );
+}
@@ -0,0 +1,40 @@
+import { recommendRam } from "./ram-recommendation.js";
+
+const GB = 1024;
+
+describe("recommendRam", () => {
+  it("recommends 4GB for modern vanilla on a 16GB machine", () => {
+    const rec = recommendRam(16 * GB, 10 * GB, { mcVersion: "1.20.4" });
+    expect(rec).toMatchObject({ minGb: 2, maxGb: 4, constrained: false });
+  });
+
+  it("recommends less for old versions", () => {
+    const rec = recommendRam(16 * GB, 10 * GB, { mcVersion: "1.12.2" });
+    expect(rec.maxGb).toBe(2);
+    expect(rec.minGb).toBe(1);
+  });
+
+  it("nudges modded instances higher", () => {
+    const rec = recommendRam(32 * GB, 20 * GB, {
+      mcVersion: "1.20.1",
+      modded: true,
+    });
+    expect(rec).toMatchObject({ minGb: 3, maxGb: 6 });
+  });
+
+  it("leaves headroom on small machines", () => {
+    const rec = recommendRam(4 * GB, 3 * GB, { modded: true });
+    expect(rec.maxGb).toBe(2);
+    expect(rec.minGb).toBe(1);
+  });
+
+  it("never recommends less than 1GB", () => {
+    const rec = recommendRam(2 * GB, 1 * GB);
+    expect(rec).toMatchObject({ minGb: 1, maxGb: 1 });
+  });
+
+  it("flags when free memory is below the recommendation", () => {
+    const rec = recommendRam(16 * GB, 2 * GB);
+    expect(rec.constrained).toBe(true);
+  });
+});
@@ -0,0 +1,63 @@
+/**
+ * Default heap sizes for new launcher instances, derived from system memory
+ * so users don't have to guess.
+ */
+
+import type { RamRecommendation } from "@mc-server-manager/shared";
+
+/** Left for the OS, browser, Discord etc. */
+const HEADROOM_GB = 2;
+
+/** Vanilla gains nothing from more than this; modpacks rarely do past the modded cap. */
+const VANILLA_CAP_GB = 8;
+const MODDED_CAP_GB = 12;
+
+/** First release where vanilla comfortably wants 4GB (new world height/caves). */
+const MODERN_MINOR_VERSION = 18;
+
+export interface RamRecommendationOptions {
+  mcVersion?: string;
+  modded?: boolean;
+}
+
+function isModernVersion(mcVersion: string | undefined): boolean {
+  if (!mcVersion) return true;
+  const match = /^1\.(\d+)/.exec(mcVersion);
+  // Snapshots like 24w14a are all newer than 1.18
+  if (!match) return true;
+  return parseInt(match[1], 10) >= MODERN_MINOR_VERSION;
+}
+
+/**
+ * Suggest `{ minGb, maxGb }` for a new instance.
+ *
+ * The max aims for what the version needs (more for modded, less for old
+ * releases) but never eats into HEADROOM_GB of total memory. When less than
+ * that is currently free, `constrained` is set so the UI can warn.
+ */
+export function recommendRam(
+  totalMemoryMB: number,
+  freeMemoryMB: number,
+  options: RamRecommendationOptions = {},
+): RamRecommendation {
+  const totalGb = totalMemoryMB / 1024;
+  const freeGb = freeMemoryMB / 1024;
+
+  const target = options.modded
+    ? 6
+    : isModernVersion(options.mcVersion)
+      ? 4
+      : 2;
+  const cap = options.modded ? MODDED_CAP_GB : VANILLA_CAP_GB;
+  const ceiling = Math.max(1, Math.floor(totalGb - HEADROOM_GB));
+
+  const maxGb = Math.max(1, Math.min(target, cap, ceiling));
+  const minGb = Math.max(1, Math.min(4, Math.floor(maxGb / 2)));
+
+  return {
+    minGb,
+    maxGb,
+    totalGb: Math.round(totalGb * 10) / 10,
+    constrained: freeGb < maxGb,
+  };
+}
@@ -9,14 +9,18 @@
 import { WebSocketServer, type WebSocket } from "ws";
 import type { Server as HttpServer } from "node:http";
 import type {
-  WsConsoleLine,
+  WsConsoleBatch,
   WsStatusChange,
   WsStats,
+  WsServerExit,
+  WsServerForceKilled,
+  ModpackInstallProgress,
   WsModpackProgress,
   WsModpackUpdateAvailable,
 } from "@mc-server-manager/shared";
 import { serverManager } from "../services/server-manager.js";
 import { eventBus } from "../services/event-bus.js";
+import { ConsoleBatcher } from "../services/console-batcher.js";
 import { logger } from "../utils/logger.js";
 import {
   handleMessage,
@@ -69,11 +73,11 @@ export function setupWebSocketServer(httpServer: HttpServer): WebSocketServer {
 
   // ---- Wire ServerManager events → broadcast to subscribers ----
 
-  wireServerManagerEvents(wss);
+  const unwireServerManager = wireServerManagerEvents(wss);
 
   // ---- Wire event bus → broadcast modpack progress ----
 
-  wireEventBus(wss);
+  const unwireEventBus = wireEventBus(wss);
 
   // ---- Periodic stats broadcast ----
 
@@ -82,6 +86,8 @@ export function setupWebSocketServer(httpServer: HttpServer): WebSocketServer {
   // ---- Cleanup on server close ----
 
   wss.on("close", () => {
+    unwireServerManager();
+    unwireEventBus();
     if (statsInterval) {
       clearInterval(statsInterval);
       statsInterval = null;
@@ -113,45 +119,77 @@ function broadcast(
 
 /**
  * Register listeners on ServerManager to relay events to WebSocket clients.
+ * Returns a function that removes them again.
  */
-function wireServerManagerEvents(wss: WebSocketServer): void {
-  // Console output → broadcast to subscribers
-  serverManager.onConsole((serverId, entry) => {
-    const msg: WsConsoleLine = {
-      type: "console",
+function wireServerManagerEvents(wss: WebSocketServer): () => void {
+  const consoleBatcher = new ConsoleBatcher((serverId, lines, dropped) => {
+    const msg: WsConsoleBatch = {
+      type: "console:batch",
       serverId,
-      line: entry.line,
-      timestamp: entry.timestamp,
+      lines,
+      dropped,
     };
     broadcast(wss, serverId, msg);
   });
 
-  // Status changes → broadcast to subscribers
-  serverManager.onStatus((serverId, status) => {
-    const msg: WsStatusChange = {
-      type: "status",
-      serverId,
-      status,
-    };
-    broadcast(wss, serverId, msg);
-  });
+  const unsubscribers = [
+    // Console output → batched, then broadcast to subscribers
+    serverManager.onConsole((serverId, entry) => {
+      consoleBatcher.push(serverId, entry);
+    }),
 
-  // Player list changes → broadcast as a stats update
-  serverManager.onPlayers((serverId, players) => {
-    const proc = serverManager.getProcess(serverId);
-    const msg: WsStats = {
-      type: "stats",
-      serverId,
-      playerCount: players.length,
-      players,
-      uptime: proc?.uptime ?? 0,
-    };
-    broadcast(wss, serverId, msg);
-  });
+    // Status changes → broadcast to subscribers
+    serverManager.onStatus((serverId, status) => {
+      const msg: WsStatusChange = {
+        type: "status",
+        serverId,
+        status,
+      };
+      broadcast(wss, serverId, msg);
+    }),
+
+    // Player list changes → broadcast as a stats update
+    serverManager.onPlayers((serverId, players) => {
+      const proc = serverManager.getProcess(serverId);
+      const msg: WsStats = {
+        type: "stats",
+        serverId,
+        playerCount: players.length,
+        players,
+        uptime: proc?.uptime ?? 0,
+      };
+      broadcast(wss, serverId, msg);
+    }),
+
+    // Crashes → broadcast with classified cause and auto-restart state
+    serverManager.onExit((serverId, info) => {
+      const msg: WsServerExit = {
+        type: "server-exit",
+        serverId,
+        ...info,
+      };
+      broadcast(wss, serverId, msg);
+    }),
+
+    // Stop timeouts → let clients know the server didn't exit cleanly
+    serverManager.onForceKill((serverId, timeoutSecs) => {
+      const msg: WsServerForceKilled = {
+        type: "server-force-killed",
+        serverId,
+        timeoutSecs,
+      };
+      broadcast(wss, serverId, msg);
+    }),
+  ];
+
+  return () => {
+    unsubscribers.forEach((unsubscribe) => unsubscribe());
+    consoleBatcher.dispose();
+  };
 }
 
-function wireEventBus(wss: WebSocketServer): void {
-  eventBus.on("modpack:progress", (serverId, progress) => {
+function wireEventBus(wss: WebSocketServer): () => void {
+  const onProgress = (serverId: string, progress: ModpackInstallProgress) => {
     const msg: WsModpackProgress = {
       type: "modpack:progress",
       serverId,
@@ -163,21 +201,31 @@ function wireEventBus(wss: WebSocketServer): void {
       error: progress.error,
     };
     broadcast(wss, serverId, msg);
-  });
+  };
 
-  eventBus.on(
-    "modpack:update",
-    (serverId, modpackId, latestVersionId, latestVersionNumber) => {
-      const msg: WsModpackUpdateAvailable = {
-        type: "modpack:update",
-        serverId,
-        modpackId,
-        latestVersionId,
-        latestVersionNumber,
-      };
-      broadcast(wss, serverId, msg);
-    },
-  );
+  const onUpdate = (
+    serverId: string,
+    modpackId: string,
+    latestVersionId: string,
+    latestVersionNumber: string,
+  ) => {
+    const msg: WsModpackUpdateAvailable = {
+      type: "modpack:update",
+      serverId,
+      modpackId,
+      latestVersionId,
+      latestVersionNumber,
+    };
+    broadcast(wss, serverId, msg);
+  };
+
+  eventBus.on("modpack:progress", onProgress);
+  eventBus.on("modpack:update", onUpdate);
+
+  return () => {
+    eventBus.off("modpack:progress", onProgress);
+    eventBus.off("modpack:update", onUpdate);
+  };
 }
 
 /**
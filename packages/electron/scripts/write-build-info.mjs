@@ -0,0 +1,28 @@
+// Bakes build metadata into dist/build-info.json for the get-app-info IPC call.
+// CI can set GIT_COMMIT; otherwise fall back to the local checkout.
+import { execSync } from "node:child_process";
+import { mkdirSync, writeFileSync } from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __dirname = path.dirname(fileURLToPath(import.meta.url));
+const distDir = path.join(__dirname, "..", "dist");
+
+function resolveCommit() {
+  if (process.env.GIT_COMMIT) return process.env.GIT_COMMIT;
+  try {
+    return execSync("git rev-parse HEAD", { encoding: "utf-8" }).trim();
+  } catch {
+    return null;
+  }
+}
+
+mkdirSync(distDir, { recursive: true });
+writeFileSync(
+  path.join(distDir, "build-info.json"),
+  JSON.stringify(
+    { commit: resolveCommit(), builtAt: new Date().toISOString() },
+    null,
+    2,
+  ),
+);
@@ -0,0 +1,42 @@
+/**
+ * Version and build metadata for the about screen and bug reports.
+ * @module app-info
+ */
+
+import { app } from "electron";
+import { readFileSync } from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+import type { AppInfo } from "@mc-server-manager/shared";
+
+const __dirname = path.dirname(fileURLToPath(import.meta.url));
+
+interface BuildInfo {
+  commit: string | null;
+  builtAt: string | null;
+}
+
+/** Written by scripts/write-build-info.mjs; absent when running an unbuilt tree. */
+function readBuildInfo(): BuildInfo {
+  try {
+    const raw = readFileSync(path.join(__dirname, "build-info.json"), "utf-8");
+    const parsed = JSON.parse(raw) as Partial<BuildInfo>;
+    return { commit: parsed.commit ?? null, builtAt: parsed.builtAt ?? null };
+  } catch {
+    return { commit: null, builtAt: null };
+  }
+}
+
+export function getAppInfo(): AppInfo {
+  const build = readBuildInfo();
+  return {
+    version: app.getVersion(),
+    commit: build.commit,
+    builtAt: build.builtAt,
+    target: `${process.platform}-${process.arch}`,
+    // The backend runs in-process, so this is the Node that serves it
+    nodeVersion: process.versions.node,
+    electronVersion: process.versions.electron,
+    packaged: app.isPackaged,
+  };
+}
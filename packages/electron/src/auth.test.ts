@@ -0,0 +1,112 @@
+import {
+  isPlausibleJwt,
+  isPlausibleRefreshToken,
+  migrateAccountKeyring,
+} from "./auth.js";
+
+vi.mock("electron", () => ({}));
+
+const secrets = vi.hoisted(() => new Map<string, string>());
+vi.mock("./secure-storage.js", () => ({
+  saveSecret: (key: string, value: string) => secrets.set(key, value),
+  getSecret: (key: string) => secrets.get(key) ?? null,
+  hasSecret: (key: string) => secrets.has(key),
+  deleteSecret: (key: string) => secrets.delete(key),
+}));
+
+describe("stored credential checks", () => {
+  const segment = (value: object) =>
+    btoa(JSON.stringify(value))
+      .replace(/=+$/, "")
+      .replace(/\+/g, "-")
+      .replace(/\//g, "_");
+  const jwt = [
+    segment({ alg: "HS256", kid: "1" }),
+    segment({ xuid: "2535", exp: 1700000000 }),
+    "c2lnbmF0dXJl",
+  ].join(".");
+
+  it("accepts a well-formed JWT", () => {
+    expect(isPlausibleJwt(jwt)).toBe(true);
+  });
+
+  it("rejects tokens that aren't three JSON segments", () => {
+    expect(isPlausibleJwt("")).toBe(false);
+    expect(isPlausibleJwt("abc.def")).toBe(false);
+    expect(isPlausibleJwt("abc.def.ghi")).toBe(false);
+    expect(isPlausibleJwt(`${jwt} `)).toBe(false);
+    expect(isPlausibleJwt("\u0000\u0001.x.y")).toBe(false);
+  });
+
+  it("accepts opaque refresh tokens", () => {
+    expect(
+      isPlausibleRefreshToken("M.C105_BAY.0.U.-Cg7!Ph2wqcR*Ab8h$Zs4Tc"),
+    ).toBe(true);
+  });
+
+  it("rejects empty, short or mangled refresh tokens", () => {
+    expect(isPlausibleRefreshToken("")).toBe(false);
+    expect(isPlausibleRefreshToken("short")).toBe(false);
+    expect(isPlausibleRefreshToken("M.C105_BAY.0.U.-Cg7 Ph2wqcRAb8")).toBe(
+      false,
+    );
+    expect(isPlausibleRefreshToken("M.C105_BAY.0.U.\ufffd\ufffd\ufffd")).toBe(
+      false,
+    );
+  });
+});
+
+describe("migrateAccountKeyring", () => {
+  const account = { id: "acc-1", uuid: "old-uuid", username: "Steve" };
+
+  beforeEach(() => {
+    secrets.clear();
+    secrets.set("ms_refresh_token_old-uuid", "refresh");
+    secrets.set("mc_access_token_old-uuid", "access");
+    secrets.set("mc_access_token_old-uuid_expires_at", "1700000000000");
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  it("moves the secrets once the backend entry is updated", async () => {
+    vi.stubGlobal(
+      "fetch",
+      vi.fn(async (_url: string, init?: RequestInit) =>
+        init?.method === "PATCH"
+          ? Response.json({ ...account, uuid: "new-uuid" })
+          : Response.json([account]),
+      ),
+    );
+
+    await migrateAccountKeyring("old-uuid", "new-uuid", { verify: false });
+
+    expect([...secrets.keys()].sort()).toEqual([
+      "mc_access_token_new-uuid",
+      "mc_access_token_new-uuid_expires_at",
+      "ms_refresh_token_new-uuid",
+    ]);
+  });
+
+  it("keeps the old secrets when the backend update fails", async () => {
+    vi.stubGlobal(
+      "fetch",
+      vi.fn(async (_url: string, init?: RequestInit) =>
+        init?.method === "PATCH"
+          ? new Response("boom", { status: 500 })
+          : Response.json([account]),
+      ),
+    );
+
+    await expect(
+      migrateAccountKeyring("old-uuid", "new-uuid", { verify: false }),
+    ).rejects.toThrow("Failed to update account: boom");
+
+    expect(Object.fromEntries(secrets)).toEqual({
+      "ms_refresh_token_old-uuid": "refresh",
+      "mc_access_token_old-uuid": "access",
+      "mc_access_token_old-uuid_expires_at": "1700000000000",
+    });
+  });
+});
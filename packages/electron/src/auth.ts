@@ -14,8 +14,18 @@
  * @module auth
  */
 
-import { saveSecret, getSecret, deleteSecret } from "./secure-storage.js";
+import { shell } from "electron";
+import {
+  saveSecret,
+  getSecret,
+  deleteSecret,
+  hasSecret,
+} from "./secure-storage.js";
+import { clearAvatar } from "./avatars.js";
+import { notify } from "./notifications.js";
+import { backendUrl } from "./backend-port.js";
 import type {
+  AccountRepairResult,
   MSAuthDeviceCode,
   MSAuthStatus,
   LauncherAccount,
@@ -26,11 +36,16 @@ import type {
 // ---------------------------------------------------------------------------
 
 const MS_CLIENT_ID = "c36a9fb6-4f2a-41ff-90bd-ae7cc92031eb";
-const MS_TENANT = "consumers";
+/**
+ * Personal accounts use "consumers". Work/school (AAD) accounts need
+ * "organizations" or a specific tenant ID/domain, set via MC_MS_TENANT.
+ */
+const MS_TENANT = process.env.MC_MS_TENANT?.trim() || "consumers";
 const MS_SCOPE = "XboxLive.signin offline_access";
 
 const TOKEN_URL = `https://login.microsoftonline.com/${MS_TENANT}/oauth2/v2.0/token`;
 const DEVICE_CODE_URL = `https://login.microsoftonline.com/${MS_TENANT}/oauth2/v2.0/devicecode`;
+const LOGOUT_URL = `https://login.microsoftonline.com/${MS_TENANT}/oauth2/v2.0/logout`;
 
 /** Per-request timeout for auth chain HTTP calls (ms). */
 const REQUEST_TIMEOUT_MS = 15_000;
@@ -38,6 +53,22 @@ const REQUEST_TIMEOUT_MS = 15_000;
 /** Safety buffer subtracted from token TTL when computing expiresAt (ms). */
 const EXPIRY_BUFFER_MS = 60_000;
 
+/** AADSTS codes commonly hit when signing in with a work/school tenant. */
+const AAD_ERROR_MESSAGES: Record<number, string> = {
+  9002346:
+    "This app only supports personal Microsoft accounts. Unset MC_MS_TENANT to use the consumers endpoint",
+  700016:
+    "This app isn't available in your organization's tenant. Ask your administrator, or sign in with a personal account",
+  50020:
+    "This account doesn't belong to the configured tenant. Check MC_MS_TENANT",
+  90002: "Tenant not found. Check MC_MS_TENANT",
+  90072:
+    "This account doesn't exist in the configured tenant. Check MC_MS_TENANT",
+  65001:
+    "Your organization requires administrator consent for this app. Ask your IT administrator",
+  53003: "Sign-in was blocked by your organization's conditional access policy",
+};
+
 /** Known XSTS error codes → user-friendly messages. */
 const XSTS_ERROR_MESSAGES: Record<number, string> = {
   2148916233:
@@ -57,16 +88,26 @@ interface DeviceCodeResponse {
   user_code: string;
   device_code: string;
   verification_uri: string;
+  /** Pre-fills the user code; not returned by every MS endpoint. */
+  verification_uri_complete?: string;
   expires_in: number;
   interval: number;
 }
 
 interface TokenResponse {
   access_token: string;
-  refresh_token: string;
+  /** AAD tenants may omit this (offline_access blocked) or not rotate it on refresh */
+  refresh_token?: string;
   expires_in: number;
 }
 
+/** OAuth error body from login.microsoftonline.com */
+interface MsErrorResponse {
+  error?: string;
+  error_description?: string;
+  error_codes?: number[];
+}
+
 interface XboxLiveAuthResponse {
   Token: string;
   DisplayClaims: {
@@ -89,6 +130,16 @@ interface MinecraftProfile {
 // ---------------------------------------------------------------------------
 
 let pendingAuth: DeviceCodeResponse | null = null;
+let pendingAuthStartedAt = 0;
+let pendingAuthRestored = false;
+
+/** Secure-storage key for the in-flight device code, so a restart can resume polling. */
+const PENDING_AUTH_KEY = "ms_pending_auth";
+
+interface PersistedPendingAuth {
+  response: DeviceCodeResponse;
+  startedAt: number;
+}
 
 /** Simple promise-based mutex to prevent concurrent auth chain execution. */
 let authLock: Promise<void> = Promise.resolve();
@@ -97,12 +148,115 @@ let authLock: Promise<void> = Promise.resolve();
 // Helpers
 // ---------------------------------------------------------------------------
 
+function setPendingAuth(data: DeviceCodeResponse | null): void {
+  pendingAuth = data;
+  pendingAuthRestored = true;
+  if (data) {
+    pendingAuthStartedAt = Date.now();
+    const persisted: PersistedPendingAuth = {
+      response: data,
+      startedAt: pendingAuthStartedAt,
+    };
+    saveSecret(PENDING_AUTH_KEY, JSON.stringify(persisted));
+  } else {
+    deleteSecret(PENDING_AUTH_KEY);
+  }
+}
+
+/** The in-flight device code, restored from disk once after a restart. */
+function getPendingAuth(): DeviceCodeResponse | null {
+  if (pendingAuthRestored) return pendingAuth;
+  pendingAuthRestored = true;
+
+  const raw = getSecret(PENDING_AUTH_KEY);
+  if (!raw) return null;
+  try {
+    const persisted = JSON.parse(raw) as PersistedPendingAuth;
+    const expiresAt =
+      persisted.startedAt + persisted.response.expires_in * 1000;
+    if (Date.now() < expiresAt) {
+      pendingAuth = persisted.response;
+      pendingAuthStartedAt = persisted.startedAt;
+      return pendingAuth;
+    }
+  } catch {
+    // Corrupt entry — fall through and drop it
+  }
+  deleteSecret(PENDING_AUTH_KEY);
+  return null;
+}
+
 function formBody(params: Record<string, string>): string {
   return Object.entries(params)
     .map(([k, v]) => `${encodeURIComponent(k)}=${encodeURIComponent(v)}`)
     .join("&");
 }
 
+function parseMsError(body: string): MsErrorResponse {
+  try {
+    const parsed: unknown = JSON.parse(body);
+    return parsed && typeof parsed === "object"
+      ? (parsed as MsErrorResponse)
+      : {};
+  } catch {
+    return {};
+  }
+}
+
+/**
+ * Turn a Microsoft identity error body into a readable message. Known
+ * AADSTS codes (tenant mismatches etc.) get a specific explanation instead
+ * of dumping raw JSON.
+ */
+function describeMsError(body: string, context: string): string {
+  const parsed = parseMsError(body);
+  for (const code of parsed.error_codes ?? []) {
+    if (AAD_ERROR_MESSAGES[code]) {
+      return `${AAD_ERROR_MESSAGES[code]} (AADSTS${code})`;
+    }
+  }
+  if (parsed.error_description) {
+    // First line only — the rest is trace/correlation IDs
+    return `${context}: ${parsed.error_description.split(/\r?\n/)[0]}`;
+  }
+  return `${context}: ${body}`;
+}
+
+/**
+ * Parse a token endpoint body without trusting its shape. Microsoft
+ * occasionally answers 200 with an error object (or an id_token and no
+ * access_token), which would otherwise fail much later with a useless
+ * "undefined" token.
+ */
+function parseTokenResponse(body: string, context: string): TokenResponse {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(body);
+  } catch {
+    throw new Error(`${context}: Microsoft returned a non-JSON response`);
+  }
+
+  const fields =
+    parsed && typeof parsed === "object"
+      ? (parsed as Record<string, unknown>)
+      : {};
+  if (typeof fields.access_token !== "string" || !fields.access_token) {
+    if (fields.error || fields.error_description) {
+      throw new Error(describeMsError(body, context));
+    }
+    throw new Error(`${context}: Microsoft's response had no access token`);
+  }
+
+  return {
+    access_token: fields.access_token,
+    refresh_token:
+      typeof fields.refresh_token === "string"
+        ? fields.refresh_token
+        : undefined,
+    expires_in: typeof fields.expires_in === "number" ? fields.expires_in : 0,
+  };
+}
+
 /** Fetch with an AbortController timeout. */
 async function fetchWithTimeout(
   url: string,
@@ -176,9 +330,27 @@ function getTokenIfValid(key: string): string | null {
   return getSecret(key);
 }
 
-function deleteTokenWithExpiry(key: string): void {
-  deleteSecret(key);
-  deleteSecret(`${key}_expires_at`);
+/**
+ * Every secure-storage key held for an account. Anything new stored per
+ * account must be added here, or removing the account leaves it behind.
+ */
+function accountSecretKeys(accountUuid: string): string[] {
+  return [
+    `mc_access_token_${accountUuid}`,
+    `mc_access_token_${accountUuid}_expires_at`,
+    `ms_refresh_token_${accountUuid}`,
+  ];
+}
+
+/** Delete all of an account's secrets; one failure doesn't stop the rest. */
+function deleteAccountSecrets(accountUuid: string): void {
+  for (const key of accountSecretKeys(accountUuid)) {
+    try {
+      deleteSecret(key);
+    } catch (err) {
+      console.warn(`Failed to delete secret "${key}":`, err);
+    }
+  }
 }
 
 // ---------------------------------------------------------------------------
@@ -335,11 +507,11 @@ export async function msAuthStart(): Promise<MSAuthDeviceCode> {
 
   if (!res.ok) {
     const body = await res.text();
-    throw new Error(`Device code request failed: ${body}`);
+    throw new Error(describeMsError(body, "Device code request failed"));
   }
 
   const data: DeviceCodeResponse = await res.json();
-  pendingAuth = data;
+  setPendingAuth(data);
 
   return {
     userCode: data.user_code,
@@ -350,8 +522,26 @@ export async function msAuthStart(): Promise<MSAuthDeviceCode> {
   };
 }
 
+/**
+ * A device code left over from before an app restart, with its remaining
+ * lifetime, or null if there's none (or it expired).
+ */
+export async function msAuthResume(): Promise<MSAuthDeviceCode | null> {
+  const pending = getPendingAuth();
+  if (!pending) return null;
+  const elapsedSec = Math.floor((Date.now() - pendingAuthStartedAt) / 1000);
+  return {
+    userCode: pending.user_code,
+    deviceCode: pending.device_code,
+    verificationUri: pending.verification_uri,
+    expiresIn: Math.max(0, pending.expires_in - elapsedSec),
+    interval: pending.interval,
+  };
+}
+
 export async function msAuthPoll(): Promise<MSAuthStatus> {
-  if (!pendingAuth) {
+  const pending = getPendingAuth();
+  if (!pending) {
     throw new Error("No pending auth");
   }
 
@@ -361,13 +551,13 @@ export async function msAuthPoll(): Promise<MSAuthStatus> {
     body: formBody({
       client_id: MS_CLIENT_ID,
       grant_type: "urn:ietf:params:oauth:grant-type:device_code",
-      device_code: pendingAuth.device_code,
+      device_code: pending.device_code,
     }),
   });
 
   if (res.status === 400) {
-    const error = (await res.json()) as { error: string };
-    const errorCode = error.error ?? "unknown";
+    const body = await res.text();
+    const errorCode = parseMsError(body).error ?? "unknown";
 
     if (errorCode === "authorization_pending") {
       return { status: "pending" };
@@ -379,39 +569,96 @@ export async function msAuthPoll(): Promise<MSAuthStatus> {
     }
 
     if (errorCode === "authorization_declined") {
-      pendingAuth = null;
-      return { status: "error", error: "Sign-in request was denied" };
+      setPendingAuth(null);
+      return notifyAuthResult({
+        status: "error",
+        error: "Sign-in request was denied",
+      });
     }
 
-    pendingAuth = null;
+    setPendingAuth(null);
 
     if (errorCode === "expired_token") {
-      return { status: "expired", error: "Device code expired" };
+      return notifyAuthResult({
+        status: "expired",
+        error: "Device code expired",
+      });
     }
 
-    return { status: "error", error: `Auth error: ${errorCode}` };
+    return notifyAuthResult({
+      status: "error",
+      error: describeMsError(body, `Auth error: ${errorCode}`),
+    });
   }
 
   if (!res.ok) {
-    pendingAuth = null;
+    setPendingAuth(null);
     const body = await res.text();
-    return { status: "error", error: `Token request failed: ${body}` };
+    return notifyAuthResult({
+      status: "error",
+      error: describeMsError(body, "Token request failed"),
+    });
   }
 
   // Token exchange succeeded — run chain under auth lock to prevent races
-  const token: TokenResponse = await res.json();
+  let token: TokenResponse;
+  try {
+    token = parseTokenResponse(await res.text(), "Token request failed");
+  } catch (err) {
+    setPendingAuth(null);
+    return notifyAuthResult({
+      status: "error",
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+  const msRefreshToken = token.refresh_token;
+  if (!msRefreshToken) {
+    setPendingAuth(null);
+    return notifyAuthResult({
+      status: "error",
+      error:
+        "Microsoft didn't return a refresh token. Your organization may block offline access for this app",
+    });
+  }
 
   const account = await withAuthLock(async () => {
-    return await completeAuthChain(token.access_token, token.refresh_token);
+    return await completeAuthChain(token.access_token, msRefreshToken);
   });
 
-  pendingAuth = null;
+  setPendingAuth(null);
+
+  return notifyAuthResult({ status: "complete", account });
+}
 
-  return { status: "complete", account };
+/**
+ * The user is usually in their browser when the flow finishes, so surface
+ * terminal poll results as a native notification. Fire-and-forget.
+ */
+function notifyAuthResult(status: MSAuthStatus): MSAuthStatus {
+  if (status.status === "complete" && status.account) {
+    void notify("Signed in", `Signed in as ${status.account.username}`);
+  } else if (status.status === "expired") {
+    void notify("Sign-in expired", "The login code expired. Please try again.");
+  } else if (status.status === "error") {
+    void notify("Sign-in failed", status.error ?? "Microsoft sign-in failed");
+  }
+  return status;
+}
+
+/** Open the pending device-code verification page in the default browser. */
+export async function openAuthUrl(): Promise<void> {
+  const pending = getPendingAuth();
+  if (!pending) {
+    throw new Error("No pending auth");
+  }
+
+  await shell.openExternal(
+    pending.verification_uri_complete ?? pending.verification_uri,
+  );
 }
 
 export async function msAuthCancel(): Promise<void> {
-  pendingAuth = null;
+  setPendingAuth(null);
 }
 
 export async function msAuthRefresh(
@@ -435,12 +682,16 @@ export async function msAuthRefresh(
 
   if (!res.ok) {
     const body = await res.text();
-    throw new Error(`Refresh token exchange failed: ${body}`);
+    throw new Error(describeMsError(body, "Refresh token exchange failed"));
   }
 
-  const token: TokenResponse = await res.json();
+  // Some tenants don't rotate the refresh token; keep the old one then
+  const token = parseTokenResponse(
+    await res.text(),
+    "Refresh token exchange failed",
+  );
   return withAuthLock(() =>
-    completeAuthChain(token.access_token, token.refresh_token),
+    completeAuthChain(token.access_token, token.refresh_token ?? refreshToken),
   );
 }
 
@@ -469,7 +720,260 @@ export async function getMcAccessToken(accountUuid: string): Promise<string> {
   return newToken;
 }
 
-export async function removeAccount(accountUuid: string): Promise<void> {
-  deleteTokenWithExpiry(`mc_access_token_${accountUuid}`);
-  deleteSecret(`ms_refresh_token_${accountUuid}`);
+/**
+ * Token state without touching the network: "valid" if a cached MC token is
+ * unexpired, "refreshable" if it expired but a refresh token is stored (the
+ * next launch will refresh), "signed_out" if there's nothing to refresh with.
+ */
+export function getAccountTokenStatus(
+  accountUuid: string,
+): "valid" | "refreshable" | "signed_out" {
+  if (getTokenIfValid(`mc_access_token_${accountUuid}`)) return "valid";
+  if (getSecret(`ms_refresh_token_${accountUuid}`)) return "refreshable";
+  return "signed_out";
+}
+
+/**
+ * When the cached MC token stops being usable (ms since epoch, already
+ * including the safety buffer), or null if none is cached.
+ */
+export function getAccessTokenExpiry(accountUuid: string): number | null {
+  const raw = getSecret(`mc_access_token_${accountUuid}_expires_at`);
+  if (raw === null) return null;
+  const expiresAt = Number(raw);
+  return Number.isFinite(expiresAt) ? expiresAt : null;
+}
+
+function decodeBase64UrlJson(segment: string): unknown {
+  const base64 = segment.replace(/-/g, "+").replace(/_/g, "/");
+  return JSON.parse(
+    atob(base64.padEnd(base64.length + ((4 - (base64.length % 4)) % 4), "=")),
+  );
+}
+
+/**
+ * Whether a stored Minecraft access token has the shape of a JWT: three
+ * base64url segments, the header and payload decoding to JSON objects.
+ * Doesn't check the signature or expiry — just that it isn't garbage.
+ */
+export function isPlausibleJwt(token: string): boolean {
+  const parts = token.split(".");
+  if (parts.length !== 3) return false;
+  if (!parts.every((part) => /^[A-Za-z0-9_-]+$/.test(part))) return false;
+  try {
+    return parts.slice(0, 2).every((part) => {
+      const decoded = decodeBase64UrlJson(part);
+      return typeof decoded === "object" && decoded !== null;
+    });
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Microsoft refresh tokens are opaque, so only reject what can't be one:
+ * empty, very short, or containing whitespace or non-ASCII characters
+ * (the usual result of a keyring returning the wrong bytes).
+ */
+export function isPlausibleRefreshToken(token: string): boolean {
+  return token.length >= 20 && /^[\x21-\x7e]+$/.test(token);
+}
+
+/**
+ * Whether a stored secret is present but unusable: it no longer decrypts,
+ * or decrypts to something that fails `isValid`.
+ */
+function isBrokenSecret(
+  key: string,
+  isValid: (value: string) => boolean,
+): boolean {
+  if (!hasSecret(key)) return false;
+  const value = getSecret(key);
+  return value === null || !isValid(value);
+}
+
+/**
+ * Check an account's stored tokens and clear any that are garbage (keyring
+ * bugs can return empty or mangled values), so a launch fails with "sign in
+ * again" instead of a cryptic auth error. A bad access token is just
+ * dropped — the refresh token gets a new one. A bad or missing refresh
+ * token clears everything, leaving the account signed out.
+ */
+export async function repairAccount(
+  accountUuid: string,
+): Promise<AccountRepairResult> {
+  return withAuthLock(async () => {
+    const mcKey = `mc_access_token_${accountUuid}`;
+    const refreshKey = `ms_refresh_token_${accountUuid}`;
+
+    const refreshToken = getSecret(refreshKey);
+    if (refreshToken === null || !isPlausibleRefreshToken(refreshToken)) {
+      const cleared = accountSecretKeys(accountUuid).filter(hasSecret);
+      deleteAccountSecrets(accountUuid);
+      return { status: "signed_out", cleared };
+    }
+
+    const accessBroken =
+      isBrokenSecret(mcKey, isPlausibleJwt) ||
+      isBrokenSecret(`${mcKey}_expires_at`, (v) => Number.isFinite(Number(v)));
+    if (!accessBroken) return { status: "healthy", cleared: [] };
+
+    const cleared = [mcKey, `${mcKey}_expires_at`].filter(hasSecret);
+    for (const key of cleared) deleteSecret(key);
+    return { status: "repaired", cleared };
+  });
+}
+
+/**
+ * Move an account's stored credentials from `oldUuid` to `newUuid` after a
+ * profile migration, and re-point the backend's account entry at the new
+ * profile. With `verify` (the default) the stored refresh token is used to
+ * fetch the real profile first, and the move is refused if it isn't
+ * `newUuid`. If the backend update fails, the credentials stay with
+ * `oldUuid`.
+ */
+export async function migrateAccountKeyring(
+  oldUuid: string,
+  newUuid: string,
+  options: { verify?: boolean } = {},
+): Promise<LauncherAccount> {
+  if (oldUuid === newUuid) {
+    throw new Error("Old and new profile ids are the same");
+  }
+  const refreshToken = getSecret(`ms_refresh_token_${oldUuid}`);
+  if (!refreshToken) {
+    throw new Error(`No stored credentials for account ${oldUuid}`);
+  }
+
+  let username: string | undefined;
+  if (options.verify ?? true) {
+    // Saves fresh tokens under whatever profile the token really belongs to
+    const profile = await msAuthRefresh(oldUuid);
+    if (profile.uuid !== newUuid) {
+      throw new Error(
+        `These credentials belong to profile ${profile.uuid}, not ${newUuid}`,
+      );
+    }
+    username = profile.username;
+  } else {
+    saveSecret(`ms_refresh_token_${newUuid}`, refreshToken);
+    const mcKey = `mc_access_token_${oldUuid}`;
+    const mcToken = getSecret(mcKey);
+    const mcExpiresAt = getSecret(`${mcKey}_expires_at`);
+    if (mcToken && mcExpiresAt) {
+      saveSecret(`mc_access_token_${newUuid}`, mcToken);
+      saveSecret(`mc_access_token_${newUuid}_expires_at`, mcExpiresAt);
+    }
+  }
+
+  // The old secrets go only once the backend entry points at newUuid;
+  // until then they are what the entry signs in with
+  let updated: LauncherAccount;
+  try {
+    updated = await repointBackendAccount(oldUuid, newUuid, username);
+  } catch (err) {
+    // A verified refresh may have rotated the refresh token, leaving only
+    // the new copy valid, so hand it back to the old profile
+    const rotated = getSecret(`ms_refresh_token_${newUuid}`);
+    if (rotated) saveSecret(`ms_refresh_token_${oldUuid}`, rotated);
+    deleteAccountSecrets(newUuid);
+    throw err;
+  }
+  deleteAccountSecrets(oldUuid);
+  return updated;
+}
+
+/** Point the backend's account entry for `oldUuid` at `newUuid`. */
+async function repointBackendAccount(
+  oldUuid: string,
+  newUuid: string,
+  username: string | undefined,
+): Promise<LauncherAccount> {
+  const listRes = await fetchWithTimeout(
+    backendUrl("/api/launcher/accounts"),
+    {},
+  );
+  if (!listRes.ok) {
+    throw new Error(`Failed to load accounts: ${await listRes.text()}`);
+  }
+  const accounts = (await listRes.json()) as LauncherAccount[];
+  const entry = accounts.find((a) => a.uuid === oldUuid);
+  if (!entry) {
+    throw new Error(`Account ${oldUuid} is not in the account list`);
+  }
+
+  const updateRes = await fetchWithTimeout(
+    backendUrl(`/api/launcher/accounts/${entry.id}`),
+    {
+      method: "PATCH",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ uuid: newUuid, username }),
+    },
+  );
+  if (!updateRes.ok) {
+    throw new Error(`Failed to update account: ${await updateRes.text()}`);
+  }
+  return (await updateRes.json()) as LauncherAccount;
+}
+
+/**
+ * Forget an account: delete all its stored secrets, its cached avatar and
+ * its backend entry (which also clears it as the default account). With
+ * `signOut`, also end the Microsoft session in the default browser — the
+ * device-code flow leaves the user signed in there, which matters on
+ * shared machines.
+ *
+ * Secrets are always cleared, even if the backend can't be reached; that
+ * error is thrown afterwards so the caller can retry the entry removal.
+ *
+ * The identity platform has no revocation endpoint for public clients, so
+ * the refresh token itself can't be invalidated remotely; deleting it
+ * locally is what stops this app from using it. Sign-out is best-effort and
+ * never blocks local removal (e.g. when offline).
+ */
+export async function removeAccount(
+  accountUuid: string,
+  options: { signOut?: boolean } = {},
+): Promise<void> {
+  deleteAccountSecrets(accountUuid);
+  await clearAvatar(accountUuid);
+
+  let backendError: unknown = null;
+  try {
+    await deleteBackendAccount(accountUuid);
+  } catch (err) {
+    backendError = err;
+  }
+
+  if (options.signOut) {
+    try {
+      await shell.openExternal(LOGOUT_URL);
+    } catch (err) {
+      console.warn("Failed to open Microsoft sign-out page:", err);
+    }
+  }
+
+  if (backendError) throw backendError;
+}
+
+/** Remove the account list entry for a profile; a missing one is fine. */
+async function deleteBackendAccount(accountUuid: string): Promise<void> {
+  const listRes = await fetchWithTimeout(
+    backendUrl("/api/launcher/accounts"),
+    {},
+  );
+  if (!listRes.ok) {
+    throw new Error(`Failed to load accounts: ${await listRes.text()}`);
+  }
+  const accounts = (await listRes.json()) as LauncherAccount[];
+  const entry = accounts.find((a) => a.uuid === accountUuid);
+  if (!entry) return;
+
+  const res = await fetchWithTimeout(
+    backendUrl(`/api/launcher/accounts/${entry.id}`),
+    { method: "DELETE" },
+  );
+  if (!res.ok && res.status !== 404) {
+    throw new Error(`Failed to remove account: ${await res.text()}`);
+  }
 }
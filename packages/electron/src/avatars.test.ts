@@ -0,0 +1,129 @@
+import {
+  existsSync,
+  mkdirSync,
+  mkdtempSync,
+  rmSync,
+  writeFileSync,
+} from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import {
+  clearAvatar,
+  defaultSkinModel,
+  renderSkinFace,
+  skinUrlFromProfile,
+} from "./avatars.js";
+
+// Only the pure skin helpers and the cache are tested; nothing here touches
+// Electron
+vi.mock("electron", () => ({ app: {}, dialog: {}, nativeImage: {} }));
+
+describe("defaultSkinModel", () => {
+  it("follows the parity of Java's UUID hashCode", () => {
+    expect(defaultSkinModel("00000000-0000-0000-0000-000000000000")).toBe(
+      "steve",
+    );
+    expect(defaultSkinModel("00000000000000000000000000000001")).toBe("alex");
+    // Two odd words cancel out
+    expect(defaultSkinModel("00000001000000010000000000000000")).toBe(
+      "steve",
+    );
+  });
+
+  it("rejects malformed UUIDs", () => {
+    expect(() => defaultSkinModel("steve")).toThrow("Invalid UUID");
+  });
+});
+
+describe("skinUrlFromProfile", () => {
+  const profile = (textures: unknown) => ({
+    id: "069a79f444e94726a5befca90e38aaf5",
+    properties: [{ name: "textures", value: btoa(JSON.stringify(textures)) }],
+  });
+
+  it("reads the skin URL, upgrading it to https", () => {
+    const url = "http://textures.minecraft.net/texture/ab";
+    expect(skinUrlFromProfile(profile({ textures: { SKIN: { url } } }))).toBe(
+      "https://textures.minecraft.net/texture/ab",
+    );
+  });
+
+  it("returns null without a custom skin", () => {
+    expect(skinUrlFromProfile(profile({ textures: {} }))).toBeNull();
+    expect(skinUrlFromProfile({ properties: [] })).toBeNull();
+    expect(skinUrlFromProfile(null)).toBeNull();
+  });
+});
+
+describe("renderSkinFace", () => {
+  function skin(height: number): Uint8Array {
+    const pixels = new Uint8Array(64 * height * 4);
+    const set = (x: number, y: number, rgba: number[]) =>
+      pixels.set(rgba, (y * 64 + x) * 4);
+    for (let y = 8; y < 16; y++) {
+      for (let x = 8; x < 16; x++) set(x, y, [200, 150, 100, 255]);
+      for (let x = 40; x < 48; x++) set(x, y, [0, 0, 0, 255]);
+    }
+    set(40, 8, [0, 0, 255, 0]); // one transparent hat pixel
+    return pixels;
+  }
+
+  it("scales the face with the hat composited on top", () => {
+    const out = renderSkinFace(skin(64), 64, 64, 16);
+    expect(out).toHaveLength(16 * 16 * 4);
+    // Top-left 2x2 block shows the face through the transparent hat pixel
+    expect(Array.from(out.subarray(0, 4))).toEqual([200, 150, 100, 255]);
+    expect(Array.from(out.subarray(4, 8))).toEqual([200, 150, 100, 255]);
+    // Elsewhere the opaque black hat covers it
+    expect(Array.from(out.subarray(8, 12))).toEqual([0, 0, 0, 255]);
+  });
+
+  it("ignores a legacy skin's fully opaque hat", () => {
+    const legacy = skin(32);
+    legacy[(8 * 64 + 40) * 4 + 3] = 255;
+    const out = renderSkinFace(legacy, 64, 32, 8);
+    expect(Array.from(out.subarray(8, 12))).toEqual([200, 150, 100, 255]);
+  });
+
+  it("rejects textures that aren't skins", () => {
+    expect(() => renderSkinFace(new Uint8Array(16), 2, 2, 8)).toThrow(
+      "Not a skin texture",
+    );
+  });
+});
+
+describe("clearAvatar", () => {
+  let dataDir: string;
+
+  beforeEach(() => {
+    dataDir = mkdtempSync(path.join(os.tmpdir(), "mc-avatars-"));
+    vi.stubEnv("MC_DATA_DIR", dataDir);
+  });
+
+  afterEach(() => {
+    vi.unstubAllEnvs();
+    rmSync(dataDir, { recursive: true, force: true });
+  });
+
+  it("deletes only that account's cached files", async () => {
+    const avatars = path.join(dataDir, "launcher", "avatars");
+    const removed = path.join(avatars, "0123456789abcdef0123456789abcdef");
+    const kept = path.join(avatars, "fedcba9876543210fedcba9876543210");
+    for (const dir of [removed, kept]) {
+      mkdirSync(path.join(dir, "skins"), { recursive: true });
+      writeFileSync(path.join(dir, "skins", "key.png"), "skin");
+      writeFileSync(path.join(dir, "key-64.png"), "render");
+    }
+
+    await clearAvatar("01234567-89AB-CDEF-0123-456789ABCDEF");
+
+    expect(existsSync(removed)).toBe(false);
+    expect(existsSync(path.join(kept, "key-64.png"))).toBe(true);
+  });
+
+  it("ignores something that isn't a UUID", async () => {
+    mkdirSync(path.join(dataDir, "launcher", "avatars"), { recursive: true });
+    await clearAvatar("..");
+    expect(existsSync(path.join(dataDir, "launcher", "avatars"))).toBe(true);
+  });
+});
@@ -0,0 +1,314 @@
+/**
+ * Account avatars: the face from a player's skin, rendered to a PNG at the
+ * requested size. Skin textures are immutable per URL, so they and the
+ * renders are cached on disk, per account, until the account is removed;
+ * only the profile → skin URL lookup expires.
+ * @module avatars
+ */
+
+import { nativeImage } from "electron";
+import { createHash } from "node:crypto";
+import { mkdir, readFile, rename, rm, writeFile } from "node:fs/promises";
+import path from "node:path";
+import { getDataDir } from "./data-dir.js";
+
+const PROFILE_URL =
+  "https://sessionserver.mojang.com/session/minecraft/profile/";
+
+const FETCH_TIMEOUT_MS = 10_000;
+
+/** Skin changes show up within this long. */
+const SKIN_URL_TTL_MS = 30 * 60_000;
+
+const MIN_SIZE = 8;
+const MAX_SIZE = 512;
+
+interface SkinUrlEntry {
+  url: string | null;
+  expiresAt: number;
+}
+
+export type SkinModel = "steve" | "alex";
+
+/** Keyed by undashed lowercase UUID. */
+const skinUrls = new Map<string, SkinUrlEntry>();
+
+/** 8x8 default faces; one letter per pixel, see FACE_COLORS. */
+const DEFAULT_FACES: Record<SkinModel, string[]> = {
+  steve: [
+    "HHHHHHHH",
+    "HHHHHHHH",
+    "HSSSSSSH",
+    "SSSSSSSS",
+    "SWBSSBWS",
+    "SSSNNSSS",
+    "SSMSSMSS",
+    "SSMMMMSS",
+  ],
+  alex: [
+    "OOOOOOOO",
+    "OOOOOOOO",
+    "OPPPPPOO",
+    "PPPPPPPO",
+    "PWGPPGWO",
+    "PPPPPPPO",
+    "PPPmmPPO",
+    "PPPPPPPO",
+  ],
+};
+
+const FACE_COLORS: Record<string, [number, number, number]> = {
+  H: [47, 31, 15],
+  S: [180, 132, 109],
+  W: [255, 255, 255],
+  B: [82, 61, 137],
+  N: [150, 95, 70],
+  M: [106, 64, 48],
+  O: [231, 142, 62],
+  P: [245, 209, 176],
+  G: [59, 138, 78],
+  m: [200, 110, 100],
+};
+
+/**
+ * The default skin the game gives a profile without one, by Java's
+ * UUID.hashCode() parity. (Newer versions pick from nine defaults, but
+ * Steve and Alex are the ones every version agrees on.)
+ */
+export function defaultSkinModel(uuid: string): SkinModel {
+  const hex = uuid.replace(/-/g, "");
+  if (!/^[0-9a-f]{32}$/i.test(hex)) {
+    throw new Error(`Invalid UUID: ${uuid}`);
+  }
+  // hashCode XORs the four 32-bit words; its low bit is the XOR of theirs
+  let parity = 0;
+  for (let i = 7; i < 32; i += 8) parity ^= parseInt(hex[i], 16) & 1;
+  return parity === 1 ? "alex" : "steve";
+}
+
+/**
+ * The skin texture URL from a session server profile (its base64 `textures`
+ * property), or null when the player has no custom skin.
+ */
+export function skinUrlFromProfile(profile: unknown): string | null {
+  const properties = (profile as { properties?: unknown })?.properties;
+  if (!Array.isArray(properties)) return null;
+  const textures = properties.find(
+    (p: { name?: unknown }) => p?.name === "textures",
+  ) as { value?: unknown } | undefined;
+  if (typeof textures?.value !== "string") return null;
+  try {
+    const decoded = JSON.parse(atob(textures.value)) as {
+      textures?: { SKIN?: { url?: unknown } };
+    };
+    const url = decoded.textures?.SKIN?.url;
+    return typeof url === "string" ? url.replace(/^http:/, "https:") : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Render a skin's face (plus the hat overlay) at `size`×`size` with
+ * nearest-neighbour scaling, so pixels stay crisp. Works on any 4-byte
+ * pixel format with alpha last (RGBA or BGRA); HD skins are handled by
+ * scaling the face region with the texture width.
+ */
+export function renderSkinFace(
+  pixels: Uint8Array,
+  width: number,
+  height: number,
+  size: number,
+): Uint8Array {
+  const square = height === width;
+  if (width < 64 || width % 64 !== 0 || (!square && height * 2 !== width)) {
+    throw new Error(`Not a skin texture: ${width}x${height}`);
+  }
+  if (pixels.length !== width * height * 4) {
+    throw new Error("Pixel data doesn't match the texture size");
+  }
+  const scale = width / 64;
+  const face = 8 * scale;
+  const at = (x: number, y: number) => (y * width + x) * 4;
+
+  // Legacy 64x32 skins often fill the hat with opaque black; the game
+  // ignores such a hat, and so do we
+  let useHat = true;
+  if (!square) {
+    useHat = false;
+    for (let y = face; y < 2 * face && !useHat; y++) {
+      for (let x = 5 * face; x < 6 * face; x++) {
+        if (pixels[at(x, y) + 3] < 128) {
+          useHat = true;
+          break;
+        }
+      }
+    }
+  }
+
+  const out = new Uint8Array(size * size * 4);
+  for (let y = 0; y < size; y++) {
+    const sy = face + Math.floor((y * face) / size);
+    for (let x = 0; x < size; x++) {
+      const sx = Math.floor((x * face) / size);
+      const base = at(face + sx, sy);
+      const o = (y * size + x) * 4;
+      for (let c = 0; c < 3; c++) out[o + c] = pixels[base + c];
+      out[o + 3] = 255;
+      if (useHat) {
+        const hat = at(5 * face + sx, sy);
+        const alpha = pixels[hat + 3] / 255;
+        for (let c = 0; c < 3; c++) {
+          out[o + c] = Math.round(
+            pixels[hat + c] * alpha + out[o + c] * (1 - alpha),
+          );
+        }
+      }
+    }
+  }
+  return out;
+}
+
+/** Undashed lowercase UUID, or null if it isn't one. */
+function normalizeUuid(accountUuid: string): string | null {
+  const uuid = accountUuid.replace(/-/g, "").toLowerCase();
+  return /^[0-9a-f]{32}$/.test(uuid) ? uuid : null;
+}
+
+/** Cached skin and renders for one account. */
+function avatarsDir(uuid: string): string {
+  return path.join(getDataDir(), "launcher", "avatars", uuid);
+}
+
+async function fetchBytes(url: string): Promise<Response> {
+  const res = await fetch(url, {
+    signal: AbortSignal.timeout(FETCH_TIMEOUT_MS),
+  });
+  if (!res.ok && res.status !== 204) {
+    throw new Error(`${url} returned HTTP ${res.status}`);
+  }
+  return res;
+}
+
+/** The player's skin URL, or null if they use a default skin. */
+async function getSkinUrl(uuid: string): Promise<string | null> {
+  const cached = skinUrls.get(uuid);
+  if (cached && cached.expiresAt > Date.now()) return cached.url;
+
+  const res = await fetchBytes(PROFILE_URL + uuid);
+  // 204: no such profile (e.g. an offline account)
+  const url = res.status === 204 ? null : skinUrlFromProfile(await res.json());
+  skinUrls.set(uuid, { url, expiresAt: Date.now() + SKIN_URL_TTL_MS });
+  return url;
+}
+
+async function writeAtomic(file: string, data: Buffer): Promise<void> {
+  await mkdir(path.dirname(file), { recursive: true });
+  const temp = `${file}.${process.pid}.tmp`;
+  await writeFile(temp, data);
+  await rename(temp, file);
+}
+
+async function readCached(file: string): Promise<Buffer | null> {
+  try {
+    return await readFile(file);
+  } catch {
+    return null;
+  }
+}
+
+async function getSkinTexture(
+  uuid: string,
+  url: string,
+  key: string,
+): Promise<Buffer> {
+  const file = path.join(avatarsDir(uuid), "skins", `${key}.png`);
+  const cached = await readCached(file);
+  if (cached) return cached;
+  const png = Buffer.from(await (await fetchBytes(url)).arrayBuffer());
+  await writeAtomic(file, png);
+  return png;
+}
+
+function toPng(pixels: Uint8Array, size: number): Buffer {
+  return nativeImage
+    .createFromBitmap(Buffer.from(pixels), { width: size, height: size })
+    .toPNG();
+}
+
+function renderSkin(texture: Buffer, size: number): Buffer {
+  const image = nativeImage.createFromBuffer(texture);
+  const { width, height } = image.getSize();
+  // toBitmap is BGRA on every platform we ship; renderSkinFace doesn't mind
+  const pixels = renderSkinFace(image.toBitmap(), width, height, size);
+  return toPng(pixels, size);
+}
+
+function renderDefaultFace(model: SkinModel, size: number): Buffer {
+  const rows = DEFAULT_FACES[model];
+  const pixels = new Uint8Array(size * size * 4);
+  for (let y = 0; y < size; y++) {
+    const row = rows[Math.floor((y * 8) / size)];
+    for (let x = 0; x < size; x++) {
+      const [r, g, b] = FACE_COLORS[row[Math.floor((x * 8) / size)]];
+      // createFromBitmap expects BGRA
+      pixels.set([b, g, r, 255], (y * size + x) * 4);
+    }
+  }
+  return toPng(pixels, size);
+}
+
+/**
+ * A PNG of the account's face at `size`×`size` pixels. Players without a
+ * custom skin, and lookups that fail (offline), get the default Steve or
+ * Alex face the game would show.
+ */
+export async function getAccountAvatar(
+  accountUuid: string,
+  size: number,
+): Promise<Buffer> {
+  if (!Number.isInteger(size) || size < MIN_SIZE || size > MAX_SIZE) {
+    throw new Error(`Avatar size must be ${MIN_SIZE}-${MAX_SIZE} pixels`);
+  }
+  const uuid = normalizeUuid(accountUuid);
+  if (!uuid) throw new Error(`Invalid account UUID: ${accountUuid}`);
+  const model = defaultSkinModel(uuid);
+
+  let url: string | null;
+  try {
+    url = await getSkinUrl(uuid);
+  } catch (err) {
+    console.warn(`Skin lookup for ${uuid} failed:`, err);
+    url = null;
+  }
+  if (!url) return renderDefaultFace(model, size);
+
+  const key = createHash("sha1").update(url).digest("hex");
+  const file = path.join(avatarsDir(uuid), `${key}-${size}.png`);
+  const cached = await readCached(file);
+  if (cached) return cached;
+
+  try {
+    const png = renderSkin(await getSkinTexture(uuid, url, key), size);
+    await writeAtomic(file, png);
+    return png;
+  } catch (err) {
+    console.warn(`Rendering the skin for ${uuid} failed:`, err);
+    return renderDefaultFace(model, size);
+  }
+}
+
+/**
+ * Forget a removed account's avatar: its cached skin, renders and skin URL.
+ * Never throws; a failed delete only leaves stale files behind.
+ */
+export async function clearAvatar(accountUuid: string): Promise<void> {
+  const uuid = normalizeUuid(accountUuid);
+  if (!uuid) return;
+  skinUrls.delete(uuid);
+  try {
+    await rm(avatarsDir(uuid), { recursive: true, force: true });
+  } catch (err) {
+    console.warn(`Failed to clear the avatar cache for ${uuid}:`, err);
+  }
+}
@@ -0,0 +1,27 @@
+/**
+ * Single source of truth for the backend's HTTP port. The requested port is
+ * only a starting point — the backend moves up if it's taken — so this is
+ * set from the port the backend confirms: startServer's `actualPort` for the
+ * in-process backend, or the `backend.port` file a separately run dev
+ * backend writes once listening. Read by every main-process HTTP caller and
+ * handed to the renderer via preload.
+ * @module backend-port
+ */
+
+let backendPort = parseInt(
+  process.env.BACKEND_PORT ?? process.env.PORT ?? "3001",
+  10,
+);
+
+export function getBackendPort(): number {
+  return backendPort;
+}
+
+export function setBackendPort(port: number): void {
+  backendPort = port;
+  process.env.BACKEND_PORT = String(port);
+}
+
+export function backendUrl(pathname = ""): string {
+  return `http://localhost:${backendPort}${pathname}`;
+}
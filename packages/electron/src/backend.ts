@@ -0,0 +1,390 @@
+/**
+ * Lifecycle of the in-process backend (production builds only — in dev the
+ * backend runs as its own process and only its port is discovered here).
+ * @module backend
+ */
+
+import { app, BrowserWindow } from "electron";
+import { readFile } from "node:fs/promises";
+import type { Server as HttpServer } from "node:http";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+import type { WebSocketServer } from "ws";
+import type {
+  BackendHealth,
+  BackendReloadResult,
+  ServerStopOutcome,
+} from "@mc-server-manager/shared";
+import { getBackendPort, setBackendPort } from "./backend-port.js";
+import { watchServerCrashes } from "./crash-notifications.js";
+import { checkBackendDataDir } from "./data-dir.js";
+import { emitToRenderers } from "./events.js";
+import {
+  checkBackendPaths,
+  logStartup,
+  recordStartupFailure,
+} from "./startup-log.js";
+
+export const BACKEND_HOST = process.env.HOST ?? "localhost";
+
+interface RunningBackend {
+  httpServer: HttpServer;
+  wss: WebSocketServer;
+  shutdown: (s: HttpServer, w: WebSocketServer) => Promise<void>;
+}
+
+let running: RunningBackend | null = null;
+let restarting = false;
+
+/** Restarts allowed within SUPERVISOR_WINDOW_MS before giving up. */
+const SUPERVISOR_MAX_RESTARTS = 5;
+const SUPERVISOR_WINDOW_MS = 5 * 60_000;
+/** Uptime after which the backend counts as healthy again (count resets). */
+const SUPERVISOR_STABLE_MS = 60_000;
+/** First restart delay; doubles with each consecutive restart. */
+const SUPERVISOR_BASE_DELAY_MS = 1_000;
+
+/**
+ * Bookkeeping for automatic restarts when the backend's HTTP server closes
+ * without us asking it to.
+ */
+interface BackendSupervisor {
+  restartCount: number;
+  lastRestartAt: number | null;
+  startedAt: number | null;
+  shuttingDown: boolean;
+  pendingRestart: ReturnType<typeof setTimeout> | null;
+}
+
+const supervisor: BackendSupervisor = {
+  restartCount: 0,
+  lastRestartAt: null,
+  startedAt: null,
+  shuttingDown: false,
+  pendingRestart: null,
+};
+
+export function backendOrigin(): string {
+  return `http://${BACKEND_HOST}:${getBackendPort()}`;
+}
+
+export async function waitForServer(
+  url: string,
+  timeoutMs = 15_000,
+): Promise<void> {
+  const start = Date.now();
+  while (Date.now() - start < timeoutMs) {
+    try {
+      const response = await fetch(url);
+      if (response.ok) return;
+    } catch {
+      // Server not ready yet
+    }
+    await new Promise((r) => setTimeout(r, 200));
+  }
+  throw new Error(
+    `Server at ${url} did not become ready within ${timeoutMs}ms`,
+  );
+}
+
+/**
+ * Where a dev backend (run from the repo, not by us) writes its port: its
+ * default data dir is `<repo>/data`. BACKEND_PORT_FILE overrides this for a
+ * backend started with a different DATA_DIR.
+ */
+function devBackendPortFile(): string {
+  if (process.env.BACKEND_PORT_FILE) return process.env.BACKEND_PORT_FILE;
+  const distDir = path.dirname(fileURLToPath(import.meta.url));
+  return path.resolve(distDir, "..", "..", "..", "data", "backend.port");
+}
+
+async function readPortFile(file: string): Promise<number | null> {
+  try {
+    const port = parseInt((await readFile(file, "utf-8")).trim(), 10);
+    return port > 0 && port < 65536 ? port : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Development only: wait for the separately run backend to report the port
+ * it actually listens on, and use that. A port file left by an earlier run
+ * is ignored until something answers /api/health on it. Falls back to the
+ * requested port (with a warning) if nothing turns up within `timeoutMs`.
+ */
+export async function discoverDevBackendPort(
+  timeoutMs = 30_000,
+): Promise<number> {
+  const file = devBackendPortFile();
+  const start = Date.now();
+  while (Date.now() - start < timeoutMs) {
+    const port = await readPortFile(file);
+    if (port !== null) {
+      try {
+        const response = await fetch(
+          `http://${BACKEND_HOST}:${port}/api/health`,
+        );
+        if (response.ok) {
+          setBackendPort(port);
+          return port;
+        }
+      } catch {
+        // Stale file, or the backend is still starting
+      }
+    }
+    await new Promise((r) => setTimeout(r, 200));
+  }
+  console.warn(
+    `No backend reported its port in ${file} within ${timeoutMs}ms; ` +
+      `assuming ${getBackendPort()}`,
+  );
+  return getBackendPort();
+}
+
+/** Timeout for a single diagnostics health probe. */
+const HEALTH_CHECK_TIMEOUT_MS = 3_000;
+
+/**
+ * Probe /api/health once. Distinguishes a backend that isn't running from one
+ * that's running but not responding.
+ */
+export async function checkBackendHealth(): Promise<BackendHealth> {
+  const port = getBackendPort();
+  const processRunning = app.isPackaged ? running !== null : null;
+  const result: BackendHealth = {
+    reachable: false,
+    processRunning,
+    port,
+    latencyMs: null,
+    version: null,
+    error: null,
+  };
+
+  if (processRunning === false) {
+    result.error = "Backend is not running";
+    return result;
+  }
+
+  const start = performance.now();
+  try {
+    const response = await fetch(`${backendOrigin()}/api/health`, {
+      signal: AbortSignal.timeout(HEALTH_CHECK_TIMEOUT_MS),
+    });
+    result.latencyMs = Math.round(performance.now() - start);
+    if (!response.ok) {
+      result.error = `Backend responded with HTTP ${response.status}`;
+      return result;
+    }
+    const body = (await response.json()) as { version?: string | null };
+    result.reachable = true;
+    result.version = body.version ?? null;
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    result.error =
+      err instanceof Error && err.name === "TimeoutError"
+        ? `Backend is running but did not respond within ${HEALTH_CHECK_TIMEOUT_MS}ms`
+        : `Backend is not responding: ${message}`;
+  }
+  return result;
+}
+
+// Dynamic import loads the backend without triggering standalone auto-start
+// because process.versions.electron is set, skipping the isStandaloneEntry guard.
+export async function startBackend(): Promise<void> {
+  logStartup("info", "backend-starting", {
+    host: BACKEND_HOST,
+    port: getBackendPort(),
+    paths: checkBackendPaths(),
+  });
+
+  const backend = await import("@mc-server-manager/backend");
+  checkBackendDataDir(backend.config.dataDir);
+
+  backend.initDatabase();
+  watchServerCrashes(backend.serverManager);
+
+  const result = await backend.startServer(getBackendPort(), BACKEND_HOST);
+  setBackendPort(result.actualPort);
+  const current: RunningBackend = {
+    httpServer: result.httpServer,
+    wss: result.wss,
+    shutdown: backend.shutdownServer,
+  };
+  running = current;
+  supervisor.startedAt = Date.now();
+
+  // stopBackend clears `running` first, so a close we didn't initiate is
+  // one where this instance is still the current one.
+  result.httpServer.once("close", () => {
+    if (running === current) {
+      running = null;
+      // Detach the WS layer's listeners before a replacement is attached
+      current.wss.close();
+      handleUnexpectedStop();
+    }
+  });
+
+  backend.autoStartServers();
+}
+
+function handleUnexpectedStop(): void {
+  if (supervisor.shuttingDown) return;
+
+  const now = Date.now();
+  const wasStable =
+    supervisor.startedAt !== null &&
+    now - supervisor.startedAt >= SUPERVISOR_STABLE_MS;
+  const windowExpired =
+    supervisor.lastRestartAt !== null &&
+    now - supervisor.lastRestartAt > SUPERVISOR_WINDOW_MS;
+  if (wasStable || windowExpired) {
+    supervisor.restartCount = 0;
+  }
+
+  supervisor.restartCount++;
+  supervisor.lastRestartAt = now;
+
+  if (supervisor.restartCount > SUPERVISOR_MAX_RESTARTS) {
+    console.error(
+      `Backend stopped ${SUPERVISOR_MAX_RESTARTS} times within ${SUPERVISOR_WINDOW_MS / 60_000} minutes; giving up`,
+    );
+    emitToRenderers("backend-down", { restarts: SUPERVISOR_MAX_RESTARTS });
+    return;
+  }
+
+  const delayMs =
+    SUPERVISOR_BASE_DELAY_MS * 2 ** (supervisor.restartCount - 1);
+  console.warn(
+    `Backend stopped unexpectedly; restart ${supervisor.restartCount}/${SUPERVISOR_MAX_RESTARTS} in ${delayMs}ms`,
+  );
+  emitToRenderers("backend-restarting");
+
+  supervisor.pendingRestart = setTimeout(() => {
+    supervisor.pendingRestart = null;
+    if (supervisor.shuttingDown) return;
+    startBackend()
+      .then(() => waitForServer(`${backendOrigin()}/api/health`))
+      .then(() => emitToRenderers("backend-ready", { port: getBackendPort() }))
+      .catch((err) => {
+        console.error("Backend restart failed:", err);
+        recordStartupFailure(err);
+        handleUnexpectedStop();
+      });
+  }, delayMs);
+}
+
+/** Called on app quit so the supervisor doesn't restart a backend we're stopping. */
+export function beginShutdown(): void {
+  supervisor.shuttingDown = true;
+  if (supervisor.pendingRestart) {
+    clearTimeout(supervisor.pendingRestart);
+    supervisor.pendingRestart = null;
+  }
+}
+
+/**
+ * Stop every managed Minecraft server without stopping the backend itself,
+ * reporting each server's outcome. Returns null when there's no in-process
+ * backend (dev).
+ */
+export async function stopManagedServers(
+  graceful = true,
+): Promise<ServerStopOutcome[] | null> {
+  if (!running) return null;
+  const backend = await import("@mc-server-manager/backend");
+  return backend.serverManager.stopAll({ graceful });
+}
+
+/**
+ * Stop all managed servers — the server-side counterpart of killAllGames.
+ * `graceful` sends `stop` and waits; otherwise servers are killed at once.
+ */
+export async function stopAllServers(
+  graceful: boolean,
+): Promise<ServerStopOutcome[]> {
+  const outcomes = await stopManagedServers(graceful);
+  if (!outcomes) {
+    throw new Error(
+      "In development the backend runs separately — stop its servers from the web UI",
+    );
+  }
+  return outcomes;
+}
+
+/** Stop managed servers, close WS/HTTP and the database. No-op if not running. */
+export async function stopBackend(): Promise<void> {
+  if (!running) return;
+  const { httpServer, wss, shutdown } = running;
+  running = null;
+  await shutdown(httpServer, wss);
+}
+
+/**
+ * Bounce the backend in place. Managed servers are stopped and autoStart
+ * servers come back up with it. Emits `backend-restarting` / `backend-ready`.
+ */
+export async function restartBackend(): Promise<void> {
+  if (!app.isPackaged) {
+    throw new Error(
+      "In development the backend runs separately — restart it from your terminal",
+    );
+  }
+  if (restarting) {
+    throw new Error("Backend is already restarting");
+  }
+
+  restarting = true;
+  if (supervisor.pendingRestart) {
+    clearTimeout(supervisor.pendingRestart);
+    supervisor.pendingRestart = null;
+  }
+  const previousPort = getBackendPort();
+  emitToRenderers("backend-restarting");
+
+  try {
+    await stopBackend();
+    await startBackend();
+    await waitForServer(`${backendOrigin()}/api/health`);
+  } catch (err) {
+    recordStartupFailure(err);
+    throw err;
+  } finally {
+    restarting = false;
+  }
+
+  // The UI is served by the backend, so follow it if it had to move ports
+  if (getBackendPort() !== previousPort) {
+    for (const win of BrowserWindow.getAllWindows()) {
+      if (!win.isDestroyed()) win.loadURL(backendOrigin());
+    }
+  }
+
+  emitToRenderers("backend-ready", { port: getBackendPort() });
+}
+
+/**
+ * Apply config changes without bouncing the backend, so managed servers and
+ * in-flight requests carry on. A backend build without `reloadConfig` is
+ * restarted instead, and the result says so.
+ */
+export async function reloadBackendConfig(): Promise<BackendReloadResult> {
+  if (!app.isPackaged) {
+    throw new Error(
+      "In development the backend runs separately — send it SIGHUP to reload",
+    );
+  }
+  if (!running) {
+    throw new Error("Backend is not running");
+  }
+
+  const backend = await import("@mc-server-manager/backend");
+  if ("reloadConfig" in backend && typeof backend.reloadConfig === "function") {
+    return { method: "reload", reloaded: backend.reloadConfig() };
+  }
+
+  const warning = "This backend can't reload its config; restarted it instead";
+  console.warn(warning);
+  await restartBackend();
+  return { method: "restart", reloaded: [], warning };
+}
@@ -0,0 +1,211 @@
+/**
+ * Maintenance cleanup of regenerable data under the data dir.
+ * Anything a running game references is skipped, never removed.
+ * @module cache
+ */
+
+import {
+  existsSync,
+  readdirSync,
+  realpathSync,
+  rmSync,
+  statSync,
+  type Dirent,
+} from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import type {
+  CacheCategory,
+  CacheCategoryResult,
+  CacheClearResult,
+} from "@mc-server-manager/shared";
+import { getDataDir } from "./data-dir.js";
+import { getActiveGamePaths, resolveNativesBase } from "./launcher.js";
+import { getLogsDir } from "./logs.js";
+
+const CACHE_CATEGORIES: readonly CacheCategory[] = [
+  "natives",
+  "logs",
+  "partial_downloads",
+  "runtimes",
+];
+
+/** Temp archives left by downloadJava (`java-<version>-<timestamp>.<ext>`). */
+const PARTIAL_JAVA_DOWNLOAD = /^java-\d+-\d+\.(zip|tar\.gz)$/;
+
+/** Temp files younger than this may belong to a download still in progress. */
+const PARTIAL_DOWNLOAD_MIN_AGE_MS = 60 * 60_000;
+
+function launcherDir(): string {
+  return path.join(getDataDir(), "launcher");
+}
+
+function sizeOf(target: string): number {
+  let stats;
+  try {
+    stats = statSync(target);
+  } catch {
+    return 0;
+  }
+  if (!stats.isDirectory()) return stats.size;
+
+  let total = 0;
+  for (const entry of readdirSync(target)) {
+    total += sizeOf(path.join(target, entry));
+  }
+  return total;
+}
+
+function safeRealpath(p: string): string {
+  try {
+    return realpathSync(p);
+  } catch {
+    return path.resolve(p);
+  }
+}
+
+function isInside(child: string, parent: string): boolean {
+  const rel = path.relative(parent, child);
+  return rel === "" || (!rel.startsWith("..") && !path.isAbsolute(rel));
+}
+
+function listEntries(dir: string): Dirent[] {
+  if (!existsSync(dir)) return [];
+  try {
+    return readdirSync(dir, { withFileTypes: true });
+  } catch {
+    return [];
+  }
+}
+
+/** Remove every entry in `dir` for which `keep` returns false. */
+function clearEntries(
+  category: CacheCategory,
+  dir: string,
+  keep: (entryPath: string) => boolean,
+): CacheCategoryResult {
+  const result: CacheCategoryResult = {
+    category,
+    bytesFreed: 0,
+    removed: 0,
+    skipped: [],
+  };
+
+  for (const entry of listEntries(dir)) {
+    const entryPath = path.join(dir, entry.name);
+    if (keep(entryPath)) {
+      result.skipped.push(entryPath);
+      continue;
+    }
+    const size = sizeOf(entryPath);
+    try {
+      rmSync(entryPath, { recursive: true, force: true });
+      result.bytesFreed += size;
+      result.removed++;
+    } catch (err) {
+      console.error(`Failed to remove ${entryPath}:`, err);
+      result.skipped.push(entryPath);
+    }
+  }
+  return result;
+}
+
+function clearNatives(): CacheCategoryResult {
+  const active = new Set(
+    getActiveGamePaths().nativesDirs.map((d) => safeRealpath(d)),
+  );
+  return clearEntries(
+    "natives",
+    resolveNativesBase(launcherDir()),
+    (entryPath) => active.has(safeRealpath(entryPath)),
+  );
+}
+
+function clearLogs(): CacheCategoryResult {
+  const dir = getLogsDir();
+  // The most recently written file is the backend's live log
+  let newest: string | null = null;
+  let newestMtime = 0;
+  for (const entry of listEntries(dir)) {
+    if (!entry.isFile()) continue;
+    const entryPath = path.join(dir, entry.name);
+    const mtime = statSync(entryPath).mtimeMs;
+    if (mtime > newestMtime) {
+      newestMtime = mtime;
+      newest = entryPath;
+    }
+  }
+  return clearEntries("logs", dir, (entryPath) => entryPath === newest);
+}
+
+function clearPartialDownloads(): CacheCategoryResult {
+  const now = Date.now();
+  const result: CacheCategoryResult = {
+    category: "partial_downloads",
+    bytesFreed: 0,
+    removed: 0,
+    skipped: [],
+  };
+
+  // Only our own temp archives — everything else in tmpdir isn't ours
+  for (const entry of listEntries(os.tmpdir())) {
+    if (!entry.isFile() || !PARTIAL_JAVA_DOWNLOAD.test(entry.name)) continue;
+    const entryPath = path.join(os.tmpdir(), entry.name);
+    const stats = statSync(entryPath);
+    if (now - stats.mtimeMs < PARTIAL_DOWNLOAD_MIN_AGE_MS) {
+      result.skipped.push(entryPath);
+      continue;
+    }
+    try {
+      rmSync(entryPath, { force: true });
+      result.bytesFreed += stats.size;
+      result.removed++;
+    } catch (err) {
+      console.error(`Failed to remove ${entryPath}:`, err);
+      result.skipped.push(entryPath);
+    }
+  }
+  return result;
+}
+
+function clearRuntimes(): CacheCategoryResult {
+  const javaPaths = getActiveGamePaths().javaPaths.map((p) => safeRealpath(p));
+  return clearEntries(
+    "runtimes",
+    path.join(launcherDir(), "runtime"),
+    (entryPath) => {
+      const runtime = safeRealpath(entryPath);
+      return javaPaths.some((javaPath) => isInside(javaPath, runtime));
+    },
+  );
+}
+
+const CLEARERS: Record<CacheCategory, () => CacheCategoryResult> = {
+  natives: clearNatives,
+  logs: clearLogs,
+  partial_downloads: clearPartialDownloads,
+  runtimes: clearRuntimes,
+};
+
+/**
+ * Clear the requested cache categories and report what was freed.
+ * Unknown category names are rejected up front so nothing is half-done.
+ */
+export async function clearCache(what: string[]): Promise<CacheClearResult> {
+  const unknown = what.filter(
+    (c) => !CACHE_CATEGORIES.includes(c as CacheCategory),
+  );
+  if (unknown.length > 0) {
+    throw new Error(
+      `Unknown cache categories: ${unknown.join(", ")}. Valid: ${CACHE_CATEGORIES.join(", ")}`,
+    );
+  }
+
+  const categories = [...new Set(what as CacheCategory[])].map((c) =>
+    CLEARERS[c](),
+  );
+  return {
+    categories,
+    totalBytesFreed: categories.reduce((sum, c) => sum + c.bytesFreed, 0),
+  };
+}
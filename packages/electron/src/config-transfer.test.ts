@@ -0,0 +1,83 @@
+import { CONFIG_EXPORT_FORMAT, parseConfigExport } from "./config-transfer.js";
+
+vi.mock("electron", () => ({}));
+
+describe("parseConfigExport", () => {
+  const instance = {
+    id: "abc",
+    name: "Survival",
+    mcVersion: "1.21.4",
+    versionType: "release",
+    loader: null,
+    javaVersion: 21,
+    ramMin: 2,
+    ramMax: 4,
+    jvmArgs: [],
+    gameArgs: [],
+  };
+
+  function files(overrides: Record<string, unknown> = {}) {
+    return {
+      "manifest.json": {
+        format: CONFIG_EXPORT_FORMAT,
+        version: 1,
+        exportedAt: "2026-01-01T00:00:00.000Z",
+        appVersion: "1.0.0",
+      },
+      "settings.json": { javaPath: "java", dataDir: "/home/me/data" },
+      "accounts.json": [
+        { uuid: "069a79f444e94726a5befca90e38aaf5", username: "Notch" },
+      ],
+      "instances.json": [instance],
+      "jvm-presets.json": { Mine: ["-XX:+UseZGC"] },
+      ...overrides,
+    };
+  }
+
+  it("parses a valid backup and drops excluded settings", () => {
+    const result = parseConfigExport(files());
+    expect(result.settings).toEqual({ javaPath: "java" });
+    expect(result.accounts).toEqual([
+      {
+        uuid: "069a79f444e94726a5befca90e38aaf5",
+        username: "Notch",
+        accountType: "msa",
+      },
+    ]);
+    expect(result.instances).toHaveLength(1);
+    expect(result.jvmPresets).toEqual({ Mine: ["-XX:+UseZGC"] });
+  });
+
+  it("rejects archives with a missing entry", () => {
+    expect(() =>
+      parseConfigExport(files({ "instances.json": undefined })),
+    ).toThrow("instances.json is missing");
+  });
+
+  it("rejects foreign and newer manifests", () => {
+    expect(() =>
+      parseConfigExport(files({ "manifest.json": { format: "other" } })),
+    ).toThrow("unrecognised manifest");
+    expect(() =>
+      parseConfigExport(
+        files({
+          "manifest.json": { format: CONFIG_EXPORT_FORMAT, version: 99 },
+        }),
+      ),
+    ).toThrow("newer version");
+  });
+
+  it("rejects malformed accounts, instances and presets", () => {
+    expect(() =>
+      parseConfigExport(files({ "accounts.json": [{ uuid: "nope" }] })),
+    ).toThrow("accounts.json[0]");
+    expect(() =>
+      parseConfigExport(
+        files({ "instances.json": [{ ...instance, ramMax: "4" }] }),
+      ),
+    ).toThrow("instances.json[0]");
+    expect(() =>
+      parseConfigExport(files({ "jvm-presets.json": { Mine: "-Xmx4G" } })),
+    ).toThrow("jvm-presets.json");
+  });
+});
@@ -0,0 +1,441 @@
+/**
+ * Config backups for moving to a new machine: a zip of app settings, the
+ * account list, instance configs and user JVM presets. Secrets never go in
+ * — tokens stay in the OS keychain, so restored accounts need a fresh
+ * sign-in — and neither do game files or worlds.
+ * @module config-transfer
+ */
+
+import AdmZip from "adm-zip";
+import { app } from "electron";
+import { existsSync } from "node:fs";
+import type {
+  AppSettings,
+  ConfigImportResult,
+  LauncherAccount,
+  LauncherInstance,
+  UpdateInstanceRequest,
+} from "@mc-server-manager/shared";
+import { getAccountTokenStatus } from "./auth.js";
+import { backendUrl } from "./backend-port.js";
+import {
+  checkJvmPreset,
+  getUserJvmPresets,
+  saveJvmPreset,
+} from "./jvm-presets.js";
+
+/** `format` tag in a config backup's manifest.json */
+export const CONFIG_EXPORT_FORMAT = "mc-server-manager-config";
+/** Newest backup layout this build can read */
+export const CONFIG_EXPORT_VERSION = 1;
+
+/**
+ * Settings left out of backups: the data dir is machine-specific and the
+ * CurseForge key is a secret.
+ */
+export const CONFIG_EXPORT_EXCLUDED_SETTINGS = [
+  "dataDir",
+  "curseforgeApiKey",
+] as const;
+
+export interface ConfigExportManifest {
+  format: typeof CONFIG_EXPORT_FORMAT;
+  version: number;
+  exportedAt: string;
+  appVersion: string;
+}
+
+/** An account as stored in a backup — identity only, never tokens */
+export type ConfigExportAccount = Pick<
+  LauncherAccount,
+  "uuid" | "username" | "accountType"
+>;
+
+/** Contents of a config backup archive, one JSON file per field */
+export interface ConfigExport {
+  manifest: ConfigExportManifest;
+  settings: Partial<AppSettings>;
+  accounts: ConfigExportAccount[];
+  instances: LauncherInstance[];
+  /** User JVM presets by name; built-ins aren't exported */
+  jvmPresets: Record<string, string[]>;
+}
+
+/** Archive entry holding each ConfigExport field */
+export const CONFIG_EXPORT_FILES: Record<keyof ConfigExport, string> = {
+  manifest: "manifest.json",
+  settings: "settings.json",
+  accounts: "accounts.json",
+  instances: "instances.json",
+  jvmPresets: "jvm-presets.json",
+};
+
+function isStringArray(value: unknown): value is string[] {
+  return Array.isArray(value) && value.every((v) => typeof v === "string");
+}
+
+function isPlainObject(value: unknown): value is Record<string, unknown> {
+  return !!value && typeof value === "object" && !Array.isArray(value);
+}
+
+function checkBackupInstance(raw: unknown, index: number): LauncherInstance {
+  const where = `${CONFIG_EXPORT_FILES.instances}[${index}]`;
+  if (!isPlainObject(raw)) throw new Error(`${where} is not an object`);
+  for (const key of ["id", "name", "mcVersion", "versionType"]) {
+    if (typeof raw[key] !== "string" || !raw[key]) {
+      throw new Error(`${where} has no ${key}`);
+    }
+  }
+  for (const key of ["javaVersion", "ramMin", "ramMax"]) {
+    if (typeof raw[key] !== "number") {
+      throw new Error(`${where} has no numeric ${key}`);
+    }
+  }
+  if (!isStringArray(raw.jvmArgs) || !isStringArray(raw.gameArgs)) {
+    throw new Error(`${where} has malformed jvmArgs/gameArgs`);
+  }
+  return raw as unknown as LauncherInstance;
+}
+
+/**
+ * Validate the parsed JSON files of a config backup, keyed by archive entry
+ * name (missing entries undefined). Throws on the first problem, so nothing
+ * is applied from a damaged or foreign archive. Excluded settings are
+ * dropped even if present.
+ */
+export function parseConfigExport(
+  files: Record<string, unknown>,
+): ConfigExport {
+  const read = (field: keyof ConfigExport): unknown => {
+    const name = CONFIG_EXPORT_FILES[field];
+    if (files[name] === undefined) {
+      throw new Error(`Not a config backup: ${name} is missing`);
+    }
+    return files[name];
+  };
+
+  const manifest = read("manifest");
+  if (!isPlainObject(manifest) || manifest.format !== CONFIG_EXPORT_FORMAT) {
+    throw new Error("Not a config backup: unrecognised manifest");
+  }
+  if (typeof manifest.version !== "number" || manifest.version < 1) {
+    throw new Error("Config backup manifest has no valid version");
+  }
+  if (manifest.version > CONFIG_EXPORT_VERSION) {
+    throw new Error(
+      "This backup was made by a newer version of the app — update to import it",
+    );
+  }
+
+  const settings = read("settings");
+  if (!isPlainObject(settings)) {
+    throw new Error(`${CONFIG_EXPORT_FILES.settings} is not an object`);
+  }
+  const keptSettings: Record<string, unknown> = { ...settings };
+  for (const key of CONFIG_EXPORT_EXCLUDED_SETTINGS) delete keptSettings[key];
+
+  const accounts = read("accounts");
+  if (!Array.isArray(accounts)) {
+    throw new Error(`${CONFIG_EXPORT_FILES.accounts} is not a list`);
+  }
+  const checkedAccounts = accounts.map((raw, i): ConfigExportAccount => {
+    const entry = (raw ?? {}) as Record<string, unknown>;
+    if (
+      typeof entry.uuid !== "string" ||
+      !/^[0-9a-f]{32}$/i.test(entry.uuid.replace(/-/g, "")) ||
+      typeof entry.username !== "string" ||
+      !entry.username
+    ) {
+      throw new Error(`${CONFIG_EXPORT_FILES.accounts}[${i}] is malformed`);
+    }
+    return {
+      uuid: entry.uuid,
+      username: entry.username,
+      accountType: entry.accountType === "legacy" ? "legacy" : "msa",
+    };
+  });
+
+  const instances = read("instances");
+  if (!Array.isArray(instances)) {
+    throw new Error(`${CONFIG_EXPORT_FILES.instances} is not a list`);
+  }
+
+  const jvmPresets = read("jvmPresets");
+  if (
+    !isPlainObject(jvmPresets) ||
+    !Object.values(jvmPresets).every(isStringArray)
+  ) {
+    throw new Error(
+      `${CONFIG_EXPORT_FILES.jvmPresets} must map names to argument lists`,
+    );
+  }
+
+  return {
+    manifest: manifest as unknown as ConfigExportManifest,
+    settings: keptSettings as Partial<AppSettings>,
+    accounts: checkedAccounts,
+    instances: instances.map(checkBackupInstance),
+    jvmPresets: jvmPresets as Record<string, string[]>,
+  };
+}
+
+async function request<T>(
+  pathname: string,
+  method = "GET",
+  body?: unknown,
+): Promise<T> {
+  const res = await fetch(backendUrl(pathname), {
+    method,
+    headers: body ? { "Content-Type": "application/json" } : undefined,
+    body: body ? JSON.stringify(body) : undefined,
+  });
+  if (!res.ok) {
+    throw new Error(`${method} ${pathname} failed: ${await res.text()}`);
+  }
+  return (await res.json()) as T;
+}
+
+function exportableSettings(settings: AppSettings): Partial<AppSettings> {
+  const kept: Partial<AppSettings> = { ...settings };
+  for (const key of CONFIG_EXPORT_EXCLUDED_SETTINGS) delete kept[key];
+  return kept;
+}
+
+/** Write a config backup to `destPath` (a .zip). */
+export async function exportConfig(destPath: string): Promise<void> {
+  const [settings, accounts, instances] = await Promise.all([
+    request<AppSettings>("/api/system/settings"),
+    request<LauncherAccount[]>("/api/launcher/accounts"),
+    request<LauncherInstance[]>("/api/launcher/instances"),
+  ]);
+
+  const backup: ConfigExport = {
+    manifest: {
+      format: CONFIG_EXPORT_FORMAT,
+      version: CONFIG_EXPORT_VERSION,
+      exportedAt: new Date().toISOString(),
+      appVersion: app.getVersion(),
+    },
+    settings: exportableSettings(settings),
+    accounts: accounts.map(({ uuid, username, accountType }) => ({
+      uuid,
+      username,
+      accountType,
+    })),
+    instances,
+    jvmPresets: getUserJvmPresets(),
+  };
+
+  const zip = new AdmZip();
+  for (const [field, name] of Object.entries(CONFIG_EXPORT_FILES)) {
+    const data = backup[field as keyof ConfigExport];
+    zip.addFile(name, Buffer.from(JSON.stringify(data, null, 2), "utf-8"));
+  }
+  await zip.writeZipPromise(destPath);
+}
+
+/** Read and validate a backup without touching any app state. */
+function readBackup(srcPath: string): ConfigExport {
+  let zip: AdmZip;
+  try {
+    zip = new AdmZip(srcPath);
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new Error(`Could not open ${srcPath}: ${reason}`);
+  }
+
+  const files: Record<string, unknown> = {};
+  for (const name of Object.values(CONFIG_EXPORT_FILES)) {
+    const entry = zip.getEntry(name);
+    if (!entry) continue;
+    try {
+      files[name] = JSON.parse(entry.getData().toString("utf-8"));
+    } catch {
+      throw new Error(`Config backup is damaged: ${name} isn't valid JSON`);
+    }
+  }
+  return parseConfigExport(files);
+}
+
+function normalizeUuid(uuid: string): string {
+  return uuid.replace(/-/g, "").toLowerCase();
+}
+
+/** Same id (restoring on the same machine), else same name. */
+function findExistingInstance(
+  existing: LauncherInstance[],
+  instance: LauncherInstance,
+): LauncherInstance | undefined {
+  return (
+    existing.find((e) => e.id === instance.id) ??
+    existing.find(
+      (e) => e.name.toLowerCase() === instance.name.toLowerCase(),
+    )
+  );
+}
+
+/**
+ * The per-instance settings restored onto a new or matched instance. A
+ * custom Java path that doesn't exist here falls back to auto-detection.
+ */
+function instanceUpdate(instance: LauncherInstance): UpdateInstanceRequest {
+  return {
+    name: instance.name,
+    ramMin: instance.ramMin,
+    ramMax: instance.ramMax,
+    resolutionWidth: instance.resolutionWidth,
+    resolutionHeight: instance.resolutionHeight,
+    jvmArgs: instance.jvmArgs,
+    gameArgs: instance.gameArgs,
+    javaAgents: instance.javaAgents,
+    pathPrepend: instance.pathPrepend,
+    icon: instance.icon,
+    javaPath:
+      instance.javaPath && existsSync(instance.javaPath)
+        ? instance.javaPath
+        : null,
+    restartOnCrash: instance.restartOnCrash,
+    gpuPreference: instance.gpuPreference,
+    isolation: instance.isolation,
+  };
+}
+
+/** What importing `backup` would replace, for the no-overwrite check. */
+function findConflicts(
+  backup: ConfigExport,
+  settings: AppSettings,
+  instances: LauncherInstance[],
+  presets: Record<string, string[]>,
+): string[] {
+  const conflicts: string[] = [];
+
+  const changed = Object.entries(backup.settings).filter(
+    ([key, value]) =>
+      JSON.stringify(settings[key as keyof AppSettings]) !==
+      JSON.stringify(value),
+  );
+  if (changed.length > 0) {
+    conflicts.push(`settings (${changed.map(([key]) => key).join(", ")})`);
+  }
+
+  for (const instance of backup.instances) {
+    const match = findExistingInstance(instances, instance);
+    if (match) conflicts.push(`instance "${match.name}"`);
+  }
+
+  for (const [name, args] of Object.entries(backup.jvmPresets)) {
+    const current = presets[name];
+    if (current && JSON.stringify(current) !== JSON.stringify(args)) {
+      conflicts.push(`JVM preset "${name}"`);
+    }
+  }
+  return conflicts;
+}
+
+/**
+ * Restore a config backup from `srcPath`. The archive is validated first,
+ * and when it would replace existing settings, instances or presets the
+ * import is refused unless `overwrite` is set; nothing is applied until
+ * both checks pass.
+ */
+export async function importConfig(
+  srcPath: string,
+  overwrite = false,
+): Promise<ConfigImportResult> {
+  const backup = readBackup(srcPath);
+  // Presets are saved last; catch bad ones before anything is written
+  for (const [name, args] of Object.entries(backup.jvmPresets)) {
+    checkJvmPreset(name, args);
+  }
+
+  const [settings, accounts, instances] = await Promise.all([
+    request<AppSettings>("/api/system/settings"),
+    request<LauncherAccount[]>("/api/launcher/accounts"),
+    request<LauncherInstance[]>("/api/launcher/instances"),
+  ]);
+  const presets = getUserJvmPresets();
+
+  const conflicts = findConflicts(backup, settings, instances, presets);
+  if (conflicts.length > 0 && !overwrite) {
+    throw new Error(
+      `Importing would overwrite ${conflicts.join(", ")}. ` +
+        "Import again with overwrite enabled to replace them.",
+    );
+  }
+
+  const result: ConfigImportResult = {
+    settingsApplied: false,
+    accountsImported: [],
+    accountsExisting: [],
+    instancesCreated: [],
+    instancesUpdated: [],
+    jvmPresets: [],
+    needsReauth: [],
+  };
+
+  if (Object.keys(backup.settings).length > 0) {
+    await request("/api/system/settings", "PATCH", backup.settings);
+    result.settingsApplied = true;
+  }
+
+  const knownAccounts = new Map(
+    accounts.map((a) => [normalizeUuid(a.uuid), a]),
+  );
+  for (const entry of backup.accounts) {
+    let account = knownAccounts.get(normalizeUuid(entry.uuid));
+    if (account) {
+      result.accountsExisting.push(account);
+    } else {
+      account = await request<LauncherAccount>(
+        "/api/launcher/accounts",
+        "POST",
+        { ...entry, uuid: normalizeUuid(entry.uuid) },
+      );
+      result.accountsImported.push(account);
+    }
+    if (getAccountTokenStatus(account.uuid) === "signed_out") {
+      result.needsReauth.push(account);
+    }
+  }
+
+  for (const instance of backup.instances) {
+    const match = findExistingInstance(instances, instance);
+    if (match) {
+      result.instancesUpdated.push(
+        await request<LauncherInstance>(
+          `/api/launcher/instances/${match.id}`,
+          "PATCH",
+          instanceUpdate(instance),
+        ),
+      );
+      continue;
+    }
+    const created = await request<LauncherInstance>(
+      "/api/launcher/instances",
+      "POST",
+      {
+        name: instance.name,
+        mcVersion: instance.mcVersion,
+        versionType: instance.versionType,
+        loader: instance.loader ?? undefined,
+        loaderVersion: instance.loaderVersion ?? undefined,
+        ramMin: instance.ramMin,
+        ramMax: instance.ramMax,
+      },
+    );
+    result.instancesCreated.push(
+      await request<LauncherInstance>(
+        `/api/launcher/instances/${created.id}`,
+        "PATCH",
+        instanceUpdate(instance),
+      ),
+    );
+  }
+
+  for (const [name, args] of Object.entries(backup.jvmPresets)) {
+    const preset = await saveJvmPreset(name, args);
+    result.jvmPresets.push(preset.name);
+  }
+
+  return result;
+}
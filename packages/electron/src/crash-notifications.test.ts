@@ -0,0 +1,23 @@
+import { describeCrash } from "./crash-notifications.js";
+
+vi.mock("electron", () => ({}));
+
+describe("describeCrash", () => {
+  it("names a known cause", () => {
+    expect(describeCrash("Survival", "out_of_memory", 1, null)).toBe(
+      "Survival crashed: out of memory",
+    );
+  });
+
+  it("falls back to the signal, then the exit code", () => {
+    expect(describeCrash("Survival", "unknown", null, "SIGSEGV")).toBe(
+      "Survival crashed (signal SIGSEGV)",
+    );
+    expect(describeCrash("Survival", "unknown", 137, null)).toBe(
+      "Survival crashed (exit code 137)",
+    );
+    expect(describeCrash("Survival", "unknown", null, null)).toBe(
+      "Survival crashed",
+    );
+  });
+});
@@ -0,0 +1,152 @@
+/**
+ * System notifications when a game or a managed server crashes, gated by the
+ * `crashNotifications` app setting. Only crashes get here — clean exits and
+ * user-initiated stops never reach these functions. Clicking a notification
+ * opens the matching crash report.
+ * @module crash-notifications
+ */
+
+import { shell } from "electron";
+import { existsSync } from "node:fs";
+import path from "node:path";
+import type {
+  Server,
+  ServerCrashCause,
+  WsServerExit,
+} from "@mc-server-manager/shared";
+import { backendUrl } from "./backend-port.js";
+import { exportCrashReport, newestCrashReport } from "./crash-report.js";
+import { openLogFile } from "./logs.js";
+import { notify } from "./notifications.js";
+
+const OUT_OF_MEMORY =
+  /java\.lang\.OutOfMemoryError|Could not reserve enough space for .*object heap/i;
+
+type ServerCrashInfo = Pick<
+  WsServerExit,
+  "exitCode" | "signal" | "cause" | "restartScheduled"
+>;
+
+/** The part of the backend's ServerManager this module subscribes to. */
+interface ServerExitSource {
+  onExit(listener: (serverId: string, info: ServerCrashInfo) => void): void;
+}
+
+let watchedSource: ServerExitSource | null = null;
+
+const CRASH_CAUSE_LABELS: Record<ServerCrashCause, string | null> = {
+  out_of_memory: "out of memory",
+  port_in_use: "port already in use",
+  corrupt_world: "corrupt world",
+  unknown: null,
+};
+
+/**
+ * One-line crash summary for notifications, e.g. "Survival crashed: out of
+ * memory". Falls back to the exit code or signal when the cause is unknown.
+ */
+export function describeCrash(
+  name: string,
+  cause: ServerCrashCause,
+  exitCode: number | null,
+  signal: string | null,
+): string {
+  const label = CRASH_CAUSE_LABELS[cause];
+  if (label) return `${name} crashed: ${label}`;
+  if (signal) return `${name} crashed (signal ${signal})`;
+  if (exitCode !== null) return `${name} crashed (exit code ${exitCode})`;
+  return `${name} crashed`;
+}
+
+/** Client output is only checked for OOM; other game crashes vary too much. */
+export function classifyGameCrash(outputTail: string[]): ServerCrashCause {
+  return outputTail.some((line) => OUT_OF_MEMORY.test(line))
+    ? "out_of_memory"
+    : "unknown";
+}
+
+function clickHint(restartScheduled: boolean): string {
+  return restartScheduled
+    ? "Restarting automatically. Click to open the crash report."
+    : "Click to open the crash report.";
+}
+
+async function openGameCrashReport(instanceId: string): Promise<void> {
+  try {
+    await openLogFile(await exportCrashReport(instanceId));
+  } catch (err) {
+    console.error(`Failed to open crash report for ${instanceId}:`, err);
+  }
+}
+
+/** Notify that a game crashed. Never throws. */
+export function notifyGameCrash(
+  instanceId: string,
+  instanceName: string,
+  outputTail: string[],
+  exitCode: number | null,
+  signal: string | null,
+  restartScheduled: boolean,
+): void {
+  const cause = classifyGameCrash(outputTail);
+  void notify(
+    describeCrash(instanceName, cause, exitCode, signal),
+    clickHint(restartScheduled),
+    {
+      setting: "crashNotifications",
+      onClick: () => void openGameCrashReport(instanceId),
+    },
+  );
+}
+
+async function getServer(serverId: string): Promise<Server | null> {
+  try {
+    const res = await fetch(backendUrl(`/api/servers/${serverId}`));
+    return res.ok ? ((await res.json()) as Server) : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Servers write crash-reports/ only for in-game crashes; startup failures
+ * and OOM kills just end latest.log, so fall back to that.
+ */
+async function openServerCrashReport(directory: string): Promise<void> {
+  const target =
+    newestCrashReport(directory) ??
+    path.join(directory, "logs", "latest.log");
+  if (!existsSync(target)) return;
+  const error = await shell.openPath(target);
+  if (error) console.error("Failed to open server crash report:", error);
+}
+
+async function notifyServerCrash(
+  serverId: string,
+  info: ServerCrashInfo,
+): Promise<void> {
+  const server = await getServer(serverId);
+  const name = server?.name ?? serverId;
+  await notify(
+    describeCrash(name, info.cause, info.exitCode, info.signal),
+    server ? clickHint(info.restartScheduled) : "",
+    {
+      setting: "crashNotifications",
+      onClick: server
+        ? () => void openServerCrashReport(server.directory)
+        : undefined,
+    },
+  );
+}
+
+/**
+ * Notify on server crashes. The ServerManager is a singleton that outlives
+ * backend restarts, so subscribing again is a no-op.
+ */
+export function watchServerCrashes(source: ServerExitSource): void {
+  if (watchedSource === source) return;
+  watchedSource = source;
+  source.onExit((serverId, info) => {
+    void notifyServerCrash(serverId, info);
+  });
+}
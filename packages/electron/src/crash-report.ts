@@ -0,0 +1,156 @@
+/**
+ * Support bundle for "the game crashed" bug reports: a single text file with
+ * the client's last output, the redacted launch command, Java/system info
+ * and app versions. Written under the logs dir so `openLogFile` can show it.
+ * @module crash-report
+ */
+
+import { execFile } from "node:child_process";
+import {
+  existsSync,
+  mkdirSync,
+  readdirSync,
+  readFileSync,
+  statSync,
+  writeFileSync,
+} from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { promisify } from "node:util";
+import { getAppInfo } from "./app-info.js";
+import { checkBackendHealth } from "./backend.js";
+import { getLastLaunch } from "./launcher.js";
+import { getLogsDir } from "./logs.js";
+import { isJavaTranslated, isRunningUnderRosetta } from "./platform.js";
+
+const execFileAsync = promisify(execFile);
+
+/** Lines taken from the end of latest.log / the newest crash report. */
+const LOG_TAIL_LINES = 300;
+
+/** Minecraft/Xbox tokens are JWTs; catch any that end up in logs. */
+const JWT_PATTERN = /eyJ[\w-]+\.[\w-]+\.[\w-]+/g;
+
+/** Strip tokens and the user's home directory (which contains their OS username). */
+function redact(text: string): string {
+  const home = os.homedir();
+  let result = text.replace(JWT_PATTERN, "[REDACTED]");
+  if (home) {
+    result = result.split(home).join("~");
+  }
+  return result;
+}
+
+function tailFile(filePath: string, lines: number): string | null {
+  try {
+    const content = readFileSync(filePath, "utf-8");
+    return content.split(/\r?\n/).slice(-lines).join("\n");
+  } catch {
+    return null;
+  }
+}
+
+/** Newest Minecraft crash report under `dir/crash-reports`, if any. */
+export function newestCrashReport(instanceDir: string): string | null {
+  const dir = path.join(instanceDir, "crash-reports");
+  if (!existsSync(dir)) return null;
+  try {
+    const newest = readdirSync(dir)
+      .filter((name) => name.endsWith(".txt"))
+      .map((name) => path.join(dir, name))
+      .sort((a, b) => statSync(b).mtimeMs - statSync(a).mtimeMs)[0];
+    return newest ?? null;
+  } catch {
+    return null;
+  }
+}
+
+async function javaVersionLine(javaPath: string): Promise<string> {
+  try {
+    const { stderr } = await execFileAsync(javaPath, ["-version"], {
+      timeout: 10_000,
+    });
+    return stderr.split(/\r?\n/)[0]?.trim() || "unknown";
+  } catch (err) {
+    return `unknown (${err instanceof Error ? err.message : String(err)})`;
+  }
+}
+
+function section(title: string, body: string): string {
+  return `===== ${title} =====\n${body.trimEnd()}\n`;
+}
+
+/**
+ * Write a crash report for the instance's most recent launch and return its
+ * absolute path. Only launches from the current app session are known.
+ */
+export async function exportCrashReport(instanceId: string): Promise<string> {
+  const launch = getLastLaunch(instanceId);
+  if (!launch) {
+    throw new Error(
+      "This instance hasn't been launched since the app started — launch it again to capture a crash report",
+    );
+  }
+
+  const appInfo = getAppInfo();
+  const health = await checkBackendHealth();
+
+  const signal = launch.signal ? ` (signal ${launch.signal})` : "";
+  const summary = [
+    `Instance: ${instanceId}`,
+    `Started: ${launch.process.startedAt}`,
+    `Exited: ${launch.exitedAt ?? "still running"}`,
+    `Exit code: ${launch.exitCode ?? "n/a"}${signal}`,
+    `Reached in-game: ${launch.process.ready ? "yes" : "no"}`,
+  ].join("\n");
+
+  const versions = [
+    `App: ${appInfo.version}${appInfo.commit ? ` (${appInfo.commit})` : ""}`,
+    `Backend: ${health.version ?? "unknown"}`,
+    `Electron: ${appInfo.electronVersion}`,
+    `Node: ${appInfo.nodeVersion}`,
+    `Packaged: ${appInfo.packaged}`,
+  ].join("\n");
+
+  const totalMb = Math.round(os.totalmem() / 1024 / 1024);
+  const freeMb = Math.round(os.freemem() / 1024 / 1024);
+  const system = [
+    `OS: ${os.type()} ${os.release()} (${appInfo.target})`,
+    `CPUs: ${os.cpus().length}`,
+    `Memory: ${totalMb} MB total, ${freeMb} MB free`,
+    `Java: ${await javaVersionLine(launch.javaPath)}`,
+    `App under Rosetta: ${(await isRunningUnderRosetta()) ? "yes" : "no"}`,
+    `Java under Rosetta: ${(await isJavaTranslated(launch.javaPath)) ? "yes" : "no"}`,
+  ].join("\n");
+
+  const sections = [
+    section("Summary", summary),
+    section("Versions", versions),
+    section("System", system),
+    section("Launch command", launch.process.command.join(" ")),
+    section("Client output (tail)", launch.outputTail.join("\n") || "(none)"),
+  ];
+
+  const latestLog = tailFile(
+    path.join(launch.instanceDir, "logs", "latest.log"),
+    LOG_TAIL_LINES,
+  );
+  if (latestLog) {
+    sections.push(section("logs/latest.log (tail)", latestLog));
+  }
+
+  const crashFile = newestCrashReport(launch.instanceDir);
+  const crashContent = crashFile && tailFile(crashFile, LOG_TAIL_LINES);
+  if (crashFile && crashContent) {
+    sections.push(
+      section(`crash-reports/${path.basename(crashFile)}`, crashContent),
+    );
+  }
+
+  const outDir = path.join(getLogsDir(), "crash-reports");
+  mkdirSync(outDir, { recursive: true });
+  const stamp = new Date().toISOString().replace(/[:.]/g, "-");
+  const outPath = path.join(outDir, `crash-${instanceId}-${stamp}.txt`);
+  writeFileSync(outPath, redact(sections.join("\n")), "utf-8");
+  return outPath;
+}
@@ -0,0 +1,168 @@
+/**
+ * Data directory checks. On locked-down machines (read-only volume, bad
+ * permissions) the backend can't create its database, so detect that up
+ * front and offer a temporary fallback instead of failing mid-startup.
+ *
+ * Portable mode (`--portable` or `MC_PORTABLE=1`) keeps everything in a
+ * `data/` folder beside the executable, e.g. for a USB stick install.
+ * Saved credentials are still encrypted with the OS keychain, so accounts
+ * need a fresh sign-in when the stick moves to another machine.
+ * @module data-dir
+ */
+
+import { app, dialog } from "electron";
+import {
+  accessSync,
+  constants as fsConstants,
+  mkdirSync,
+  rmSync,
+  writeFileSync,
+} from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { logStartup } from "./startup-log.js";
+
+/**
+ * The one data directory the desktop side uses: MC_DATA_DIR once main has
+ * set it (after portable mode and the unwritable fallback), else userData.
+ * The in-process backend reads the same variable.
+ */
+export function getDataDir(): string {
+  return process.env.MC_DATA_DIR ?? app.getPath("userData");
+}
+
+/**
+ * Warn if the backend resolved a different data directory than ours, e.g.
+ * because DATA_DIR is set (the backend prefers it over MC_DATA_DIR). The
+ * two would then work on different databases and instance folders, and
+ * instances would seem to disappear. Returns whether they agree.
+ */
+export function checkBackendDataDir(backendDataDir: string): boolean {
+  const dataDir = getDataDir();
+  if (path.resolve(backendDataDir) === path.resolve(dataDir)) return true;
+  logStartup("error", "data-dir-mismatch", {
+    desktop: dataDir,
+    backend: backendDataDir,
+    hint: "Unset DATA_DIR so the backend uses the desktop data directory",
+  });
+  return false;
+}
+
+/** Returns null if `dir` can be created and written to, else the reason. */
+function checkWritable(dir: string): string | null {
+  try {
+    mkdirSync(dir, { recursive: true });
+    accessSync(dir, fsConstants.W_OK);
+    // access() can lie on network/ACL filesystems; a real write can't
+    const probe = path.join(dir, `.write-test-${process.pid}`);
+    writeFileSync(probe, "");
+    rmSync(probe, { force: true });
+    return null;
+  } catch (err) {
+    return err instanceof Error ? err.message : String(err);
+  }
+}
+
+/** Why portable mode was requested but couldn't be used, if it was. */
+let portableFailure: { dataDir: string; reason: string } | null = null;
+
+export function isPortableRequested(): boolean {
+  return (
+    app.commandLine.hasSwitch("portable") || process.env.MC_PORTABLE === "1"
+  );
+}
+
+/**
+ * The portable data directory. electron-builder's portable Windows target
+ * runs from a temp extraction dir and reports the real location in
+ * PORTABLE_EXECUTABLE_DIR; on macOS the data sits beside the .app bundle.
+ */
+export function portableDataDir(): string {
+  let baseDir = process.env.PORTABLE_EXECUTABLE_DIR;
+  if (!baseDir) {
+    const exe = app.getPath("exe");
+    const bundle =
+      process.platform === "darwin"
+        ? exe.match(/^(.*?\.app)[\\/]/)?.[1]
+        : undefined;
+    baseDir = path.dirname(bundle ?? exe);
+  }
+  return path.join(baseDir, "data");
+}
+
+/**
+ * Switch userData to the portable data directory when portable mode is
+ * requested. Must run before anything reads `app.getPath("userData")`. An
+ * unwritable portable dir leaves the normal location in place; the reason
+ * is shown by ensureWritableDataDir.
+ */
+export function applyPortableMode(): void {
+  if (!isPortableRequested()) return;
+
+  const dataDir = portableDataDir();
+  const reason = checkWritable(dataDir);
+  if (reason) {
+    portableFailure = { dataDir, reason };
+    logStartup("error", "portable-dir-unwritable", { dataDir, error: reason });
+    return;
+  }
+  app.setPath("userData", dataDir);
+  logStartup("info", "portable-mode", { dataDir });
+}
+
+/**
+ * Make sure MC_DATA_DIR is writable. If it isn't, tell the user and either
+ * switch to a temp dir (degraded mode — data won't persist reliably) or
+ * quit. Requires `app.whenReady()`. Returns false if the app should quit.
+ */
+export function ensureWritableDataDir(): boolean {
+  if (portableFailure) {
+    dialog.showMessageBoxSync({
+      type: "warning",
+      title: "Portable mode unavailable",
+      message: "MC Server Manager can't write to its portable data folder.",
+      detail:
+        `${portableFailure.dataDir}\n\n${portableFailure.reason}\n\n` +
+        "Your data will be stored in the usual location instead:\n" +
+        app.getPath("userData"),
+    });
+  }
+
+  const dataDir = getDataDir();
+  const reason = checkWritable(dataDir);
+  if (!reason) return true;
+
+  const fallback = path.join(os.tmpdir(), "mc-server-manager");
+  logStartup("error", "data-dir-unwritable", {
+    dataDir,
+    error: reason,
+    fallback,
+  });
+
+  const choice = dialog.showMessageBoxSync({
+    type: "error",
+    title: "Can't write to data directory",
+    message: "MC Server Manager can't write to its data directory.",
+    detail:
+      `${dataDir}\n\n${reason}\n\n` +
+      "You can continue using a temporary directory, but servers, " +
+      "settings and accounts may be lost when it's cleaned up.",
+    buttons: ["Use temporary directory", "Quit"],
+    defaultId: 1,
+    cancelId: 1,
+  });
+  if (choice !== 0) return false;
+
+  const fallbackReason = checkWritable(fallback);
+  if (fallbackReason) {
+    dialog.showErrorBox(
+      "Can't write to data directory",
+      `The temporary directory isn't writable either:\n${fallback}\n\n${fallbackReason}`,
+    );
+    return false;
+  }
+
+  process.env.MC_DATA_DIR = fallback;
+  logStartup("info", "data-dir-fallback", { dataDir: fallback });
+  return true;
+}
@@ -0,0 +1,86 @@
+/**
+ * Disk usage of instance directories, for storage management. Walking a big
+ * modpack means statting tens of thousands of files, so each directory's
+ * file total is cached against its mtime and only re-read once files are
+ * added, removed or renamed in it.
+ * @module disk-usage
+ */
+
+import { existsSync } from "node:fs";
+import { lstat, readdir } from "node:fs/promises";
+import path from "node:path";
+import { getInstancePaths } from "./launcher.js";
+
+interface DirUsage {
+  mtimeMs: number;
+  /** Bytes in the directory's own files, not its subdirectories */
+  fileBytes: number;
+  subdirs: string[];
+}
+
+const dirCache = new Map<string, DirUsage>();
+
+async function readDirUsage(dir: string, mtimeMs: number): Promise<DirUsage> {
+  const entries = await readdir(dir, { withFileTypes: true });
+  const usage: DirUsage = { mtimeMs, fileBytes: 0, subdirs: [] };
+  await Promise.all(
+    entries.map(async (entry) => {
+      const full = path.join(dir, entry.name);
+      // Symlinks aren't followed: no loops, and no counting space twice
+      if (entry.isDirectory()) {
+        usage.subdirs.push(full);
+      } else if (entry.isFile()) {
+        try {
+          usage.fileBytes += (await lstat(full)).size;
+        } catch {
+          // Deleted mid-walk
+        }
+      }
+    }),
+  );
+  return usage;
+}
+
+async function sizeOfDir(dir: string, refresh: boolean): Promise<number> {
+  let mtimeMs: number;
+  try {
+    mtimeMs = (await lstat(dir)).mtimeMs;
+  } catch {
+    dirCache.delete(dir);
+    return 0;
+  }
+
+  let usage = dirCache.get(dir);
+  if (refresh || !usage || usage.mtimeMs !== mtimeMs) {
+    try {
+      usage = await readDirUsage(dir, mtimeMs);
+    } catch {
+      dirCache.delete(dir);
+      return 0;
+    }
+    dirCache.set(dir, usage);
+  }
+
+  const subdirBytes = await Promise.all(
+    usage.subdirs.map((subdir) => sizeOfDir(subdir, refresh)),
+  );
+  return subdirBytes.reduce((sum, bytes) => sum + bytes, usage.fileBytes);
+}
+
+/**
+ * Total bytes under an instance's directory, worlds included. A file that
+ * grows in place (a world's region files while playing) doesn't touch its
+ * directory's mtime, so pass `refresh` to re-stat everything.
+ */
+export async function getInstanceSize(
+  instanceId: string,
+  refresh = false,
+): Promise<number> {
+  const { gameDir } = await getInstancePaths(instanceId);
+  if (!existsSync(gameDir)) {
+    throw new Error(
+      `Instance directory ${gameDir} doesn't exist (never launched?)`,
+    );
+  }
+  return sizeOfDir(gameDir, refresh);
+}
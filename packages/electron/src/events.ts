@@ -0,0 +1,32 @@
+/**
+ * Main → renderer push events.
+ * New channels must also be added to the allowlist in preload.cts.
+ * @module events
+ */
+
+import { BrowserWindow } from "electron";
+
+export type RendererEventChannel =
+  | "backend-restarting"
+  | "backend-ready"
+  | "backend-down"
+  | "launch-progress"
+  | "launch-failed"
+  | "launch-stalled"
+  | "game-ready"
+  | "game-force-killed"
+  | "game-crashed"
+  /** Sent by log-windows.ts to the matching log window only */
+  | "game-log"
+  | "account-refresh-failed";
+
+export function emitToRenderers(
+  channel: RendererEventChannel,
+  payload?: unknown,
+): void {
+  for (const win of BrowserWindow.getAllWindows()) {
+    if (!win.isDestroyed()) {
+      win.webContents.send(channel, payload);
+    }
+  }
+}
@@ -0,0 +1,99 @@
+/**
+ * Per-instance GPU selection on hybrid-graphics machines. Each platform has
+ * its own mechanism, and none of them is a guarantee:
+ *
+ * - Windows: the per-executable "Graphics settings" preference is written
+ *   to the registry for the instance's Java binary, so it also applies to
+ *   anything else run with that same java.exe. NVIDIA Optimus additionally
+ *   honours the SHIM_MCCOMPAT environment hint. Changing the preference in
+ *   Windows Settings by hand overrides it until the next launch.
+ * - Linux: Mesa PRIME offload (`DRI_PRIME`) covers AMD/Intel dGPUs; NVIDIA's
+ *   proprietary driver uses its own render-offload variables, which are only
+ *   set when that driver is loaded (they break OpenGL otherwise). Needs an
+ *   offload-capable setup — a GPU muxed off in firmware can't be picked.
+ * - macOS: the OS switches GPUs itself and apps can't ask for one, so the
+ *   preference is ignored.
+ * @module gpu
+ */
+
+import { execFile } from "node:child_process";
+import { existsSync } from "node:fs";
+import path from "node:path";
+import { promisify } from "node:util";
+import type { GpuPreference } from "@mc-server-manager/shared";
+
+const execFileAsync = promisify(execFile);
+
+const WINDOWS_GPU_PREFS_KEY =
+  "HKCU\\Software\\Microsoft\\DirectX\\UserGpuPreferences";
+
+/** Windows GpuPreference values: 1 = power saving, 2 = high performance. */
+const WINDOWS_GPU_PREFERENCE: Record<GpuPreference, number> = {
+  integrated: 1,
+  discrete: 2,
+};
+
+function hasNvidiaDriver(): boolean {
+  return existsSync("/proc/driver/nvidia/version");
+}
+
+async function setWindowsGpuPreference(
+  javaPath: string,
+  preference: GpuPreference,
+): Promise<void> {
+  // The registry entry is keyed on a full path; a bare "java" can't be set
+  if (!path.isAbsolute(javaPath)) return;
+  try {
+    await execFileAsync(
+      "reg",
+      [
+        "add",
+        WINDOWS_GPU_PREFS_KEY,
+        "/v",
+        javaPath,
+        "/t",
+        "REG_SZ",
+        "/d",
+        `GpuPreference=${WINDOWS_GPU_PREFERENCE[preference]};`,
+        "/f",
+      ],
+      { timeout: 5_000, windowsHide: true },
+    );
+  } catch (err) {
+    // Env hint below still applies; don't fail the launch over this
+    console.warn("Failed to set Windows GPU preference:", err);
+  }
+}
+
+/**
+ * Apply a GPU preference for a game launched with `javaPath`. Returns the
+ * environment variables to add to the game process; on Windows this also
+ * updates the registry preference for that Java binary.
+ */
+export async function applyGpuPreference(
+  preference: GpuPreference | null,
+  javaPath: string,
+): Promise<Record<string, string>> {
+  if (!preference) return {};
+
+  switch (process.platform) {
+    case "win32":
+      await setWindowsGpuPreference(javaPath, preference);
+      return {
+        SHIM_MCCOMPAT:
+          preference === "discrete" ? "0x800000001" : "0x800000000",
+      };
+    case "linux":
+      if (preference === "integrated") return { DRI_PRIME: "0" };
+      if (hasNvidiaDriver()) {
+        return {
+          __NV_PRIME_RENDER_OFFLOAD: "1",
+          __GLX_VENDOR_LIBRARY_NAME: "nvidia",
+          __VK_LAYER_NV_optimus: "NVIDIA_only",
+        };
+      }
+      return { DRI_PRIME: "1" };
+    default:
+      return {};
+  }
+}
@@ -0,0 +1,159 @@
+/**
+ * Read-only integrity check of the files a launch depends on: the game jar,
+ * classpath libraries, the asset index and the prepared natives. Nothing is
+ * re-downloaded here; a failed item tells the user (or a fresh prepare)
+ * exactly what to fix.
+ * @module instance-verify
+ */
+
+import { cp, open, readFile, rm, stat } from "node:fs/promises";
+import path from "node:path";
+import type {
+  PrepareResponse,
+  VerifyItem,
+  VerifyReport,
+} from "@mc-server-manager/shared";
+import { getLastPrepareResult, resolveNativesBase } from "./launcher.js";
+
+/** Every jar is a zip, which starts with a local file header. */
+const ZIP_MAGIC = Buffer.from([0x50, 0x4b, 0x03, 0x04]);
+
+async function checkJar(
+  kind: VerifyItem["kind"],
+  filePath: string,
+): Promise<VerifyItem> {
+  try {
+    const info = await stat(filePath);
+    if (info.size === 0) return { kind, path: filePath, status: "empty" };
+
+    const handle = await open(filePath, "r");
+    try {
+      const header = Buffer.alloc(ZIP_MAGIC.length);
+      await handle.read(header, 0, header.length, 0);
+      if (!header.equals(ZIP_MAGIC)) {
+        return {
+          kind,
+          path: filePath,
+          status: "corrupt",
+          detail: "Not a valid jar (zip) file",
+        };
+      }
+    } finally {
+      await handle.close();
+    }
+    return { kind, path: filePath, status: "ok" };
+  } catch {
+    return { kind, path: filePath, status: "missing" };
+  }
+}
+
+async function checkAssetIndex(
+  prepareResult: PrepareResponse,
+): Promise<VerifyItem> {
+  const filePath = path.join(
+    prepareResult.assetsDir,
+    "indexes",
+    `${prepareResult.assetIndex}.json`,
+  );
+  const kind = "asset_index";
+  let raw: string;
+  try {
+    raw = await readFile(filePath, "utf-8");
+  } catch {
+    return { kind, path: filePath, status: "missing" };
+  }
+  if (raw.length === 0) return { kind, path: filePath, status: "empty" };
+
+  try {
+    const parsed = JSON.parse(raw) as { objects?: unknown };
+    if (!parsed.objects || typeof parsed.objects !== "object") {
+      return {
+        kind,
+        path: filePath,
+        status: "corrupt",
+        detail: "Index has no objects map",
+      };
+    }
+  } catch (err) {
+    return {
+      kind,
+      path: filePath,
+      status: "corrupt",
+      detail: err instanceof Error ? err.message : String(err),
+    };
+  }
+  return { kind, path: filePath, status: "ok" };
+}
+
+/** Copy the prepared natives the way a launch does, into a throwaway dir. */
+async function checkNatives(
+  instanceId: string,
+  prepareResult: PrepareResponse,
+): Promise<VerifyItem> {
+  const kind = "natives";
+  const source = prepareResult.nativesDir;
+  try {
+    if (!(await stat(source)).isDirectory()) {
+      return {
+        kind,
+        path: source,
+        status: "corrupt",
+        detail: "Not a directory",
+      };
+    }
+  } catch {
+    return { kind, path: source, status: "missing" };
+  }
+
+  const launcherBase = path.dirname(prepareResult.assetsDir);
+  const scratch = path.join(
+    resolveNativesBase(launcherBase),
+    `${instanceId}-verify-${Date.now()}`,
+  );
+  try {
+    await cp(source, scratch, { recursive: true });
+    return { kind, path: source, status: "ok" };
+  } catch (err) {
+    return {
+      kind,
+      path: source,
+      status: "corrupt",
+      detail: `Could not be extracted: ${
+        err instanceof Error ? err.message : String(err)
+      }`,
+    };
+  } finally {
+    await rm(scratch, { recursive: true, force: true }).catch(() => {});
+  }
+}
+
+/**
+ * Check an instance's launch files. Uses `prepareResult` when given (e.g.
+ * straight after a prepare), otherwise the one from its last launch.
+ */
+export async function verifyInstanceFiles(
+  instanceId: string,
+  prepareResult?: PrepareResponse,
+): Promise<VerifyReport> {
+  const prepared = prepareResult ?? getLastPrepareResult(instanceId);
+  if (!prepared) {
+    throw new Error(
+      "This instance hasn't been launched yet, so there are no files to verify",
+    );
+  }
+
+  const items: VerifyItem[] = [
+    await checkJar("game_jar", prepared.gameJarPath),
+  ];
+  for (const jar of prepared.classpath) {
+    items.push(await checkJar("library", jar));
+  }
+  items.push(await checkAssetIndex(prepared));
+  items.push(await checkNatives(instanceId, prepared));
+
+  return {
+    instanceId,
+    ok: items.every((item) => item.status === "ok"),
+    items,
+  };
+}
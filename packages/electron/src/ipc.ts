@@ -1,10 +1,33 @@
 import { ipcMain } from "electron";
 import type { PrepareResponse } from "@mc-server-manager/shared";
+import * as appInfo from "./app-info.js";
 import * as auth from "./auth.js";
+import * as avatars from "./avatars.js";
+import * as backend from "./backend.js";
+import * as cache from "./cache.js";
+import * as configTransfer from "./config-transfer.js";
+import * as crashReport from "./crash-report.js";
+import * as diskUsage from "./disk-usage.js";
+import { backendUrl, getBackendPort } from "./backend-port.js";
+import * as instanceVerify from "./instance-verify.js";
+import * as java from "./java.js";
+import * as javaDiagnosis from "./java-diagnosis.js";
+import * as jvmPresets from "./jvm-presets.js";
 import * as launcher from "./launcher.js";
+import * as logWindows from "./log-windows.js";
+import * as logs from "./logs.js";
+import * as platform from "./platform.js";
+import * as playerLookup from "./player-lookup.js";
+import * as query from "./query.js";
+import * as rcon from "./rcon.js";
+import * as secureStorage from "./secure-storage.js";
+import * as shutdown from "./shutdown.js";
+import * as vanillaImport from "./vanilla-import.js";
+import * as worlds from "./worlds.js";
 
 // Electron strips non-standard Error properties across the IPC boundary,
-// so we re-throw as plain Error with just the message string.
+// so we re-throw as plain Error with just the message string. LaunchErrors
+// keep their code and stage by encoding them into that message.
 function serializableHandler<T>(
   fn: (args: Record<string, unknown>) => T | Promise<T>,
 ): (
@@ -15,6 +38,9 @@ function serializableHandler<T>(
     try {
       return await fn(args);
     } catch (err: unknown) {
+      if (err instanceof launcher.LaunchError) {
+        throw new Error(launcher.encodeLaunchError(err.toInfo()));
+      }
       const message = err instanceof Error ? err.message : String(err);
       throw new Error(message);
     }
@@ -22,6 +48,58 @@ function serializableHandler<T>(
 }
 
 export function registerIpcHandlers(): void {
+  ipcMain.handle(
+    "get-app-info",
+    serializableHandler(() => appInfo.getAppInfo()),
+  );
+
+  ipcMain.handle(
+    "get-backend-port",
+    serializableHandler(() => getBackendPort()),
+  );
+
+  ipcMain.handle(
+    "restart-backend",
+    serializableHandler(() => backend.restartBackend()),
+  );
+
+  ipcMain.handle(
+    "reload-backend-config",
+    serializableHandler(() => backend.reloadBackendConfig()),
+  );
+
+  ipcMain.handle(
+    "get-backend-startup-log",
+    serializableHandler(() => logs.getBackendStartupLog()),
+  );
+
+  ipcMain.handle(
+    "stop-all-servers",
+    serializableHandler((args) =>
+      backend.stopAllServers(args.graceful !== false),
+    ),
+  );
+
+  ipcMain.handle(
+    "shutdown-app",
+    serializableHandler(() => shutdown.shutdownApp()),
+  );
+
+  ipcMain.handle(
+    "is-running-under-rosetta",
+    serializableHandler(() => platform.isRunningUnderRosetta()),
+  );
+
+  ipcMain.handle(
+    "is-elevated",
+    serializableHandler(() => platform.isElevated()),
+  );
+
+  ipcMain.handle(
+    "check-backend-health",
+    serializableHandler(() => backend.checkBackendHealth()),
+  );
+
   ipcMain.handle(
     "ms-auth-start",
     serializableHandler(() => auth.msAuthStart()),
@@ -32,6 +110,16 @@ export function registerIpcHandlers(): void {
     serializableHandler(() => auth.msAuthPoll()),
   );
 
+  ipcMain.handle(
+    "ms-auth-resume",
+    serializableHandler(() => auth.msAuthResume()),
+  );
+
+  ipcMain.handle(
+    "open-auth-url",
+    serializableHandler(() => auth.openAuthUrl()),
+  );
+
   ipcMain.handle(
     "ms-auth-cancel",
     serializableHandler(() => auth.msAuthCancel()),
@@ -42,14 +130,53 @@ export function registerIpcHandlers(): void {
     serializableHandler((args) => auth.msAuthRefresh(args.uuid as string)),
   );
 
+  ipcMain.handle(
+    "get-account-avatar",
+    serializableHandler((args) =>
+      avatars.getAccountAvatar(args.uuid as string, args.size as number),
+    ),
+  );
+
   ipcMain.handle(
     "get-mc-access-token",
     serializableHandler((args) => auth.getMcAccessToken(args.uuid as string)),
   );
 
+  ipcMain.handle(
+    "import-vanilla-accounts",
+    serializableHandler((args) =>
+      vanillaImport.importVanillaAccounts(args.path as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "migrate-account-keyring",
+    serializableHandler((args) =>
+      auth.migrateAccountKeyring(
+        args.oldUuid as string,
+        args.newUuid as string,
+        { verify: args.verify !== false },
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "check-credential-storage",
+    serializableHandler(() => secureStorage.checkCredentialStorage()),
+  );
+
   ipcMain.handle(
     "remove-account",
-    serializableHandler((args) => auth.removeAccount(args.uuid as string)),
+    serializableHandler((args) =>
+      auth.removeAccount(args.uuid as string, {
+        signOut: args.signOut === true,
+      }),
+    ),
+  );
+
+  ipcMain.handle(
+    "repair-account",
+    serializableHandler((args) => auth.repairAccount(args.uuid as string)),
   );
 
   ipcMain.handle(
@@ -57,8 +184,30 @@ export function registerIpcHandlers(): void {
     serializableHandler((args) =>
       launcher.launchGame(
         args.instanceId as string,
-        args.accountId as string,
+        (args.accountId as string | undefined) ?? null,
         args.prepareResult as PrepareResponse,
+        {
+          force: args.force === true,
+          prepareMs:
+            typeof args.prepareMs === "number" ? args.prepareMs : undefined,
+        },
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "test-java-launch",
+    serializableHandler((args) =>
+      launcher.testJavaLaunch(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "validate-instance",
+    serializableHandler((args) =>
+      launcher.validateInstance(
+        args.instanceId as string,
+        args.accountId as string | undefined,
       ),
     ),
   );
@@ -68,16 +217,306 @@ export function registerIpcHandlers(): void {
     serializableHandler(() => launcher.getRunningGames()),
   );
 
+  ipcMain.handle(
+    "get-last-launch-timings",
+    serializableHandler((args) =>
+      launcher.getLastLaunchTimings(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "open-log-window",
+    serializableHandler((args) =>
+      logWindows.openLogWindow(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "get-game-output",
+    serializableHandler((args) =>
+      logWindows.getGameOutput(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "get-running-games-detailed",
+    serializableHandler(() => launcher.getRunningGamesDetailed()),
+  );
+
+  ipcMain.handle(
+    "get-instance-paths",
+    serializableHandler((args) =>
+      launcher.getInstancePaths(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "get-effective-classpath",
+    serializableHandler((args) =>
+      launcher.getEffectiveClasspath(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "preview-game-args",
+    serializableHandler((args) =>
+      launcher.previewGameArgs(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "verify-instance-files",
+    serializableHandler((args) =>
+      instanceVerify.verifyInstanceFiles(
+        args.instanceId as string,
+        args.prepareResult as PrepareResponse | undefined,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "get-mc-versions",
+    serializableHandler((args) =>
+      launcher.getMcVersions(args.includeSnapshots === true),
+    ),
+  );
+
+  ipcMain.handle(
+    "list-instances",
+    serializableHandler((args) =>
+      launcher.listInstances(args.refresh === true),
+    ),
+  );
+
+  ipcMain.handle(
+    "get-max-concurrent-games",
+    serializableHandler(() => launcher.getMaxConcurrentGames()),
+  );
+
+  ipcMain.handle(
+    "set-max-concurrent-games",
+    serializableHandler((args) =>
+      launcher.setMaxConcurrentGames(args.max as number),
+    ),
+  );
+
+  ipcMain.handle(
+    "cancel-launch",
+    serializableHandler((args) =>
+      launcher.cancelLaunch(args.instanceId as string),
+    ),
+  );
+
   ipcMain.handle(
     "kill-game",
-    serializableHandler((args) => launcher.killGame(args.instanceId as string)),
+    serializableHandler((args) =>
+      launcher.killGame(
+        args.instanceId as string,
+        args.timeoutSecs as number | undefined,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "list-worlds",
+    serializableHandler((args) => worlds.listWorlds(args.instanceId as string)),
+  );
+
+  ipcMain.handle(
+    "open-world-folder",
+    serializableHandler((args) =>
+      worlds.openWorldFolder(
+        args.instanceId as string,
+        args.folder as string,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "read-level-dat",
+    serializableHandler((args) =>
+      worlds.readLevelDat(args.instanceId as string, args.folder as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "write-level-dat",
+    serializableHandler((args) =>
+      worlds.writeLevelDat(
+        args.instanceId as string,
+        args.folder as string,
+        args.changes,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "suspend-game",
+    serializableHandler((args) =>
+      launcher.suspendGame(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "resume-game",
+    serializableHandler((args) =>
+      launcher.resumeGame(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "clear-cache",
+    serializableHandler((args) => cache.clearCache(args.what as string[])),
+  );
+
+  ipcMain.handle(
+    "get-instance-size",
+    serializableHandler((args) =>
+      diskUsage.getInstanceSize(
+        args.instanceId as string,
+        args.refresh === true,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "export-config",
+    serializableHandler((args) =>
+      configTransfer.exportConfig(args.destPath as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "import-config",
+    serializableHandler((args) =>
+      configTransfer.importConfig(
+        args.srcPath as string,
+        args.overwrite === true,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "tail-log",
+    serializableHandler((args) =>
+      logs.tailLog(
+        args.path as string,
+        args.fromOffset as number | undefined,
+        args.fileId as string | undefined,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "export-crash-report",
+    serializableHandler((args) =>
+      crashReport.exportCrashReport(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "prune-game-logs",
+    serializableHandler((args) =>
+      logs.pruneGameLogs(args.keepLatest as number, args.maxAgeDays as number),
+    ),
+  );
+
+  ipcMain.handle(
+    "open-log-file",
+    serializableHandler((args) => logs.openLogFile(args.path as string)),
+  );
+
+  ipcMain.handle(
+    "set-rcon-password",
+    serializableHandler((args) =>
+      rcon.setRconPassword(args.serverId as string, args.password as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "clear-rcon-password",
+    serializableHandler((args) =>
+      rcon.clearRconPassword(args.serverId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "has-rcon-password",
+    serializableHandler((args) => rcon.hasRconPassword(args.serverId as string)),
+  );
+
+  ipcMain.handle(
+    "rcon-connect",
+    serializableHandler((args) =>
+      rcon.rconConnect(
+        args.serverId as string,
+        args.host as string,
+        args.port as number,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "rcon-command",
+    serializableHandler((args) =>
+      rcon.rconCommand(args.serverId as string, args.command as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "list-jvm-presets",
+    serializableHandler(() => jvmPresets.listJvmPresets()),
+  );
+
+  ipcMain.handle(
+    "save-jvm-preset",
+    serializableHandler((args) =>
+      jvmPresets.saveJvmPreset(args.name as string, args.args as string[]),
+    ),
+  );
+
+  ipcMain.handle(
+    "delete-jvm-preset",
+    serializableHandler((args) =>
+      jvmPresets.deleteJvmPreset(args.name as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "query-server",
+    serializableHandler((args) =>
+      query.queryServer(args.host as string, args.port as number),
+    ),
+  );
+
+  ipcMain.handle(
+    "lookup-player",
+    serializableHandler((args) =>
+      playerLookup.lookupPlayer(args.username as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "rcon-test",
+    serializableHandler((args) =>
+      rcon.rconTest(
+        args.host as string,
+        args.port as number,
+        args.password as string,
+        args.runList !== false,
+      ),
+    ),
+  );
+
+  ipcMain.handle(
+    "rcon-disconnect",
+    serializableHandler((args) => rcon.rconDisconnect(args.serverId as string)),
   );
 
   ipcMain.handle(
     "get-java-installations",
     serializableHandler(async () => {
-      const port = process.env.PORT ?? "3001";
-      const res = await fetch(`http://localhost:${port}/api/launcher/java`);
+      const res = await fetch(backendUrl("/api/launcher/java"));
       if (!res.ok) {
         const body = await res.text();
         throw new Error(`Failed to get Java installations: ${body}`);
@@ -86,18 +525,29 @@ export function registerIpcHandlers(): void {
     }),
   );
 
+  ipcMain.handle(
+    "get-available-java-versions",
+    serializableHandler(async () => {
+      const res = await fetch(backendUrl("/api/launcher/java/available"));
+      if (!res.ok) {
+        const body = await res.text();
+        throw new Error(`Failed to get available Java versions: ${body}`);
+      }
+      return res.json();
+    }),
+  );
+
   ipcMain.handle(
     "download-java",
     serializableHandler(async (args) => {
-      const port = process.env.PORT ?? "3001";
-      const res = await fetch(
-        `http://localhost:${port}/api/launcher/java/download`,
-        {
-          method: "POST",
-          headers: { "Content-Type": "application/json" },
-          body: JSON.stringify({ version: args.version }),
-        },
-      );
+      const res = await fetch(backendUrl("/api/launcher/java/download"), {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({
+          version: args.version,
+          allowEmulated: args.allowEmulated,
+        }),
+      });
       if (!res.ok) {
         const body = await res.text();
         throw new Error(`Failed to download Java: ${body}`);
@@ -105,4 +555,48 @@ export function registerIpcHandlers(): void {
       return res.json();
     }),
   );
+
+  ipcMain.handle(
+    "warm-java",
+    serializableHandler(async (args) => {
+      const res = await fetch(backendUrl("/api/launcher/java/warm"), {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ javaPath: args.javaPath }),
+      });
+      if (!res.ok) {
+        const body = await res.text();
+        throw new Error(`Failed to warm up Java: ${body}`);
+      }
+      return res.json();
+    }),
+  );
+
+  ipcMain.handle(
+    "diagnose-java",
+    serializableHandler((args) =>
+      javaDiagnosis.diagnoseJava(args.instanceId as string),
+    ),
+  );
+
+  ipcMain.handle(
+    "open-java-download-page",
+    serializableHandler((args) =>
+      java.openJavaDownloadPage(args.version as number),
+    ),
+  );
+
+  ipcMain.handle(
+    "cancel-java-download",
+    serializableHandler(async (args) => {
+      const res = await fetch(
+        backendUrl(`/api/launcher/java/download/${args.version}`),
+        { method: "DELETE" },
+      );
+      if (!res.ok) {
+        const body = await res.text();
+        throw new Error(`Failed to cancel Java download: ${body}`);
+      }
+    }),
+  );
 }
@@ -0,0 +1,66 @@
+import { compareJavaCandidates } from "./java-diagnosis.js";
+
+vi.mock("electron", () => ({}));
+
+describe("compareJavaCandidates", () => {
+  const candidate = (
+    source: "path" | "java_home" | "instance",
+    realPath: string | null,
+    version: string | null,
+  ) => ({
+    source,
+    path: realPath,
+    realPath,
+    version,
+    error: null,
+  });
+
+  it("agrees when every Java found is the same binary", () => {
+    const result = compareJavaCandidates([
+      candidate("path", "/jdk21/bin/java", "21.0.1"),
+      candidate("java_home", null, null),
+      candidate("instance", "/jdk21/bin/java", "21.0.1"),
+    ]);
+    expect(result.agree).toBe(true);
+    expect(result.sameMajorVersion).toBe(true);
+    expect(result.summary).toContain("PATH use");
+  });
+
+  it("explains a different major version on PATH", () => {
+    const result = compareJavaCandidates([
+      candidate("path", "/usr/bin/java", "1.8.0_392"),
+      candidate("java_home", "/jdk21/bin/java", "21.0.1"),
+      candidate("instance", "/jdk21/bin/java", "21.0.1"),
+    ]);
+    expect(result.agree).toBe(false);
+    expect(result.sameMajorVersion).toBe(false);
+    expect(result.summary).toBe(
+      "The instance runs Java 21.0.1 (/jdk21/bin/java), but PATH has " +
+        "Java 1.8.0_392 (/usr/bin/java) — a game started from a terminal " +
+        "won't use the same Java",
+    );
+  });
+
+  it("notes when different binaries share a major version", () => {
+    const result = compareJavaCandidates([
+      candidate("path", "/usr/bin/java", "21.0.3"),
+      candidate("instance", "/jdk21/bin/java", "21.0.1"),
+    ]);
+    expect(result.agree).toBe(false);
+    expect(result.sameMajorVersion).toBe(true);
+  });
+
+  it("reports an instance without Java", () => {
+    const result = compareJavaCandidates([
+      candidate("path", "/usr/bin/java", "21.0.1"),
+      {
+        ...candidate("instance", null, null),
+        error: "Java 21 not found",
+      },
+    ]);
+    expect(result.agree).toBe(false);
+    expect(result.summary).toBe(
+      "This instance has no usable Java: Java 21 not found",
+    );
+  });
+});
@@ -0,0 +1,251 @@
+/**
+ * "Works in my terminal but not in the app" helper: compares the java on
+ * the user's shell PATH, the one under JAVA_HOME and the one an instance
+ * would launch with. Apps started from Finder or a desktop menu don't get
+ * the shell's PATH or JAVA_HOME, so on macOS/Linux both are read from a
+ * login shell rather than from this process.
+ * @module java-diagnosis
+ */
+
+import { execFile } from "node:child_process";
+import { existsSync, realpathSync } from "node:fs";
+import path from "node:path";
+import { promisify } from "node:util";
+import {
+  getJavaMajorVersion,
+  type JavaCandidate,
+  type JavaDiagnosis,
+  type JavaInfo,
+  type JavaSource,
+  type LauncherInstance,
+} from "@mc-server-manager/shared";
+import { backendUrl } from "./backend-port.js";
+import { resolveJavaPath } from "./launcher.js";
+
+const execFileAsync = promisify(execFile);
+
+const SHELL_TIMEOUT_MS = 5_000;
+
+/** Prefixes our output lines; login shells may print banners or motd. */
+const MARKER = "__mcsm_java__";
+
+interface ShellJava {
+  javaOnPath: string | null;
+  javaHome: string | null;
+}
+
+function javaBinName(): string {
+  return process.platform === "win32" ? "java.exe" : "java";
+}
+
+async function readShellJava(): Promise<ShellJava> {
+  if (process.platform === "win32") {
+    // GUI apps inherit the user's environment on Windows
+    let javaOnPath: string | null = null;
+    try {
+      const { stdout } = await execFileAsync("where", ["java"], {
+        timeout: SHELL_TIMEOUT_MS,
+        windowsHide: true,
+      });
+      javaOnPath = stdout.split(/\r?\n/)[0]?.trim() || null;
+    } catch {
+      // Not on PATH
+    }
+    return { javaOnPath, javaHome: process.env.JAVA_HOME || null };
+  }
+
+  const shell = process.env.SHELL || "/bin/sh";
+  const script =
+    `printf '${MARKER}path=%s\\n' "$(command -v java)"; ` +
+    `printf '${MARKER}home=%s\\n' "$JAVA_HOME"`;
+  try {
+    const { stdout } = await execFileAsync(shell, ["-ilc", script], {
+      timeout: SHELL_TIMEOUT_MS,
+    });
+    const value = (key: string): string | null => {
+      const line = stdout
+        .split(/\r?\n/)
+        .find((l) => l.startsWith(`${MARKER}${key}=`));
+      return line?.slice(MARKER.length + key.length + 1).trim() || null;
+    };
+    return { javaOnPath: value("path"), javaHome: value("home") };
+  } catch (err) {
+    console.warn("Could not read java from the login shell:", err);
+    return {
+      javaOnPath: null,
+      javaHome: process.env.JAVA_HOME || null,
+    };
+  }
+}
+
+/** Common JAVA_HOME layouts: the JDK root, a JDK 8 jre/, a macOS bundle. */
+function javaHomeBinary(javaHome: string): string | null {
+  const candidates = [
+    path.join(javaHome, "bin", javaBinName()),
+    path.join(javaHome, "jre", "bin", javaBinName()),
+    path.join(javaHome, "Contents", "Home", "bin", javaBinName()),
+  ];
+  return candidates.find((candidate) => existsSync(candidate)) ?? null;
+}
+
+async function probe(
+  source: JavaSource,
+  javaPath: string | null,
+  missing: string,
+): Promise<JavaCandidate> {
+  if (!javaPath) {
+    return {
+      source,
+      path: null,
+      realPath: null,
+      version: null,
+      error: missing,
+    };
+  }
+
+  let realPath: string | null = null;
+  try {
+    realPath = realpathSync(javaPath);
+  } catch {
+    // Bare command name or dangling link; validation below explains it
+  }
+
+  try {
+    const res = await fetch(
+      backendUrl(`/api/system/java?path=${encodeURIComponent(javaPath)}`),
+    );
+    if (!res.ok) throw new Error(await res.text());
+    const info = (await res.json()) as JavaInfo;
+    return {
+      source,
+      path: javaPath,
+      realPath,
+      version: info.version,
+      error: info.found ? null : (info.errorMessage ?? "Not a working Java"),
+    };
+  } catch (err) {
+    return {
+      source,
+      path: javaPath,
+      realPath,
+      version: null,
+      error: err instanceof Error ? err.message : String(err),
+    };
+  }
+}
+
+const JAVA_SOURCE_LABELS: Record<JavaSource, string> = {
+  path: "PATH",
+  java_home: "JAVA_HOME",
+  instance: "the instance",
+};
+
+function describeJavaCandidate(candidate: JavaCandidate): string {
+  return candidate.version
+    ? `Java ${candidate.version} (${candidate.path})`
+    : `${candidate.path} (version unknown)`;
+}
+
+/** Compare diagnosis candidates and explain any mismatch in one sentence */
+export function compareJavaCandidates(
+  candidates: JavaCandidate[],
+): Pick<JavaDiagnosis, "agree" | "sameMajorVersion" | "summary"> {
+  const found = candidates.filter((c) => c.path !== null);
+  const majors = new Set(
+    found.map((c) => (c.version ? getJavaMajorVersion(c.version) : NaN)),
+  );
+  const sameMajorVersion = majors.size === 1 && !majors.has(NaN);
+
+  const instance = candidates.find((c) => c.source === "instance");
+  if (!instance?.path) {
+    return {
+      agree: false,
+      sameMajorVersion,
+      summary: `This instance has no usable Java${
+        instance?.error ? `: ${instance.error}` : ""
+      }`,
+    };
+  }
+
+  const differing = found.filter(
+    (c) => c.source !== "instance" && c.realPath !== instance.realPath,
+  );
+  if (differing.length === 0) {
+    const matching = found
+      .filter((c) => c.source !== "instance")
+      .map((c) => JAVA_SOURCE_LABELS[c.source]);
+    const instanceJava = describeJavaCandidate(instance);
+    return {
+      agree: true,
+      sameMajorVersion,
+      summary:
+        matching.length === 0
+          ? "No java on PATH or in JAVA_HOME; only the instance's Java exists"
+          : `${matching.join(" and ")} use the instance's ${instanceJava}`,
+    };
+  }
+
+  const others = differing
+    .map(
+      (c) => `${JAVA_SOURCE_LABELS[c.source]} has ${describeJavaCandidate(c)}`,
+    )
+    .join(" and ");
+  return {
+    agree: false,
+    sameMajorVersion,
+    summary:
+      `The instance runs ${describeJavaCandidate(instance)}, but ${others}` +
+      (sameMajorVersion
+        ? " — same major version, so this is unlikely to matter"
+        : " — a game started from a terminal won't use the same Java"),
+  };
+}
+
+async function resolveInstanceJava(
+  instanceId: string,
+): Promise<{ path: string | null; error: string }> {
+  const res = await fetch(backendUrl(`/api/launcher/instances/${instanceId}`));
+  if (!res.ok) {
+    throw new Error(`Failed to load instance: ${await res.text()}`);
+  }
+  const instance = (await res.json()) as LauncherInstance;
+  try {
+    return { path: await resolveJavaPath(instance), error: "" };
+  } catch (err) {
+    return {
+      path: null,
+      error: err instanceof Error ? err.message : String(err),
+    };
+  }
+}
+
+/** Compare PATH, JAVA_HOME and the instance's resolved Java. */
+export async function diagnoseJava(
+  instanceId: string,
+): Promise<JavaDiagnosis> {
+  const [shellJava, instanceJava] = await Promise.all([
+    readShellJava(),
+    resolveInstanceJava(instanceId),
+  ]);
+
+  const homeBinary = shellJava.javaHome
+    ? javaHomeBinary(shellJava.javaHome)
+    : null;
+  const candidates = await Promise.all([
+    probe("path", shellJava.javaOnPath, "No java on PATH"),
+    probe(
+      "java_home",
+      homeBinary,
+      shellJava.javaHome
+        ? `No java binary under JAVA_HOME (${shellJava.javaHome})`
+        : "JAVA_HOME is not set",
+    ),
+    probe("instance", instanceJava.path, instanceJava.error),
+  ]);
+
+  return {
+    instanceId,
+    candidates,
+    ...compareJavaCandidates(candidates),
+  };
+}
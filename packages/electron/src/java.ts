@@ -0,0 +1,52 @@
+/**
+ * Manual Java install fallback for when automatic downloads are blocked.
+ * @module java
+ */
+
+import { shell } from "electron";
+
+function adoptiumOs(): string | null {
+  switch (process.platform) {
+    case "win32":
+      return "windows";
+    case "darwin":
+      return "mac";
+    case "linux":
+      return "linux";
+    default:
+      return null;
+  }
+}
+
+function adoptiumArch(): string | null {
+  switch (process.arch) {
+    case "x64":
+      return "x64";
+    case "arm64":
+      return "aarch64";
+    case "ia32":
+      return "x86";
+    default:
+      return null;
+  }
+}
+
+/**
+ * Open the Temurin releases page for `version`, pre-filtered to this
+ * platform where Adoptium has a matching build. The user can then point the
+ * Java path setting (or an instance's Java path) at what they installed.
+ */
+export async function openJavaDownloadPage(version: number): Promise<void> {
+  if (!Number.isInteger(version) || version < 8 || version > 99) {
+    throw new Error(`Invalid Java version: ${version}`);
+  }
+
+  const url = new URL("https://adoptium.net/temurin/releases/");
+  url.searchParams.set("version", String(version));
+  const os = adoptiumOs();
+  const arch = adoptiumArch();
+  if (os) url.searchParams.set("os", os);
+  if (arch) url.searchParams.set("arch", arch);
+
+  await shell.openExternal(url.toString());
+}
@@ -0,0 +1,41 @@
+import {
+  BUILTIN_JVM_ARGS_PRESETS,
+  findUnsafeJvmArgs,
+} from "./jvm-presets.js";
+
+vi.mock("electron", () => ({}));
+
+describe("findUnsafeJvmArgs", () => {
+  it("accepts GC tuning flags", () => {
+    expect(
+      findUnsafeJvmArgs(["-XX:+UseG1GC", "-XX:MaxGCPauseMillis=200"]),
+    ).toEqual([]);
+  });
+
+  it("flags agents and shell hooks", () => {
+    expect(
+      findUnsafeJvmArgs([
+        "-XX:+UseG1GC",
+        "-javaagent:/tmp/evil.jar",
+        "-agentpath:/tmp/lib.so",
+        "-XX:OnOutOfMemoryError=rm -rf ~",
+      ]),
+    ).toEqual([
+      "-javaagent:/tmp/evil.jar",
+      "-agentpath:/tmp/lib.so",
+      "-XX:OnOutOfMemoryError=rm -rf ~",
+    ]);
+  });
+
+  it("flags classpath and jar overrides", () => {
+    expect(
+      findUnsafeJvmArgs(["-cp", "x.jar", "--class-path=y.jar", "-jar"]),
+    ).toEqual(["-cp", "--class-path=y.jar", "-jar"]);
+  });
+
+  it("passes every built-in preset", () => {
+    for (const preset of BUILTIN_JVM_ARGS_PRESETS) {
+      expect(findUnsafeJvmArgs(preset.args)).toEqual([]);
+    }
+  });
+});
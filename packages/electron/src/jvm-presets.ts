@@ -0,0 +1,177 @@
+/**
+ * User-defined JVM flag presets for launcher instances, stored as JSON next
+ * to the other app data. Built-in presets are merged in on read and can't be
+ * overwritten or deleted.
+ * @module jvm-presets
+ */
+
+import { mkdirSync, readFileSync, writeFileSync } from "node:fs";
+import path from "node:path";
+import type { JvmArgsPreset } from "@mc-server-manager/shared";
+import { getDataDir } from "./data-dir.js";
+
+const MAX_PRESET_NAME_LENGTH = 64;
+
+/** Read-only presets shipped with the app. */
+export const BUILTIN_JVM_ARGS_PRESETS: readonly JvmArgsPreset[] = [
+  {
+    name: "Aikar's Flags",
+    args: [
+      "-XX:+UseG1GC",
+      "-XX:+ParallelRefProcEnabled",
+      "-XX:MaxGCPauseMillis=200",
+      "-XX:+UnlockExperimentalVMOptions",
+      "-XX:+DisableExplicitGC",
+      "-XX:+AlwaysPreTouch",
+      "-XX:G1NewSizePercent=30",
+      "-XX:G1MaxNewSizePercent=40",
+      "-XX:G1HeapRegionSize=8M",
+      "-XX:G1ReservePercent=20",
+      "-XX:G1HeapWastePercent=5",
+      "-XX:G1MixedGCCountTarget=4",
+      "-XX:InitiatingHeapOccupancyPercent=15",
+      "-XX:G1MixedGCLiveThresholdPercent=90",
+      "-XX:G1RSetUpdatingPauseTimePercent=5",
+      "-XX:SurvivorRatio=32",
+      "-XX:+PerfDisableSharedMem",
+      "-XX:MaxTenuringThreshold=1",
+    ],
+    builtIn: true,
+  },
+];
+
+/**
+ * Flags that load foreign code or run shell commands. A shared "performance"
+ * preset has no business containing them.
+ */
+const UNSAFE_JVM_ARG_PREFIXES = [
+  "-javaagent:",
+  "-agentlib:",
+  "-agentpath:",
+  "-Xbootclasspath",
+  "-XX:OnError=",
+  "-XX:OnOutOfMemoryError=",
+  "-Djava.system.class.loader=",
+];
+
+const UNSAFE_JVM_ARGS = new Set(["-jar", "-cp", "-classpath", "--class-path"]);
+
+/** Returns the args that shouldn't appear in a JVM preset (empty if none). */
+export function findUnsafeJvmArgs(args: string[]): string[] {
+  return args.filter((raw) => {
+    const arg = raw.trim();
+    return (
+      UNSAFE_JVM_ARGS.has(arg) ||
+      arg.startsWith("--class-path=") ||
+      UNSAFE_JVM_ARG_PREFIXES.some((prefix) => arg.startsWith(prefix))
+    );
+  });
+}
+
+function presetsFilePath(): string {
+  return path.join(getDataDir(), "jvm-presets.json");
+}
+
+function readUserPresets(): Record<string, string[]> {
+  try {
+    const raw = readFileSync(presetsFilePath(), "utf-8");
+    const parsed: unknown = JSON.parse(raw);
+    if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+      return {};
+    }
+    const presets: Record<string, string[]> = {};
+    for (const [name, args] of Object.entries(parsed)) {
+      if (Array.isArray(args) && args.every((a) => typeof a === "string")) {
+        presets[name] = args;
+      }
+    }
+    return presets;
+  } catch {
+    return {};
+  }
+}
+
+function writeUserPresets(presets: Record<string, string[]>): void {
+  const filePath = presetsFilePath();
+  mkdirSync(path.dirname(filePath), { recursive: true });
+  writeFileSync(filePath, JSON.stringify(presets, null, 2), "utf-8");
+}
+
+/** The user's own presets by name, as stored (for config backups). */
+export function getUserJvmPresets(): Record<string, string[]> {
+  return readUserPresets();
+}
+
+function isBuiltIn(name: string): boolean {
+  return BUILTIN_JVM_ARGS_PRESETS.some(
+    (p) => p.name.toLowerCase() === name.toLowerCase(),
+  );
+}
+
+/** Built-in presets first, then the user's in name order. */
+export async function listJvmPresets(): Promise<JvmArgsPreset[]> {
+  const user = Object.entries(readUserPresets())
+    .sort(([a], [b]) => a.localeCompare(b))
+    .map(([name, args]) => ({ name, args, builtIn: false }));
+  return [...BUILTIN_JVM_ARGS_PRESETS, ...user];
+}
+
+/**
+ * Check a user preset and return it cleaned up (trimmed name and args).
+ * Throws with a user-facing message if it can't be saved.
+ */
+export function checkJvmPreset(
+  name: string,
+  args: string[],
+): { name: string; args: string[] } {
+  const trimmedName = name.trim();
+  if (!trimmedName) {
+    throw new Error("Preset name is required");
+  }
+  if (trimmedName.length > MAX_PRESET_NAME_LENGTH) {
+    throw new Error(
+      `Preset name must be ${MAX_PRESET_NAME_LENGTH} characters or less`,
+    );
+  }
+  if (isBuiltIn(trimmedName)) {
+    throw new Error(
+      `"${trimmedName}" is a built-in preset and can't be changed`,
+    );
+  }
+
+  const cleanArgs = args.map((a) => a.trim()).filter(Boolean);
+  if (cleanArgs.length === 0) {
+    throw new Error("Preset must contain at least one argument");
+  }
+  const unsafe = findUnsafeJvmArgs(cleanArgs);
+  if (unsafe.length > 0) {
+    throw new Error(
+      `Preset contains disallowed arguments: ${unsafe.join(" ")}`,
+    );
+  }
+  return { name: trimmedName, args: cleanArgs };
+}
+
+/** Create or replace a user preset. */
+export async function saveJvmPreset(
+  name: string,
+  args: string[],
+): Promise<JvmArgsPreset> {
+  const { name: trimmedName, args: cleanArgs } = checkJvmPreset(name, args);
+  const presets = readUserPresets();
+  presets[trimmedName] = cleanArgs;
+  writeUserPresets(presets);
+  return { name: trimmedName, args: cleanArgs, builtIn: false };
+}
+
+export async function deleteJvmPreset(name: string): Promise<void> {
+  if (isBuiltIn(name)) {
+    throw new Error(`"${name}" is a built-in preset and can't be deleted`);
+  }
+  const presets = readUserPresets();
+  if (!(name in presets)) {
+    throw new Error(`No preset named "${name}"`);
+  }
+  delete presets[name];
+  writeUserPresets(presets);
+}
@@ -0,0 +1,331 @@
+import { decodeLaunchError } from "@mc-server-manager/shared";
+import {
+  buildJavaAgentArgs,
+  buildLaunchClasspath,
+  createLaunchTimer,
+  encodeLaunchError,
+  expandLaunchArgPlaceholders,
+  formatJavaAgentArg,
+  getEffectiveClasspath,
+  LaunchError,
+  normalizeJvmArgs,
+  prependToPath,
+} from "./launcher.js";
+
+vi.mock("electron", () => ({}));
+
+describe("encodeLaunchError", () => {
+  it("round-trips through an Electron-wrapped message", () => {
+    const err = new LaunchError("java_not_found", "Java 21 not found");
+    err.stage = "resolving_java";
+    const wrapped = `Error invoking remote method 'launch-game': Error: ${encodeLaunchError(err.toInfo())}`;
+    expect(decodeLaunchError(wrapped)).toEqual({
+      code: "java_not_found",
+      message: "Java 21 not found",
+      stage: "resolving_java",
+    });
+  });
+});
+
+describe("createLaunchTimer", () => {
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it("keeps the last stage's duration when finishing after running", () => {
+    const now = vi.spyOn(performance, "now");
+    now.mockReturnValue(0);
+    const timer = createLaunchTimer("inst-1", 500);
+
+    now.mockReturnValue(100);
+    timer.enterStage("fetching_instance");
+    now.mockReturnValue(250);
+    timer.enterStage("spawning");
+    now.mockReturnValue(1250);
+    timer.enterStage("running");
+    now.mockReturnValue(1300);
+    const timings = timer.finish(true);
+
+    expect(timings.stages).toEqual([
+      { stage: "fetching_instance", ms: 150 },
+      { stage: "spawning", ms: 1000 },
+    ]);
+    expect(timings.totalMs).toBe(1650);
+    expect(timings.succeeded).toBe(true);
+  });
+
+  it("closes the failing stage when a launch fails", () => {
+    const now = vi.spyOn(performance, "now");
+    now.mockReturnValue(0);
+    const timer = createLaunchTimer("inst-1", null);
+
+    timer.enterStage("fetching_instance");
+    now.mockReturnValue(40);
+    const timings = timer.finish(false);
+
+    expect(timings.stages).toEqual([{ stage: "fetching_instance", ms: 40 }]);
+    expect(timings.totalMs).toBe(40);
+    expect(timings.succeeded).toBe(false);
+  });
+});
+
+describe("normalizeJvmArgs", () => {
+  it("uses the instance RAM settings when the user sets none", () => {
+    expect(normalizeJvmArgs(2, 4, ["-XX:+UseG1GC"])).toEqual([
+      "-Xms2G",
+      "-Xmx4G",
+      "-XX:+UseG1GC",
+    ]);
+  });
+
+  it("lets user memory flags override the computed ones", () => {
+    expect(normalizeJvmArgs(2, 4, ["-Xmx6G", "-Xms3072M"])).toEqual([
+      "-Xms3072M",
+      "-Xmx6G",
+    ]);
+  });
+
+  it("keeps only the last of repeated user memory flags", () => {
+    expect(normalizeJvmArgs(2, 4, ["-Xmx6G", "-Xmx8G"])).toEqual([
+      "-Xms2G",
+      "-Xmx8G",
+    ]);
+  });
+
+  it("strips user classpath flags and their values", () => {
+    expect(
+      normalizeJvmArgs(1, 2, [
+        "-cp",
+        "mods.jar",
+        "-Dfoo=bar",
+        "-classpath",
+        "x.jar",
+        "--class-path=y.jar",
+        "--class-path",
+        "z.jar",
+      ]),
+    ).toEqual(["-Xms1G", "-Xmx2G", "-Dfoo=bar"]);
+  });
+
+  it("drops blank entries", () => {
+    expect(normalizeJvmArgs(1, 2, ["", "  "])).toEqual(["-Xms1G", "-Xmx2G"]);
+  });
+});
+
+describe("expandLaunchArgPlaceholders", () => {
+  const values = {
+    instanceDir: "/data/instances/abc",
+    assetsDir: "/data/assets",
+    nativesDir: "/data/natives/abc-1",
+    logsDir: "/data/instances/abc/logs",
+    ram: "4G",
+  };
+
+  it("substitutes known placeholders", () => {
+    const result = expandLaunchArgPlaceholders(
+      ["-Xlog:gc:file={logsDir}/gc.log", "-XX:MaxDirectMemorySize={ram}"],
+      values,
+    );
+    expect(result.args).toEqual([
+      "-Xlog:gc:file=/data/instances/abc/logs/gc.log",
+      "-XX:MaxDirectMemorySize=4G",
+    ]);
+    expect(result.unknown).toEqual([]);
+  });
+
+  it("replaces every occurrence in one arg", () => {
+    const result = expandLaunchArgPlaceholders(
+      ["{instanceDir}:{nativesDir}:{instanceDir}"],
+      values,
+    );
+    expect(result.args).toEqual([
+      "/data/instances/abc:/data/natives/abc-1:/data/instances/abc",
+    ]);
+  });
+
+  it("leaves unknown placeholders and reports each once", () => {
+    const result = expandLaunchArgPlaceholders(
+      ["--foo={gameDir}", "--bar={gameDir}", "{assetsDir}"],
+      values,
+    );
+    expect(result.args).toEqual([
+      "--foo={gameDir}",
+      "--bar={gameDir}",
+      "/data/assets",
+    ]);
+    expect(result.unknown).toEqual(["gameDir"]);
+  });
+
+  it("ignores braces that aren't placeholders", () => {
+    const result = expandLaunchArgPlaceholders(['{"a":1}', "{}"], values);
+    expect(result.args).toEqual(['{"a":1}', "{}"]);
+    expect(result.unknown).toEqual([]);
+  });
+
+  it("doesn't treat inherited object keys as placeholders", () => {
+    const result = expandLaunchArgPlaceholders(["{constructor}"], values);
+    expect(result.args).toEqual(["{constructor}"]);
+    expect(result.unknown).toEqual(["constructor"]);
+  });
+});
+
+describe("formatJavaAgentArg", () => {
+  it("appends options after =", () => {
+    expect(
+      formatJavaAgentArg({
+        path: "/tools/spark-agent.jar",
+        options: "port=9000,mode=sampling",
+      }),
+    ).toBe("-javaagent:/tools/spark-agent.jar=port=9000,mode=sampling");
+  });
+
+  it("omits = when there are no options", () => {
+    expect(
+      formatJavaAgentArg({ path: "C:\\Tools\\agent.jar", options: "" }),
+    ).toBe("-javaagent:C:\\Tools\\agent.jar");
+  });
+});
+
+describe("buildJavaAgentArgs", () => {
+  const placeholders = {
+    instanceDir: "/data/instances/abc",
+    assetsDir: "/data/assets",
+    nativesDir: "/data/natives/abc-1",
+    logsDir: "/data/instances/abc/logs",
+    ram: "4G",
+  };
+
+  it("expands placeholders in the options but not the path", () => {
+    expect(
+      buildJavaAgentArgs(
+        [
+          { path: "{instanceDir}/agent.jar", options: "out={logsDir}/a.log" },
+          { path: "/tools/other.jar", options: "" },
+        ],
+        placeholders,
+      ),
+    ).toEqual({
+      args: [
+        "-javaagent:{instanceDir}/agent.jar=out=/data/instances/abc/logs/a.log",
+        "-javaagent:/tools/other.jar",
+      ],
+      unknown: [],
+    });
+  });
+
+  it("reports unknown placeholders in the options", () => {
+    expect(
+      buildJavaAgentArgs(
+        [{ path: "/tools/agent.jar", options: "dir={gameDir}" }],
+        placeholders,
+      ).unknown,
+    ).toEqual(["gameDir"]);
+  });
+});
+
+describe("buildLaunchClasspath", () => {
+  it("puts the game jar last", () => {
+    expect(
+      buildLaunchClasspath({
+        classpath: ["/libs/a.jar", "/libs/b.jar"],
+        gameJarPath: "/versions/1.21/1.21.jar",
+      }),
+    ).toEqual(["/libs/a.jar", "/libs/b.jar", "/versions/1.21/1.21.jar"]);
+  });
+
+  it("keeps the first occurrence of a repeated jar", () => {
+    expect(
+      buildLaunchClasspath({
+        classpath: ["/libs/a.jar", "/libs/b.jar", "/libs/a.jar"],
+        gameJarPath: "/libs/b.jar",
+      }),
+    ).toEqual(["/libs/a.jar", "/libs/b.jar"]);
+  });
+});
+
+describe("getEffectiveClasspath", () => {
+  const prepared = {
+    classpath: ["/libs/a.jar"],
+    gameJarPath: "/versions/1.21/1.21.jar",
+  };
+  const job = (phase: string) => ({
+    id: "job-1",
+    instanceId: "inst-1",
+    phase,
+    result: phase === "completed" ? prepared : null,
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it("waits on a prepare that is already running", async () => {
+    const fetchMock = vi.fn(async (url: string, init?: RequestInit) => {
+      if (init?.method === "POST") {
+        return Response.json(
+          { error: "in progress", details: { jobId: "job-1" } },
+          { status: 409 },
+        );
+      }
+      expect(url).toMatch(/\/api\/launcher\/prepare\/jobs\/job-1$/);
+      return Response.json(job("completed"));
+    });
+    vi.stubGlobal("fetch", fetchMock);
+
+    const entries = await getEffectiveClasspath("inst-1");
+    expect(entries.map((e) => e.path)).toEqual([
+      "/libs/a.jar",
+      "/versions/1.21/1.21.jar",
+    ]);
+  });
+
+  it("gives up and cancels its own job after the deadline", async () => {
+    vi.useFakeTimers();
+    const fetchMock = vi.fn(async (_url: string, init?: RequestInit) =>
+      init?.method === "DELETE"
+        ? Response.json({ message: "cancelled" })
+        : Response.json(job("libraries"), {
+            status: init?.method === "POST" ? 202 : 200,
+          }),
+    );
+    vi.stubGlobal("fetch", fetchMock);
+
+    const result = getEffectiveClasspath("inst-1");
+    const assertion = expect(result).rejects.toThrow(/Prepare timed out/);
+    await vi.advanceTimersByTimeAsync(16 * 60_000);
+    await assertion;
+    expect(
+      fetchMock.mock.calls.some(([, init]) => init?.method === "DELETE"),
+    ).toBe(true);
+  });
+});
+
+describe("prependToPath", () => {
+  it("puts the directories first on POSIX", () => {
+    expect(
+      prependToPath(
+        { PATH: "/usr/bin:/bin" },
+        ["/opt/natives", "/opt/more"],
+        "linux",
+      ),
+    ).toEqual({ key: "PATH", value: "/opt/natives:/opt/more:/usr/bin:/bin" });
+  });
+
+  it("reuses Windows' Path key and separator", () => {
+    expect(
+      prependToPath(
+        { Path: "C:\\Windows\\system32" },
+        ["D:\\natives"],
+        "win32",
+      ),
+    ).toEqual({ key: "Path", value: "D:\\natives;C:\\Windows\\system32" });
+  });
+
+  it("creates PATH when there is none", () => {
+    expect(prependToPath({}, ["/opt/natives"], "darwin")).toEqual({
+      key: "PATH",
+      value: "/opt/natives",
+    });
+  });
+});
@@ -1,32 +1,325 @@
-import { spawn, type ChildProcess } from "node:child_process";
+import { execFile, spawn, type ChildProcess } from "node:child_process";
+import { promisify } from "node:util";
+import { app } from "electron";
+import os from "node:os";
 import path from "node:path";
-import { mkdirSync } from "node:fs";
-import { getMcAccessToken } from "./auth.js";
+import { createInterface } from "node:readline";
+import {
+  accessSync,
+  constants as fsConstants,
+  cpSync,
+  existsSync,
+  mkdirSync,
+  readdirSync,
+  readFileSync,
+  rmSync,
+  statSync,
+  writeFileSync,
+} from "node:fs";
+import { getAccountTokenStatus, getMcAccessToken } from "./auth.js";
+import { backendUrl } from "./backend-port.js";
+import { notifyGameCrash } from "./crash-notifications.js";
+import { getDataDir } from "./data-dir.js";
+import { emitToRenderers } from "./events.js";
+import { applyGpuPreference } from "./gpu.js";
+import { isJavaTranslated } from "./platform.js";
+import { LAUNCH_ERROR_PREFIX } from "@mc-server-manager/shared";
 import type {
+  AppSettings,
+  ClasspathEntry,
+  GameArgsPreview,
+  GameCrashedEvent,
+  GameForceKilledEvent,
   GameProcess,
+  InstanceIssue,
+  InstancePaths,
+  InstanceSummary,
   LauncherInstance,
   LauncherAccount,
+  JavaAgent,
   JavaInstallation,
+  LaunchErrorCode,
+  LaunchErrorInfo,
+  LaunchFailedEvent,
+  LaunchStage,
+  LaunchTimings,
+  McVersionSummary,
+  PrepareJob,
   PrepareResponse,
+  RunningGameDetails,
 } from "@mc-server-manager/shared";
 
-const BACKEND_PORT = process.env.BACKEND_PORT
-  ? parseInt(process.env.BACKEND_PORT, 10)
-  : 3001;
+const execFileAsync = promisify(execFile);
 
 function baseUrl(): string {
-  return `http://localhost:${BACKEND_PORT}`;
+  return backendUrl();
 }
 
 interface RunningGame {
+  /** `process.ready` flips once a readiness marker shows up in the output. */
   process: GameProcess;
   child: ChildProcess;
+  accountId: string;
+  username: string;
+  javaPath: string;
+  nativesDir: string;
+  stallTimer: ReturnType<typeof setTimeout> | null;
+  /** Set by killGame/killAllGames so the exit isn't treated as a crash. */
+  stopRequested: boolean;
+  /** When the client last logged the start of a world save, if unfinished. */
+  savingSince: number | null;
 }
 
 const runningGames: RunningGame[] = [];
 
+/** Launches past the concurrency check that aren't in runningGames yet. */
+let launchesInFlight = 0;
+
+/** Aborts for launches that haven't spawned their game yet, by instance. */
+const cancellableLaunches = new Map<string, AbortController>();
+
+/**
+ * A launcher failure with a machine-readable code. IPC handlers send it to
+ * the renderer via encodeLaunchError; the message stays human-readable.
+ */
+export class LaunchError extends Error {
+  stage: LaunchStage | null = null;
+
+  constructor(
+    readonly code: LaunchErrorCode,
+    message: string,
+  ) {
+    super(message);
+    this.name = "LaunchError";
+  }
+
+  toInfo(): LaunchErrorInfo {
+    return { code: this.code, message: this.message, stage: this.stage };
+  }
+}
+
+/** The IPC error message for a LaunchError; see decodeLaunchError */
+export function encodeLaunchError(info: LaunchErrorInfo): string {
+  return LAUNCH_ERROR_PREFIX + JSON.stringify(info);
+}
+
+/** Max crash restarts within RESTART_WINDOW_MS before giving up. */
+const RESTART_MAX_ATTEMPTS = 3;
+
+/** Window over which crash restarts are counted. */
+const RESTART_WINDOW_MS = 10 * 60 * 1000;
+
+/** Delay before relaunching a crashed game. */
+const RESTART_DELAY_MS = 5_000;
+
+/** Timestamps of recent crash restarts, keyed by instance ID. */
+const restartAttempts = new Map<string, number[]>();
+
+/** Pending crash-restart timers, keyed by instance ID. */
+const pendingRestarts = new Map<string, ReturnType<typeof setTimeout>>();
+
+function cancelPendingRestart(instanceId: string): void {
+  const timer = pendingRestarts.get(instanceId);
+  if (timer) {
+    clearTimeout(timer);
+    pendingRestarts.delete(instanceId);
+  }
+}
+
+/**
+ * Emit `game-crashed`, show a crash notification and, if the instance opted
+ * in and the retry cap allows, relaunch it with the same account after
+ * RESTART_DELAY_MS.
+ */
+function handleGameCrash(
+  entry: RunningGame,
+  instance: LauncherInstance,
+  outputTail: string[],
+  prepareResult: PrepareResponse,
+  code: number | null,
+  signal: string | null,
+): void {
+  const { instanceId } = entry.process;
+  let restartAttempt: number | null = null;
+
+  if (instance.restartOnCrash && !pendingRestarts.has(instanceId)) {
+    const now = Date.now();
+    const recent = (restartAttempts.get(instanceId) ?? []).filter(
+      (t) => now - t < RESTART_WINDOW_MS,
+    );
+    if (recent.length < RESTART_MAX_ATTEMPTS) {
+      recent.push(now);
+      restartAttempt = recent.length;
+      pendingRestarts.set(
+        instanceId,
+        setTimeout(() => {
+          pendingRestarts.delete(instanceId);
+          launchGame(instanceId, entry.accountId, prepareResult).catch(
+            (err) => {
+              // launchGame already emitted launch-failed
+              console.error(`Crash restart of ${instanceId} failed:`, err);
+            },
+          );
+        }, RESTART_DELAY_MS),
+      );
+    }
+    restartAttempts.set(instanceId, recent);
+  }
+
+  emitToRenderers("game-crashed", {
+    instanceId,
+    exitCode: code,
+    signal,
+    restartScheduled: restartAttempt !== null,
+    restartAttempt,
+  } satisfies GameCrashedEvent);
+  notifyGameCrash(
+    instanceId,
+    instance.name,
+    outputTail,
+    code,
+    signal,
+    restartAttempt !== null,
+  );
+}
+
+/** Lines of client output kept per launch for crash reports. */
+const OUTPUT_TAIL_LINES = 200;
+
+/** What we know about an instance's most recent launch, kept after exit. */
+export interface LastLaunch {
+  process: GameProcess;
+  javaPath: string;
+  instanceDir: string;
+  outputTail: string[];
+  exitCode: number | null;
+  signal: string | null;
+  exitedAt: string | null;
+}
+
+const lastLaunches = new Map<string, LastLaunch>();
+
+/** The most recent launch of an instance in this app session, if any. */
+export function getLastLaunch(instanceId: string): LastLaunch | undefined {
+  return lastLaunches.get(instanceId);
+}
+
+type GameOutputListener = (instanceId: string, line: string) => void;
+
+const outputListeners: GameOutputListener[] = [];
+
+/** Receive every line a game prints, for live log viewers. */
+export function onGameOutput(listener: GameOutputListener): void {
+  outputListeners.push(listener);
+}
+
+const runningListeners: (() => void)[] = [];
+
+/** Called whenever a game starts or exits, e.g. to refresh the tray menu. */
+export function onRunningGamesChanged(listener: () => void): void {
+  runningListeners.push(listener);
+}
+
+function notifyRunningGamesChanged(): void {
+  for (const listener of runningListeners) {
+    try {
+      listener();
+    } catch (err) {
+      console.error("Running games listener failed:", err);
+    }
+  }
+}
+
+/** Client log lines that only appear once the JVM got into the game proper. */
+const READY_MARKERS = [
+  /LWJGL Version/i,
+  /Setting user: /,
+  /Created: .*minecraft:textures/,
+];
+
+const DEFAULT_LAUNCHER_BRAND = "MCServerManager";
+
+/**
+ * `minecraft.launcher.brand` / `.version` sysprops. Forks can rebrand via
+ * `MC_LAUNCHER_BRAND`; the version tracks the app unless
+ * `MC_LAUNCHER_VERSION` overrides it.
+ */
+function launcherBrand(): { brand: string; version: string } {
+  return {
+    brand: process.env.MC_LAUNCHER_BRAND?.trim() || DEFAULT_LAUNCHER_BRAND,
+    version: process.env.MC_LAUNCHER_VERSION?.trim() || app.getVersion(),
+  };
+}
+
+/** Default seconds before warning that a launch looks stuck. */
+const DEFAULT_STALL_TIMEOUT_SEC = 120;
+
+/**
+ * `MC_LAUNCH_STALL_TIMEOUT_SEC` overrides the watchdog delay; 0 disables it.
+ */
+function stallTimeoutMs(): number {
+  const raw = process.env.MC_LAUNCH_STALL_TIMEOUT_SEC;
+  const sec = raw !== undefined ? Number(raw) : DEFAULT_STALL_TIMEOUT_SEC;
+  return Number.isFinite(sec) && sec > 0 ? sec * 1000 : 0;
+}
+
+/**
+ * Read the game's stdout/stderr line by line. Also keeps the pipes drained,
+ * which a chatty client otherwise fills until it blocks on write.
+ */
+function watchGameOutput(
+  child: ChildProcess,
+  onLine: (line: string) => void,
+): void {
+  for (const stream of [child.stdout, child.stderr]) {
+    if (stream) {
+      createInterface({ input: stream }).on("line", onLine);
+    }
+  }
+}
+
+function markReady(entry: RunningGame): void {
+  if (entry.process.ready) return;
+  entry.process.ready = true;
+  if (entry.stallTimer) {
+    clearTimeout(entry.stallTimer);
+    entry.stallTimer = null;
+  }
+  emitToRenderers("game-ready", { instanceId: entry.process.instanceId });
+}
+
+/**
+ * Warn (without killing) when a game is still alive but hasn't reached a
+ * readiness marker — typically a deadlock before the window opens.
+ */
+function startStallWatchdog(entry: RunningGame): void {
+  const timeoutMs = stallTimeoutMs();
+  if (!timeoutMs) return;
+
+  entry.stallTimer = setTimeout(() => {
+    entry.stallTimer = null;
+    const { child } = entry;
+    const exited = child.exitCode !== null || child.signalCode !== null;
+    if (entry.process.ready || entry.process.suspended || exited) return;
+    emitToRenderers("launch-stalled", {
+      instanceId: entry.process.instanceId,
+      elapsedMs: timeoutMs,
+    });
+  }, timeoutMs);
+}
+
 async function fetchJson<T>(url: string, init?: RequestInit): Promise<T> {
-  const res = await fetch(url, init);
+  let res: Response;
+  try {
+    res = await fetch(url, init);
+  } catch (err) {
+    if (init?.signal?.aborted) throw cancelledError();
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new LaunchError(
+      "backend_unreachable",
+      `Could not reach the backend at ${url}: ${reason}`,
+    );
+  }
   if (!res.ok) {
     const body = await res.text();
     throw new Error(
@@ -36,7 +329,13 @@ async function fetchJson<T>(url: string, init?: RequestInit): Promise<T> {
   return res.json() as Promise<T>;
 }
 
-async function resolveJavaPath(instance: LauncherInstance): Promise<string> {
+/**
+ * The Java binary a launch of `instance` would use: its custom path, else a
+ * detected installation of the required version.
+ */
+export async function resolveJavaPath(
+  instance: LauncherInstance,
+): Promise<string> {
   if (instance.javaPath) {
     return instance.javaPath;
   }
@@ -53,77 +352,713 @@ async function resolveJavaPath(instance: LauncherInstance): Promise<string> {
     return matching.path;
   }
 
-  throw new Error(
+  throw new LaunchError(
+    "java_not_found",
     `Java ${instance.javaVersion} not found. Please install it or specify a custom path.`,
   );
 }
 
-export async function launchGame(
+/**
+ * Base directory for per-launch natives. Defaults to `<launcher>/natives`;
+ * `MC_NATIVES_DIR` relocates it (e.g. off a slow or nearly-full data volume).
+ * Keep it on a local filesystem — each launch gets a fresh subdir that is
+ * deleted when the game exits, and that cleanup should be cheap.
+ */
+export function resolveNativesBase(launcherBase: string): string {
+  const override = process.env.MC_NATIVES_DIR;
+  if (!override) return path.join(launcherBase, "natives");
+
+  const base = path.resolve(override);
+  try {
+    mkdirSync(base, { recursive: true });
+    accessSync(base, fsConstants.W_OK);
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new Error(`MC_NATIVES_DIR "${base}" is not writable: ${reason}`);
+  }
+  return base;
+}
+
+/** Mirrors the backend's launcher dir, `<data dir>/launcher`. */
+function getLauncherDir(): string {
+  return path.join(getDataDir(), "launcher");
+}
+
+function preparedDir(): string {
+  return path.join(getLauncherDir(), "prepared");
+}
+
+function preparedFilePath(instanceId: string): string {
+  return path.join(preparedDir(), `${instanceId}.json`);
+}
+
+/** Keep the files a launch used, so they can be verified later. */
+function recordPrepareResult(
   instanceId: string,
-  accountId: string,
   prepareResult: PrepareResponse,
-): Promise<GameProcess> {
-  if (runningGames.some((g) => g.process.instanceId === instanceId)) {
-    throw new Error("Game is already running for this instance");
+): void {
+  const filePath = preparedFilePath(instanceId);
+  try {
+    mkdirSync(path.dirname(filePath), { recursive: true });
+    writeFileSync(filePath, JSON.stringify(prepareResult, null, 2), "utf-8");
+  } catch (err) {
+    console.warn(`Failed to record prepared files for ${instanceId}:`, err);
   }
+}
+
+/** The prepare result an instance was last launched with, if any. */
+export function getLastPrepareResult(
+  instanceId: string,
+): PrepareResponse | null {
+  try {
+    return JSON.parse(
+      readFileSync(preparedFilePath(instanceId), "utf-8"),
+    ) as PrepareResponse;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * The instance launched most recently, in this session or an earlier one:
+ * the one whose recorded prepare result is newest. Null if none has been
+ * launched (or its record is gone).
+ */
+export function getLastLaunchedInstanceId(): string | null {
+  const dir = preparedDir();
+  let newest: { id: string; mtimeMs: number } | null = null;
+  try {
+    for (const name of readdirSync(dir)) {
+      if (!name.endsWith(".json")) continue;
+      const { mtimeMs } = statSync(path.join(dir, name));
+      if (!newest || mtimeMs > newest.mtimeMs) {
+        newest = { id: name.slice(0, -".json".length), mtimeMs };
+      }
+    }
+  } catch {
+    return null;
+  }
+  return newest?.id ?? null;
+}
+
+/**
+ * Where launchGame puts an instance's files under `launcherBase`. Isolated
+ * instances get their own assets and natives under `isolated/<id>`, the
+ * same layout the backend's prepare step downloads into.
+ */
+function resolveInstancePaths(
+  launcherBase: string,
+  instance: Pick<LauncherInstance, "id" | "isolation">,
+): InstancePaths {
+  const gameDir = path.join(launcherBase, "instances", instance.id);
+  const filesBase =
+    instance.isolation === "isolated"
+      ? path.join(launcherBase, "isolated", instance.id)
+      : launcherBase;
+  return {
+    gameDir,
+    assetsDir: path.join(filesBase, "assets"),
+    nativesBase: resolveNativesBase(filesBase),
+    logsDir: path.join(gameDir, "logs"),
+  };
+}
+
+/**
+ * The directories launchGame would use for an instance, for "open folder"
+ * buttons and bug reports. Nothing is created except a MC_NATIVES_DIR
+ * override, which is checked for writability the same way a launch does.
+ */
+export async function getInstancePaths(
+  instanceId: string,
+): Promise<InstancePaths> {
+  const instance = await fetchJson<LauncherInstance>(
+    `${baseUrl()}/api/launcher/instances/${encodeURIComponent(instanceId)}`,
+  );
+  return resolveInstancePaths(getLauncherDir(), instance);
+}
+
+/** Argument flags whose following value is a credential. */
+const SECRET_ARG_FLAGS = new Set(["--accessToken"]);
+
+/**
+ * Copy of argv safe to show users or paste into bug reports.
+ * Redaction is by flag, so it stays correct if the token value appears
+ * elsewhere or the argument order changes.
+ */
+export function redactLaunchArgs(argv: string[]): string[] {
+  return argv.map((arg, i) =>
+    i > 0 && SECRET_ARG_FLAGS.has(argv[i - 1]) ? "[REDACTED]" : arg,
+  );
+}
+
+/** Share of total system memory above which ramMax gets a warning. */
+const RAM_WARNING_FRACTION = 0.75;
+
+/**
+ * Pre-flight checks for the Play button. Collects every problem instead of
+ * stopping at the first; an empty list means the instance should launch.
+ * Prepare (version/asset download) isn't run here since it can be slow.
+ */
+export async function validateInstance(
+  instanceId: string,
+  accountId?: string,
+): Promise<InstanceIssue[]> {
+  const issues: InstanceIssue[] = [];
 
   const instance = await fetchJson<LauncherInstance>(
     `${baseUrl()}/api/launcher/instances/${instanceId}`,
   );
 
+  try {
+    const javaPath = await resolveJavaPath(instance);
+    if (await isJavaTranslated(javaPath)) {
+      issues.push({
+        severity: "warning",
+        code: "java_rosetta",
+        message: `${javaPath} is an Intel (x64) Java and will run under Rosetta, which is much slower. Install an arm64 (aarch64) Java instead.`,
+      });
+    }
+  } catch (err) {
+    issues.push({
+      severity: "error",
+      code: "java_not_found",
+      message: err instanceof Error ? err.message : String(err),
+    });
+  }
+
+  const missingAgents = findMissingJavaAgents(instance);
+  if (missingAgents.length > 0) {
+    issues.push({
+      severity: "error",
+      code: "java_agent_missing",
+      message: missingJavaAgentsMessage(missingAgents),
+    });
+  }
+  const missingPathDirs = findMissingPathDirs(instance);
+  if (missingPathDirs.length > 0) {
+    issues.push({
+      severity: "error",
+      code: "path_dir_missing",
+      message: missingPathDirsMessage(missingPathDirs),
+    });
+  }
+
+  const totalGb = os.totalmem() / 1024 ** 3;
+  if (instance.ramMin > instance.ramMax) {
+    issues.push({
+      severity: "error",
+      code: "ram_min_exceeds_max",
+      message: `Minimum RAM (${instance.ramMin} GB) is higher than maximum (${instance.ramMax} GB)`,
+    });
+  }
+  if (instance.ramMax > totalGb) {
+    issues.push({
+      severity: "error",
+      code: "ram_exceeds_system",
+      message: `Maximum RAM (${instance.ramMax} GB) exceeds system memory (${totalGb.toFixed(1)} GB)`,
+    });
+  } else if (instance.ramMax > totalGb * RAM_WARNING_FRACTION) {
+    issues.push({
+      severity: "warning",
+      code: "ram_high",
+      message: `Maximum RAM (${instance.ramMax} GB) leaves little memory for the rest of the system (${totalGb.toFixed(1)} GB total)`,
+    });
+  }
+
+  if (accountId) {
+    const accounts = await fetchJson<LauncherAccount[]>(
+      `${baseUrl()}/api/launcher/accounts`,
+    );
+    const account = accounts.find((a) => a.id === accountId);
+    if (!account) {
+      issues.push({
+        severity: "error",
+        code: "account_not_found",
+        message: `Account ${accountId} not found`,
+      });
+    } else {
+      const tokenStatus = getAccountTokenStatus(account.uuid);
+      if (tokenStatus === "signed_out") {
+        issues.push({
+          severity: "error",
+          code: "account_signed_out",
+          message: `${account.username} is signed out. Sign in again to play.`,
+        });
+      } else if (tokenStatus === "refreshable") {
+        issues.push({
+          severity: "warning",
+          code: "token_expired",
+          message: `${account.username}'s session expired and will be refreshed on launch`,
+        });
+      }
+    }
+  }
+
+  return issues;
+}
+
+/**
+ * Minecraft versions for the instance editor, newest first. The backend
+ * caches Mojang's manifest, so this is cheap to call repeatedly.
+ */
+export async function getMcVersions(
+  includeSnapshots: boolean,
+): Promise<McVersionSummary[]> {
+  return fetchJson<McVersionSummary[]>(
+    `${baseUrl()}/api/launcher/versions/summary?includeSnapshots=${includeSnapshots}`,
+  );
+}
+
+/** How long listInstances reuses the backend's answer. */
+const INSTANCE_LIST_TTL_MS = 5_000;
+
+let instanceList: { summaries: InstanceSummary[]; expiresAt: number } | null =
+  null;
+
+/**
+ * Every instance, for tray menus and checks across all instances, in one
+ * backend request. Reused for a few seconds so rebuilding a menu on each
+ * open doesn't refetch; pass `refresh` right after creating or deleting one.
+ */
+export async function listInstances(
+  refresh = false,
+): Promise<InstanceSummary[]> {
+  if (!refresh && instanceList && instanceList.expiresAt > Date.now()) {
+    return instanceList.summaries;
+  }
+  const instances = await fetchJson<LauncherInstance[]>(
+    `${baseUrl()}/api/launcher/instances`,
+  );
+  const summaries = instances.map(
+    ({ id, name, mcVersion, loader, lastPlayed }) => ({
+      id,
+      name,
+      mcVersion,
+      loader,
+      lastPlayed,
+    }),
+  );
+  instanceList = { summaries, expiresAt: Date.now() + INSTANCE_LIST_TTL_MS };
+  return summaries;
+}
+
+/** The max-running-games setting; 0 means unlimited. */
+export async function getMaxConcurrentGames(): Promise<number> {
+  const settings = await fetchJson<Partial<AppSettings>>(
+    `${baseUrl()}/api/system/settings`,
+  );
+  return settings.maxConcurrentGames ?? 0;
+}
+
+export async function setMaxConcurrentGames(max: number): Promise<number> {
+  if (!Number.isInteger(max) || max < 0) {
+    throw new Error("Max concurrent games must be a whole number, 0 or more");
+  }
+  const settings = await fetchJson<AppSettings>(
+    `${baseUrl()}/api/system/settings`,
+    {
+      method: "PATCH",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ maxConcurrentGames: max }),
+    },
+  );
+  return settings.maxConcurrentGames;
+}
+
+/** Refuse a launch that would put more games up than the setting allows. */
+async function checkConcurrencyLimit(): Promise<void> {
+  const max = await getMaxConcurrentGames();
+  if (max <= 0) return;
+  // launchesInFlight includes the launch doing this check
+  const active = runningGames.length + launchesInFlight - 1;
+  if (active >= max) {
+    throw new LaunchError(
+      "too_many_running",
+      `${active} game${active === 1 ? " is" : "s are"} already running ` +
+        `(limit ${max}). Close one or raise the limit in Settings.`,
+    );
+  }
+}
+
+function cancelledError(): LaunchError {
+  return new LaunchError("cancelled", "Launch cancelled");
+}
+
+function throwIfCancelled(signal: AbortSignal): void {
+  if (signal.aborted) throw cancelledError();
+}
+
+/**
+ * Abort an instance's launch if it hasn't spawned the game yet; launchGame
+ * then rejects with a `cancelled` LaunchError. Returns false when there's
+ * nothing to cancel — once the game is up, use killGame instead.
+ */
+export function cancelLaunch(instanceId: string): boolean {
+  const controller = cancellableLaunches.get(instanceId);
+  if (!controller) return false;
+  controller.abort();
+  return true;
+}
+
+const lastLaunchTimings = new Map<string, LaunchTimings>();
+
+/** Stage durations of an instance's most recent launch attempt, if any. */
+export function getLastLaunchTimings(
+  instanceId: string,
+): LaunchTimings | null {
+  return lastLaunchTimings.get(instanceId) ?? null;
+}
+
+/**
+ * Stage stopwatch for one launch attempt. Entering a stage closes the open
+ * one; "running" only closes it, since it is where the launch ends. Closing
+ * is a no-op when no stage is open, so finishing after "running" keeps the
+ * last stage's duration.
+ */
+export function createLaunchTimer(
+  instanceId: string,
+  prepareMs: number | null,
+): {
+  enterStage: (stage: LaunchStage) => void;
+  finish: (succeeded: boolean) => LaunchTimings;
+} {
+  const timings: LaunchTimings = {
+    instanceId,
+    startedAt: new Date().toISOString(),
+    prepareMs,
+    stages: [],
+    totalMs: 0,
+    succeeded: false,
+  };
+  let open: { stage: LaunchStage; ms: number } | null = null;
+  let stageStart = performance.now();
+  const endStage = () => {
+    const now = performance.now();
+    if (open) open.ms = Math.round(now - stageStart);
+    open = null;
+    stageStart = now;
+  };
+  return {
+    enterStage(stage) {
+      endStage();
+      if (stage === "running") return;
+      open = { stage, ms: 0 };
+      timings.stages.push(open);
+    },
+    finish(succeeded) {
+      endStage();
+      timings.succeeded = succeeded;
+      timings.totalMs =
+        (timings.prepareMs ?? 0) +
+        timings.stages.reduce((sum, s) => sum + s.ms, 0);
+      return timings;
+    },
+  };
+}
+
+const SHARED_GAME_DIR_WARNING =
+  "Another copy of this instance is already running from the same game directory. " +
+  "Both copies write the same saves, options and logs — opening the same world " +
+  "in both can corrupt it.";
+
+/**
+ * Launch an instance, emitting `launch-progress` at each stage and
+ * `launch-failed` (with the stage it failed in) on error. Prepare runs in the
+ * renderer beforehand, so it isn't one of the stages here; the renderer can
+ * pass its duration as `prepareMs` for getLastLaunchTimings.
+ *
+ * A second launch of a running instance is refused unless `force` is set,
+ * and any launch is refused once the max-concurrent-games setting is hit.
+ */
+export async function launchGame(
+  instanceId: string,
+  accountId: string | null,
+  prepareResult: PrepareResponse,
+  options: { force?: boolean; prepareMs?: number } = {},
+): Promise<GameProcess> {
+  const alreadyRunning = runningGames.some(
+    (g) => g.process.instanceId === instanceId,
+  );
+  if (alreadyRunning && !options.force) {
+    throw new LaunchError(
+      "already_running",
+      "Game is already running for this instance. Launching again would share its saves " +
+        "and could corrupt a world opened in both; use force to launch anyway.",
+    );
+  }
+
+  if (cancellableLaunches.has(instanceId)) {
+    throw new LaunchError(
+      "already_running",
+      "This instance is already launching",
+    );
+  }
+  const controller = new AbortController();
+  cancellableLaunches.set(instanceId, controller);
+
+  let stage: LaunchStage = "fetching_instance";
+  const timer = createLaunchTimer(instanceId, options.prepareMs ?? null);
+  const progress = (next: LaunchStage) => {
+    stage = next;
+    timer.enterStage(next);
+    emitToRenderers("launch-progress", { instanceId, stage });
+  };
+  const recordTimings = (succeeded: boolean) => {
+    lastLaunchTimings.set(instanceId, timer.finish(succeeded));
+  };
+
+  // Counted before the first await so simultaneous launches see each other
+  launchesInFlight++;
+  try {
+    await checkConcurrencyLimit();
+    const gameProcess = await runLaunch(
+      instanceId,
+      accountId,
+      prepareResult,
+      progress,
+      controller.signal,
+    );
+    progress("running");
+    recordTimings(true);
+    if (alreadyRunning) {
+      console.warn(`Forced second launch of ${instanceId}`);
+      return { ...gameProcess, warning: SHARED_GAME_DIR_WARNING };
+    }
+    return gameProcess;
+  } catch (err) {
+    const launchErr =
+      err instanceof LaunchError
+        ? err
+        : new LaunchError(
+            "unknown",
+            err instanceof Error ? err.message : String(err),
+          );
+    launchErr.stage = stage;
+    recordTimings(false);
+    emitToRenderers("launch-failed", {
+      instanceId,
+      stage,
+      code: launchErr.code,
+      error: launchErr.message,
+    } satisfies LaunchFailedEvent);
+    throw launchErr;
+  } finally {
+    launchesInFlight--;
+    cancellableLaunches.delete(instanceId);
+  }
+}
+
+/** The requested account, or the user's default account when none is given. */
+async function resolveLaunchAccount(
+  accountId: string | null,
+): Promise<LauncherAccount> {
+  if (accountId === null) {
+    const fallback = await fetchJson<LauncherAccount | null>(
+      `${baseUrl()}/api/launcher/accounts/default`,
+    );
+    if (!fallback) {
+      throw new LaunchError(
+        "account_not_found",
+        "No account selected and no default account is set",
+      );
+    }
+    return fallback;
+  }
+
   const accounts = await fetchJson<LauncherAccount[]>(
     `${baseUrl()}/api/launcher/accounts`,
   );
   const account = accounts.find((a) => a.id === accountId);
   if (!account) {
-    throw new Error(`Account ${accountId} not found`);
+    throw new LaunchError(
+      "account_not_found",
+      `Account ${accountId} not found`,
+    );
   }
+  return account;
+}
 
-  const mcToken = await getMcAccessToken(account.uuid);
+/**
+ * Build the client's JVM args from the instance's RAM settings and the
+ * user's custom args, without duplicates.
+ *
+ * - A user `-Xms`/`-Xmx` overrides the one computed from ramMin/ramMax
+ *   (last one wins, matching the JVM's own behaviour for repeated flags).
+ * - `-cp`/`-classpath`/`--class-path` (and their value) are dropped: the
+ *   launcher supplies the classpath and a second one would replace it.
+ */
+export function normalizeJvmArgs(
+  ramMinGb: number,
+  ramMaxGb: number,
+  userArgs: string[],
+): string[] {
+  let xms = `-Xms${ramMinGb}G`;
+  let xmx = `-Xmx${ramMaxGb}G`;
+  const rest: string[] = [];
 
-  const javaPath = await resolveJavaPath(instance);
+  for (let i = 0; i < userArgs.length; i++) {
+    const arg = userArgs[i].trim();
+    if (!arg) continue;
 
-  const launcherBase = path.dirname(prepareResult.assetsDir);
+    if (arg.startsWith("-Xms")) {
+      xms = arg;
+    } else if (arg.startsWith("-Xmx")) {
+      xmx = arg;
+    } else if (
+      arg === "-cp" ||
+      arg === "-classpath" ||
+      arg === "--class-path"
+    ) {
+      i++; // skip the value too
+    } else if (arg.startsWith("--class-path=")) {
+      continue;
+    } else {
+      rest.push(arg);
+    }
+  }
 
-  const nativesDir = path.join(
-    launcherBase,
-    "natives",
-    `${instanceId}-${Date.now()}`,
+  return [xms, xmx, ...rest];
+}
+
+/**
+ * Placeholders the desktop launcher fills in inside an instance's JVM and
+ * game args, e.g. `-Xlog:gc:file={logsDir}/gc.log`. `ram` is the max heap
+ * in JVM size syntax (e.g. "4G").
+ */
+export type LaunchArgPlaceholder =
+  | "instanceDir"
+  | "assetsDir"
+  | "nativesDir"
+  | "logsDir"
+  | "ram";
+
+/**
+ * Replace `{name}` placeholders in launch args. Unknown names are left as
+ * written and listed once each in `unknown`, so the caller can warn.
+ */
+export function expandLaunchArgPlaceholders(
+  args: string[],
+  values: Record<LaunchArgPlaceholder, string>,
+): { args: string[]; unknown: string[] } {
+  const unknown = new Set<string>();
+  const expanded = args.map((arg) =>
+    arg.replace(/\{([A-Za-z]+)\}/g, (match, name: string) => {
+      if (Object.hasOwn(values, name)) {
+        return values[name as LaunchArgPlaceholder];
+      }
+      unknown.add(name);
+      return match;
+    }),
   );
-  mkdirSync(nativesDir, { recursive: true });
+  return { args: expanded, unknown: [...unknown] };
+}
 
-  const allClasspath = [...prepareResult.classpath, prepareResult.gameJarPath];
-  const separator = process.platform === "win32" ? ";" : ":";
-  const classpathStr = allClasspath.join(separator);
-
-  const instanceDir = path.join(launcherBase, "instances", instance.id);
-
-  const jvmArgs: string[] = [
-    `-Xms${instance.ramMin}G`,
-    `-Xmx${instance.ramMax}G`,
-    `-Djava.library.path=${nativesDir}`,
-    "-Dminecraft.launcher.brand=MCServerManager",
-    "-Dminecraft.launcher.version=1.0",
-    ...instance.jvmArgs,
-    "-cp",
-    classpathStr,
-  ];
+/** `-javaagent:<path>[=<options>]`, options omitted when empty. */
+export function formatJavaAgentArg(agent: JavaAgent): string {
+  return agent.options
+    ? `-javaagent:${agent.path}=${agent.options}`
+    : `-javaagent:${agent.path}`;
+}
 
-  const gameArgs: string[] = [
+/**
+ * `-javaagent` flags for an instance's agents. Placeholders are expanded in
+ * the options only: the path is used as written, so it's the same path
+ * findMissingJavaAgents checks before launch.
+ */
+export function buildJavaAgentArgs(
+  agents: JavaAgent[],
+  placeholders: Record<LaunchArgPlaceholder, string>,
+): { args: string[]; unknown: string[] } {
+  const options = expandLaunchArgPlaceholders(
+    agents.map((agent) => agent.options),
+    placeholders,
+  );
+  return {
+    args: agents.map((agent, i) =>
+      formatJavaAgentArg({ ...agent, options: options.args[i] }),
+    ),
+    unknown: options.unknown,
+  };
+}
+
+/**
+ * Put `dirs` in front of the PATH in `env`, for native libraries a mod
+ * loads by name. Returns the variable to set: Windows environments are
+ * case-insensitive and usually spell it `Path`, so the existing key is
+ * reused rather than adding a second one.
+ */
+export function prependToPath(
+  env: Record<string, string | undefined>,
+  dirs: string[],
+  platform: string,
+): { key: string; value: string } {
+  const windows = platform === "win32";
+  const key =
+    (windows && Object.keys(env).find((k) => k.toUpperCase() === "PATH")) ||
+    "PATH";
+  const existing = env[key];
+  const parts = existing ? [...dirs, existing] : dirs;
+  return { key, value: parts.join(windows ? ";" : ":") };
+}
+
+/**
+ * The JVM half of a launch command: memory flags, natives path, launcher
+ * brand and the instance's own JVM args — everything before `-cp`.
+ */
+function buildJvmArgs(
+  instance: LauncherInstance,
+  placeholders: Record<LaunchArgPlaceholder, string>,
+): { args: string[]; unknown: string[] } {
+  const custom = expandLaunchArgPlaceholders(instance.jvmArgs, placeholders);
+  // Agents go after the user's flags so those can't reorder them, and
+  // before -cp like every JVM option
+  const agents = buildJavaAgentArgs(instance.javaAgents, placeholders);
+  const brand = launcherBrand();
+  const [xms, xmx, ...userJvmArgs] = normalizeJvmArgs(
+    instance.ramMin,
+    instance.ramMax,
+    custom.args,
+  );
+  return {
+    args: [
+      xms,
+      xmx,
+      `-Djava.library.path=${placeholders.nativesDir}`,
+      `-Dminecraft.launcher.brand=${brand.brand}`,
+      `-Dminecraft.launcher.version=${brand.version}`,
+      ...userJvmArgs,
+      ...agents.args,
+    ],
+    unknown: [...new Set([...custom.unknown, ...agents.unknown])],
+  };
+}
+
+/**
+ * The game half of a launch command: identity, directories and resolution,
+ * then the instance's own game args with placeholders filled in.
+ */
+function buildGameArgs(
+  instance: LauncherInstance,
+  account: { username: string; uuid: string; token: string },
+  prepared: Pick<PrepareResponse, "assetsDir" | "assetIndex">,
+  placeholders: Record<LaunchArgPlaceholder, string>,
+): { args: string[]; unknown: string[] } {
+  const custom = expandLaunchArgPlaceholders(instance.gameArgs, placeholders);
+  const args = [
     "--username",
     account.username,
     "--version",
     instance.mcVersion,
     "--gameDir",
-    instanceDir,
+    placeholders.instanceDir,
     "--assetsDir",
-    prepareResult.assetsDir,
+    prepared.assetsDir,
     "--assetIndex",
-    prepareResult.assetIndex,
+    prepared.assetIndex,
     "--uuid",
     account.uuid,
     "--accessToken",
-    mcToken,
+    account.token,
     "--userType",
     "msa",
     "--versionType",
@@ -131,7 +1066,7 @@ export async function launchGame(
   ];
 
   if (instance.resolutionWidth != null && instance.resolutionHeight != null) {
-    gameArgs.push(
+    args.push(
       "--width",
       instance.resolutionWidth.toString(),
       "--height",
@@ -139,19 +1074,384 @@ export async function launchGame(
     );
   }
 
-  gameArgs.push(...instance.gameArgs);
+  args.push(...custom.args);
+  return { args, unknown: custom.unknown };
+}
+
+/**
+ * The game args a launch of the instance would pass, token redacted, for
+ * the instance editor. Uses the default account and the last launch's
+ * asset index when there is one; `unknownPlaceholders` lists `{names}` in
+ * the instance's game args that won't be substituted.
+ */
+export async function previewGameArgs(
+  instanceId: string,
+): Promise<GameArgsPreview> {
+  const instance = await fetchJson<LauncherInstance>(
+    `${baseUrl()}/api/launcher/instances/${encodeURIComponent(instanceId)}`,
+  );
+  const account = await fetchJson<LauncherAccount | null>(
+    `${baseUrl()}/api/launcher/accounts/default`,
+  );
+  const paths = resolveInstancePaths(getLauncherDir(), instance);
+  const prepared = getLastPrepareResult(instance.id);
+  const { args, unknown } = buildGameArgs(
+    instance,
+    {
+      username: account?.username ?? "Player",
+      uuid: account?.uuid ?? "00000000000000000000000000000000",
+      token: "",
+    },
+    {
+      assetsDir: prepared?.assetsDir ?? paths.assetsDir,
+      assetIndex: prepared?.assetIndex ?? instance.mcVersion,
+    },
+    {
+      instanceDir: paths.gameDir,
+      assetsDir: prepared?.assetsDir ?? paths.assetsDir,
+      nativesDir: paths.nativesBase,
+      logsDir: paths.logsDir,
+      ram: `${instance.ramMax}G`,
+    },
+  );
+  return { args: redactLaunchArgs(args), unknownPlaceholders: unknown };
+}
+
+/** Java agent jars configured for an instance that aren't files. */
+function findMissingJavaAgents(instance: LauncherInstance): string[] {
+  return instance.javaAgents
+    .map((agent) => agent.path)
+    .filter((agentPath) => {
+      try {
+        return !statSync(agentPath).isFile();
+      } catch {
+        return true;
+      }
+    });
+}
+
+/** PATH directories configured for an instance that aren't directories. */
+function findMissingPathDirs(instance: LauncherInstance): string[] {
+  return instance.pathPrepend.filter((dir) => {
+    try {
+      return !statSync(dir).isDirectory();
+    } catch {
+      return true;
+    }
+  });
+}
+
+function missingPathDirsMessage(missing: string[]): string {
+  return missing.length === 1
+    ? `PATH directory ${missing[0]} doesn't exist`
+    : `PATH directories don't exist: ${missing.join(", ")}`;
+}
+
+/**
+ * The game's environment: ours, plus the instance's PATH directories in
+ * front and any GPU selection variables.
+ */
+function gameEnv(
+  instance: LauncherInstance,
+  extra: Record<string, string> = {},
+): NodeJS.ProcessEnv {
+  const env: NodeJS.ProcessEnv = { ...process.env, ...extra };
+  if (instance.pathPrepend.length > 0) {
+    const { key, value } = prependToPath(
+      env,
+      instance.pathPrepend,
+      process.platform,
+    );
+    env[key] = value;
+  }
+  return env;
+}
+
+function missingJavaAgentsMessage(missing: string[]): string {
+  return missing.length === 1
+    ? `Java agent ${missing[0]} doesn't exist`
+    : `Java agents don't exist: ${missing.join(", ")}`;
+}
+
+/**
+ * The classpath a launch passes to Java: the prepared libraries, then the
+ * game jar, each path once. A jar listed twice (say by both a loader and
+ * vanilla) would otherwise appear twice, which muddles working out which
+ * copy a class was loaded from.
+ */
+export function buildLaunchClasspath(
+  prepared: Pick<PrepareResponse, "classpath" | "gameJarPath">,
+): string[] {
+  return [...new Set([...prepared.classpath, prepared.gameJarPath])];
+}
+
+/** How often runPrepare checks on the backend's prepare job. */
+const PREPARE_POLL_MS = 500;
+
+/** Longest runPrepare waits; a first prepare downloads a whole version. */
+const PREPARE_TIMEOUT_MS = 15 * 60_000;
+
+/** The backend's 409 for a prepare already in progress */
+interface ConflictBody {
+  details?: { jobId?: unknown };
+}
+
+/**
+ * Start a prepare job for the instance, or pick up the one already running
+ * (say from the launch button). `started` is false for a picked-up job.
+ */
+async function startOrJoinPrepare(
+  instanceId: string,
+): Promise<{ job: PrepareJob; started: boolean }> {
+  const url = `${baseUrl()}/api/launcher/prepare/${encodeURIComponent(instanceId)}`;
+  let res: Response;
+  try {
+    res = await fetch(url, { method: "POST" });
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new LaunchError(
+      "backend_unreachable",
+      `Could not reach the backend at ${url}: ${reason}`,
+    );
+  }
+  if (res.status === 409) {
+    const body = (await res.json().catch(() => null)) as ConflictBody | null;
+    const jobId = body?.details?.jobId;
+    if (typeof jobId === "string") {
+      const job = await fetchJson<PrepareJob>(
+        `${baseUrl()}/api/launcher/prepare/jobs/${jobId}`,
+      );
+      return { job, started: false };
+    }
+    throw new Error(`POST ${url} failed (409): ${JSON.stringify(body)}`);
+  }
+  if (!res.ok) {
+    throw new Error(`POST ${url} failed (${res.status}): ${await res.text()}`);
+  }
+  return { job: (await res.json()) as PrepareJob, started: true };
+}
+
+/**
+ * Run the backend's prepare step for an instance and wait for the result,
+ * as the launch button does before launching. Joins a prepare that's
+ * already running, and gives up after PREPARE_TIMEOUT_MS, cancelling the
+ * job if it was ours.
+ */
+async function runPrepare(instanceId: string): Promise<PrepareResponse> {
+  const deadline = Date.now() + PREPARE_TIMEOUT_MS;
+  const start = await startOrJoinPrepare(instanceId);
+  let job = start.job;
+  while (job.phase !== "completed" && job.phase !== "failed") {
+    if (Date.now() >= deadline) {
+      if (start.started) {
+        await fetch(`${baseUrl()}/api/launcher/prepare/jobs/${job.id}`, {
+          method: "DELETE",
+        }).catch(() => {});
+      }
+      throw new Error(
+        `Prepare timed out after ${PREPARE_TIMEOUT_MS / 60_000} minutes`,
+      );
+    }
+    await new Promise((resolve) => setTimeout(resolve, PREPARE_POLL_MS));
+    job = await fetchJson<PrepareJob>(
+      `${baseUrl()}/api/launcher/prepare/jobs/${job.id}`,
+    );
+  }
+  if (!job.result) {
+    throw new Error(`Prepare failed: ${job.error ?? "unknown error"}`);
+  }
+  return job.result;
+}
+
+/**
+ * The classpath a launch of the instance would use, in order, for chasing
+ * ClassNotFoundException and NoClassDefFoundError without launching. Runs
+ * prepare first (downloading anything missing), so a jar still flagged
+ * missing really is absent from what the game would get.
+ */
+export async function getEffectiveClasspath(
+  instanceId: string,
+): Promise<ClasspathEntry[]> {
+  const prepared = await runPrepare(instanceId);
+  return buildLaunchClasspath(prepared).map((jar) => ({
+    path: jar,
+    exists: existsSync(jar),
+  }));
+}
+
+/** How long a JVM gets to print its settings and exit. */
+const TEST_JAVA_TIMEOUT_MS = 30_000;
+
+/**
+ * Start the instance's Java with its exact JVM args (heap, natives path,
+ * custom flags) plus `-XshowSettings:vm -version`, without the game. Tells
+ * "the JVM won't start with these flags" apart from "Minecraft crashed".
+ * Resolves with the command and everything it printed; rejects with the
+ * same output if the JVM exits non-zero.
+ */
+export async function testJavaLaunch(instanceId: string): Promise<string> {
+  const instance = await fetchJson<LauncherInstance>(
+    `${baseUrl()}/api/launcher/instances/${encodeURIComponent(instanceId)}`,
+  );
+  const javaPath = await resolveJavaPath(instance);
+  const missingAgents = findMissingJavaAgents(instance);
+  if (missingAgents.length > 0) {
+    throw new Error(missingJavaAgentsMessage(missingAgents));
+  }
+  const missingPathDirs = findMissingPathDirs(instance);
+  if (missingPathDirs.length > 0) {
+    throw new Error(missingPathDirsMessage(missingPathDirs));
+  }
+  const paths = resolveInstancePaths(getLauncherDir(), instance);
+  const { args } = buildJvmArgs(instance, {
+    instanceDir: paths.gameDir,
+    assetsDir: paths.assetsDir,
+    // Only used as a path here; the game's natives aren't needed
+    nativesDir: paths.nativesBase,
+    logsDir: paths.logsDir,
+    ram: `${instance.ramMax}G`,
+  });
+  const argv = [...args, "-XshowSettings:vm", "-version"];
+  const header = `$ ${[javaPath, ...argv].join(" ")}\n`;
+
+  try {
+    const { stdout, stderr } = await execFileAsync(javaPath, argv, {
+      cwd: existsSync(paths.gameDir) ? paths.gameDir : undefined,
+      env: gameEnv(instance),
+      timeout: TEST_JAVA_TIMEOUT_MS,
+      windowsHide: true,
+    });
+    return header + stdout + stderr;
+  } catch (err) {
+    const failed = err as {
+      stdout?: string;
+      stderr?: string;
+      code?: number | string;
+      killed?: boolean;
+    };
+    const reason = failed.killed
+      ? `Java didn't exit within ${TEST_JAVA_TIMEOUT_MS / 1000}s`
+      : `Java exited with code ${failed.code ?? "unknown"}`;
+    throw new Error(
+      `${reason}\n${header}${failed.stdout ?? ""}${failed.stderr ?? ""}`,
+    );
+  }
+}
+
+async function runLaunch(
+  instanceId: string,
+  accountId: string | null,
+  prepareResult: PrepareResponse,
+  progress: (stage: LaunchStage) => void,
+  signal: AbortSignal,
+): Promise<GameProcess> {
+  progress("fetching_instance");
+  const instance = await fetchJson<LauncherInstance>(
+    `${baseUrl()}/api/launcher/instances/${instanceId}`,
+    { signal },
+  );
+
+  const account = await resolveLaunchAccount(accountId);
+  throwIfCancelled(signal);
+
+  let mcToken: string;
+  try {
+    mcToken = await getMcAccessToken(account.uuid);
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new LaunchError(
+      "token_expired",
+      `${account.username}'s session could not be refreshed; sign in again. (${reason})`,
+    );
+  }
+
+  throwIfCancelled(signal);
+
+  progress("resolving_java");
+  const javaPath = await resolveJavaPath(instance);
+  const missingAgents = findMissingJavaAgents(instance);
+  if (missingAgents.length > 0) {
+    throw new LaunchError(
+      "java_agent_missing",
+      missingJavaAgentsMessage(missingAgents),
+    );
+  }
+  const missingPathDirs = findMissingPathDirs(instance);
+  if (missingPathDirs.length > 0) {
+    throw new LaunchError(
+      "path_dir_missing",
+      missingPathDirsMessage(missingPathDirs),
+    );
+  }
+  throwIfCancelled(signal);
+
+  progress("creating_natives");
+  const paths = resolveInstancePaths(getLauncherDir(), instance);
+
+  const nativesDir = path.join(
+    paths.nativesBase,
+    `${instanceId}-${Date.now()}`,
+  );
+  mkdirSync(nativesDir, { recursive: true });
+  if (existsSync(prepareResult.nativesDir)) {
+    cpSync(prepareResult.nativesDir, nativesDir, { recursive: true });
+  }
+
+  const separator = process.platform === "win32" ? ";" : ":";
+  const classpathStr = buildLaunchClasspath(prepareResult).join(separator);
+
+  const instanceDir = paths.gameDir;
+
+  const placeholders = {
+    instanceDir,
+    assetsDir: prepareResult.assetsDir,
+    nativesDir,
+    logsDir: paths.logsDir,
+    ram: `${instance.ramMax}G`,
+  };
+  const customJvmArgs = buildJvmArgs(instance, placeholders);
+  const gameArgs = buildGameArgs(
+    instance,
+    { username: account.username, uuid: account.uuid, token: mcToken },
+    prepareResult,
+    placeholders,
+  );
+  const unknownPlaceholders = new Set([
+    ...customJvmArgs.unknown,
+    ...gameArgs.unknown,
+  ]);
+  if (unknownPlaceholders.size > 0) {
+    console.warn(
+      `Unknown placeholders in ${instanceId} launch args, left as-is: ` +
+        [...unknownPlaceholders].map((name) => `{${name}}`).join(", "),
+    );
+  }
+
+  const jvmArgs = [...customJvmArgs.args, "-cp", classpathStr];
+
+  const args = [...jvmArgs, prepareResult.mainClass, ...gameArgs.args];
 
-  const args = [...jvmArgs, prepareResult.mainClass, ...gameArgs];
+  const gpuEnv = await applyGpuPreference(instance.gpuPreference, javaPath);
+  if (signal.aborted) {
+    rmSync(nativesDir, { recursive: true, force: true });
+    throw cancelledError();
+  }
+  // Nothing below awaits, so a cancel can't land after the spawn
 
+  progress("spawning");
   const child = spawn(javaPath, args, {
     cwd: instanceDir,
+    env: gameEnv(instance, gpuEnv),
     detached: false,
     stdio: ["pipe", "pipe", "pipe"],
   });
 
   const pid = child.pid;
   if (pid == null) {
-    throw new Error("Failed to spawn Minecraft process: no PID returned");
+    throw new LaunchError(
+      "spawn_failed",
+      "Failed to spawn Minecraft process: no PID returned",
+    );
   }
 
   const startedAt = new Date().toISOString();
@@ -159,39 +1459,303 @@ export async function launchGame(
     instanceId,
     pid,
     startedAt,
+    command: redactLaunchArgs([javaPath, ...args]),
+    ready: false,
+    suspended: false,
   };
 
-  const entry: RunningGame = { process: gameProcess, child };
+  const entry: RunningGame = {
+    process: gameProcess,
+    child,
+    accountId: account.id,
+    username: account.username,
+    javaPath,
+    nativesDir,
+    stallTimer: null,
+    stopRequested: false,
+    savingSince: null,
+  };
   runningGames.push(entry);
+  notifyRunningGamesChanged();
+
+  const lastLaunch: LastLaunch = {
+    process: gameProcess,
+    javaPath,
+    instanceDir,
+    outputTail: [],
+    exitCode: null,
+    signal: null,
+    exitedAt: null,
+  };
+  lastLaunches.set(instanceId, lastLaunch);
+  recordPrepareResult(instanceId, prepareResult);
+  child.on("exit", (code, signal) => {
+    lastLaunch.exitCode = code;
+    lastLaunch.signal = signal;
+    lastLaunch.exitedAt = new Date().toISOString();
+    if (code !== 0 && !entry.stopRequested) {
+      handleGameCrash(
+        entry,
+        instance,
+        lastLaunch.outputTail,
+        prepareResult,
+        code,
+        signal,
+      );
+    }
+  });
+
+  watchGameOutput(child, (line) => {
+    lastLaunch.outputTail.push(line);
+    if (lastLaunch.outputTail.length > OUTPUT_TAIL_LINES) {
+      lastLaunch.outputTail.shift();
+    }
+    if (!entry.process.ready && READY_MARKERS.some((re) => re.test(line))) {
+      markReady(entry);
+    }
+    trackWorldSave(entry, line);
+    for (const listener of outputListeners) listener(instanceId, line);
+  });
+  startStallWatchdog(entry);
 
   const removeFromRunning = () => {
-    const idx = runningGames.findIndex(
-      (g) => g.process.instanceId === instanceId,
-    );
+    if (entry.stallTimer) {
+      clearTimeout(entry.stallTimer);
+      entry.stallTimer = null;
+    }
+    // By identity: a forced second launch shares the instance id
+    const idx = runningGames.indexOf(entry);
     if (idx !== -1) {
       runningGames.splice(idx, 1);
+      notifyRunningGamesChanged();
+    }
+    try {
+      rmSync(nativesDir, { recursive: true, force: true });
+    } catch (err) {
+      console.error(`Failed to clean up natives dir ${nativesDir}:`, err);
     }
   };
 
   child.on("exit", removeFromRunning);
   child.on("error", removeFromRunning);
 
-  return gameProcess;
+  return { ...gameProcess };
 }
 
 export function getRunningGames(): GameProcess[] {
   return runningGames.map((g) => ({ ...g.process }));
 }
 
-export async function killGame(instanceId: string): Promise<void> {
-  const idx = runningGames.findIndex(
+/** Files and dirs in use by running games, which cache cleanup must not touch. */
+export function getActiveGamePaths(): {
+  nativesDirs: string[];
+  javaPaths: string[];
+} {
+  return {
+    nativesDirs: runningGames.map((g) => g.nativesDir),
+    javaPaths: runningGames.map((g) => g.javaPath),
+  };
+}
+
+/** Resident set size of a process in bytes, or null if it can't be read. */
+async function getProcessMemoryBytes(pid: number): Promise<number | null> {
+  try {
+    if (process.platform === "win32") {
+      // "java.exe","1234","Console","1","1,234,567 K"
+      const { stdout } = await execFileAsync(
+        "tasklist",
+        ["/FI", `PID eq ${pid}`, "/FO", "CSV", "/NH"],
+        { timeout: 5_000 },
+      );
+      const match = stdout.match(/"([\d.,\s]+) K"/);
+      if (!match) return null;
+      return parseInt(match[1].replace(/\D/g, ""), 10) * 1024;
+    }
+
+    const { stdout } = await execFileAsync(
+      "ps",
+      ["-o", "rss=", "-p", String(pid)],
+      { timeout: 5_000 },
+    );
+    const kb = parseInt(stdout.trim(), 10);
+    return Number.isFinite(kb) ? kb * 1024 : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Running games merged with account, uptime, readiness and memory usage —
+ * the one call a dashboard needs. getRunningGames stays for existing callers.
+ */
+export async function getRunningGamesDetailed(): Promise<RunningGameDetails[]> {
+  const now = Date.now();
+  return Promise.all(
+    runningGames.map(async (g) => ({
+      ...g.process,
+      accountId: g.accountId,
+      username: g.username,
+      uptimeMs: now - new Date(g.process.startedAt).getTime(),
+      memoryBytes: await getProcessMemoryBytes(g.process.pid),
+    })),
+  );
+}
+
+/** Kill every running client. Returns how many were killed. */
+export function killAllGames(): number {
+  for (const instanceId of [...pendingRestarts.keys()]) {
+    cancelPendingRestart(instanceId);
+  }
+  // Removed up front so the list is empty as soon as this returns; the exit
+  // handlers then find nothing to remove, so listeners are notified here
+  const entries = runningGames.splice(0);
+  for (const entry of entries) {
+    entry.stopRequested = true;
+    entry.child.kill("SIGKILL");
+  }
+  if (entries.length > 0) notifyRunningGamesChanged();
+  return entries.length;
+}
+
+/** Default seconds a client gets to exit after SIGTERM before SIGKILL. */
+const DEFAULT_KILL_TIMEOUT_SECS = 10;
+
+/**
+ * SIGTERM one client, SIGKILL it if it hasn't exited after `timeoutSecs`.
+ * Resolves once the process is gone; the exit handler removes it from
+ * `runningGames`.
+ */
+function stopGameProcess(
+  entry: RunningGame,
+  timeoutSecs: number,
+): Promise<void> {
+  const { child } = entry;
+  entry.stopRequested = true;
+  if (child.exitCode !== null || child.signalCode !== null) {
+    return Promise.resolve();
+  }
+
+  return new Promise((resolve) => {
+    const timer = setTimeout(() => {
+      child.kill("SIGKILL");
+      emitToRenderers("game-force-killed", {
+        instanceId: entry.process.instanceId,
+        pid: entry.process.pid,
+        timeoutSecs,
+      } satisfies GameForceKilledEvent);
+    }, timeoutSecs * 1000);
+    child.once("exit", () => {
+      clearTimeout(timer);
+      resolve();
+    });
+    child.kill("SIGTERM");
+    // A stopped JVM can't run its shutdown hooks until it's continued
+    if (entry.process.suspended) child.kill("SIGCONT");
+  });
+}
+
+/** Integrated-server log lines bracketing a world save. */
+const SAVE_START_MARKERS = [
+  /Saving and pausing game/,
+  /Saving chunks for level/,
+];
+const SAVE_END_MARKERS = [/All (?:chunks|dimensions) are saved/];
+
+/** A save that never logged its end is assumed finished after this long. */
+const SAVE_MAX_DURATION_MS = 60_000;
+
+function trackWorldSave(entry: RunningGame, line: string): void {
+  if (SAVE_END_MARKERS.some((re) => re.test(line))) {
+    entry.savingSince = null;
+  } else if (SAVE_START_MARKERS.some((re) => re.test(line))) {
+    entry.savingSince ??= Date.now();
+  }
+}
+
+function isSavingWorld(entry: RunningGame): boolean {
+  return (
+    entry.savingSince !== null &&
+    Date.now() - entry.savingSince < SAVE_MAX_DURATION_MS
+  );
+}
+
+function runningEntriesOf(instanceId: string): RunningGame[] {
+  const entries = runningGames.filter(
     (g) => g.process.instanceId === instanceId,
   );
-  if (idx === -1) {
-    throw new Error("No running game found for this instance");
+  if (entries.length === 0) {
+    throw new LaunchError(
+      "not_running",
+      "No running game found for this instance",
+    );
+  }
+  return entries;
+}
+
+function assertCanSuspend(): void {
+  if (process.platform === "win32") {
+    // Would need NtSuspendProcess (a native addon); there's no signal for it
+    throw new LaunchError(
+      "unsupported",
+      "Suspending games isn't supported on Windows",
+    );
   }
+}
+
+/**
+ * Freeze every running copy of an instance with SIGSTOP. Refused while the
+ * client is saving its world (as far as the log shows), since a save cut
+ * off by a later kill can corrupt chunks. Unix only.
+ */
+export function suspendGame(instanceId: string): void {
+  assertCanSuspend();
+  const entries = runningEntriesOf(instanceId);
+  if (entries.some(isSavingWorld)) {
+    throw new LaunchError(
+      "saving",
+      "The game is saving its world; try again in a few seconds",
+    );
+  }
+  for (const entry of entries) {
+    if (entry.process.suspended) continue;
+    if (entry.child.kill("SIGSTOP")) entry.process.suspended = true;
+  }
+}
 
-  const entry = runningGames[idx];
-  entry.child.kill("SIGKILL");
-  runningGames.splice(idx, 1);
+/** Continue a game frozen by suspendGame. */
+export function resumeGame(instanceId: string): void {
+  assertCanSuspend();
+  for (const entry of runningEntriesOf(instanceId)) {
+    if (!entry.process.suspended) continue;
+    if (entry.child.kill("SIGCONT")) entry.process.suspended = false;
+  }
+}
+
+/**
+ * Stop every running copy of an instance: SIGTERM, then SIGKILL after
+ * `timeoutSecs` (emitting `game-force-killed`) if a copy is still alive.
+ */
+export async function killGame(
+  instanceId: string,
+  timeoutSecs = DEFAULT_KILL_TIMEOUT_SECS,
+): Promise<void> {
+  // Stopping a crashed game cancels its pending restart
+  const hadPendingRestart = pendingRestarts.has(instanceId);
+  cancelPendingRestart(instanceId);
+  restartAttempts.delete(instanceId);
+
+  const entries = runningGames.filter(
+    (g) => g.process.instanceId === instanceId,
+  );
+  if (entries.length === 0) {
+    if (hadPendingRestart) return;
+    throw new LaunchError(
+      "not_running",
+      "No running game found for this instance",
+    );
+  }
+
+  await Promise.all(
+    entries.map((entry) => stopGameProcess(entry, timeoutSecs)),
+  );
 }
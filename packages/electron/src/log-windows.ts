@@ -0,0 +1,82 @@
+/**
+ * Pop-out log windows, one per instance, showing a game's output live.
+ * Windows only receive their own instance's `game-log` events, and closing
+ * one just drops it from the fan-out — the game never notices.
+ * @module log-windows
+ */
+
+import { BrowserWindow } from "electron";
+import type { GameLogEvent } from "@mc-server-manager/shared";
+import { getLastLaunch, onGameOutput } from "./launcher.js";
+import { rendererUrl, rendererWebPreferences } from "./windows.js";
+
+/** Lines are batched so a chatty game doesn't send one IPC message each. */
+const FLUSH_INTERVAL_MS = 100;
+
+const logWindows = new Map<string, BrowserWindow>();
+const pendingLines = new Map<string, string[]>();
+let flushTimer: NodeJS.Timeout | null = null;
+let subscribed = false;
+
+function flush(): void {
+  flushTimer = null;
+  for (const [instanceId, lines] of pendingLines) {
+    const win = logWindows.get(instanceId);
+    if (win && !win.isDestroyed()) {
+      win.webContents.send("game-log", {
+        instanceId,
+        lines,
+      } satisfies GameLogEvent);
+    }
+  }
+  pendingLines.clear();
+}
+
+function queueLine(instanceId: string, line: string): void {
+  if (!logWindows.has(instanceId)) return;
+  const lines = pendingLines.get(instanceId);
+  if (lines) lines.push(line);
+  else pendingLines.set(instanceId, [line]);
+  flushTimer ??= setTimeout(flush, FLUSH_INTERVAL_MS);
+}
+
+/**
+ * Open (or focus) the log window for an instance. Any number of instances
+ * can have one open at once.
+ */
+export async function openLogWindow(instanceId: string): Promise<void> {
+  const existing = logWindows.get(instanceId);
+  if (existing && !existing.isDestroyed()) {
+    if (existing.isMinimized()) existing.restore();
+    existing.focus();
+    return;
+  }
+
+  if (!subscribed) {
+    onGameOutput(queueLine);
+    subscribed = true;
+  }
+
+  const win = new BrowserWindow({
+    width: 900,
+    height: 600,
+    minWidth: 400,
+    minHeight: 300,
+    title: "Game log",
+    webPreferences: rendererWebPreferences(),
+  });
+  logWindows.set(instanceId, win);
+  win.on("closed", () => {
+    if (logWindows.get(instanceId) === win) logWindows.delete(instanceId);
+    pendingLines.delete(instanceId);
+  });
+
+  await win.loadURL(
+    `${rendererUrl()}/log-window/${encodeURIComponent(instanceId)}`,
+  );
+}
+
+/** What the game has printed so far, for a log window's first paint. */
+export function getGameOutput(instanceId: string): string[] {
+  return [...(getLastLaunch(instanceId)?.outputTail ?? [])];
+}
@@ -0,0 +1,74 @@
+import { mkdtempSync, realpathSync, rmSync, writeFileSync } from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { pruneGameLogs, tailLog } from "./logs.js";
+
+vi.mock("electron", () => ({}));
+
+describe("tailLog", () => {
+  let logsDir: string;
+  let logFile: string;
+
+  beforeEach(() => {
+    logsDir = realpathSync(mkdtempSync(path.join(os.tmpdir(), "mc-logs-")));
+    vi.stubEnv("LOGS_DIR", logsDir);
+    logFile = path.join(logsDir, "game.log");
+  });
+
+  afterEach(() => {
+    vi.unstubAllEnvs();
+    rmSync(logsDir, { recursive: true, force: true });
+  });
+
+  it("returns what was appended since the offset", async () => {
+    writeFileSync(logFile, "first\nsecond\n");
+    const chunk = await tailLog(logFile, 6);
+    expect(chunk).toMatchObject({
+      data: "second\n",
+      nextOffset: 13,
+      reset: false,
+    });
+  });
+
+  it.each([-1, Number.NaN, 2.5])(
+    "restarts from the top for an offset of %s",
+    async (fromOffset) => {
+      writeFileSync(logFile, "hello\n");
+      const chunk = await tailLog(logFile, fromOffset);
+      expect(chunk).toMatchObject({
+        data: "hello\n",
+        nextOffset: 6,
+        reset: true,
+      });
+    },
+  );
+
+  it("holds back a character cut off at the end of the file", async () => {
+    const euro = Buffer.from("€", "utf-8");
+    writeFileSync(
+      logFile,
+      Buffer.concat([Buffer.from("ok "), euro.subarray(0, 2)]),
+    );
+    const partial = await tailLog(logFile, 0);
+    expect(partial).toMatchObject({ data: "ok ", nextOffset: 3 });
+
+    writeFileSync(logFile, Buffer.concat([Buffer.from("ok "), euro]));
+    const rest = await tailLog(logFile, partial.nextOffset, partial.fileId);
+    expect(rest).toMatchObject({ data: "€", nextOffset: 6, reset: false });
+  });
+});
+
+describe("pruneGameLogs", () => {
+  it.each([
+    [-1, 30],
+    [20, -1],
+    [Number.NaN, 30],
+    [20, Number.NaN],
+    [2.5, 30],
+    [20, Number.POSITIVE_INFINITY],
+  ])("rejects keepLatest=%s, maxAgeDays=%s", async (keepLatest, maxAgeDays) => {
+    await expect(pruneGameLogs(keepLatest, maxAgeDays)).rejects.toThrow(
+      /Invalid (keepLatest|maxAgeDays)/,
+    );
+  });
+});
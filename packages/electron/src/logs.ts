@@ -0,0 +1,345 @@
+/**
+ * Opening and tailing log files. Paths from the renderer are confined to
+ * log locations so these can't be used to read arbitrary files.
+ * @module logs
+ */
+
+import { shell } from "electron";
+import {
+  closeSync,
+  openSync,
+  readdirSync,
+  readSync,
+  realpathSync,
+  rmSync,
+  statSync,
+} from "node:fs";
+import path from "node:path";
+import type {
+  BackendStartupLog,
+  LogChunk,
+} from "@mc-server-manager/shared";
+import { getDataDir } from "./data-dir.js";
+import { getRunningGames } from "./launcher.js";
+import { getLastStartupFailure } from "./startup-log.js";
+
+/** Mirrors the backend's `config.logsDir`. */
+export function getLogsDir(): string {
+  return process.env.LOGS_DIR ?? path.join(getDataDir(), "logs");
+}
+
+/** Launcher instance dirs, each with a `logs` folder written by the game. */
+function getInstancesDir(): string {
+  return path.join(getDataDir(), "launcher", "instances");
+}
+
+/** Files the game is still writing to while it runs. */
+const LIVE_LOG_FILES = new Set(["latest.log", "debug.log"]);
+
+/** Defaults for the startup prune. */
+const DEFAULT_KEEP_LATEST_LOGS = 20;
+const DEFAULT_MAX_LOG_AGE_DAYS = 30;
+
+/** How much of the backend log `getBackendStartupLog` returns. */
+const MAX_STARTUP_LOG_BYTES = 64 * 1024;
+
+/** Largest chunk returned by one `tailLog` call. */
+const MAX_TAIL_CHUNK_BYTES = 256 * 1024;
+
+function isNonNegativeInteger(value: unknown): value is number {
+  return Number.isInteger(value) && (value as number) >= 0;
+}
+
+function isInside(root: string, target: string): boolean {
+  const relative = path.relative(root, target);
+  return !!relative && !relative.startsWith("..") && !path.isAbsolute(relative);
+}
+
+/**
+ * Resolve a renderer-supplied path, following symlinks, and require it to
+ * be in the app logs dir, or a `logs` folder of a managed server or
+ * launcher instance.
+ */
+function resolveAllowedLogPath(filePath: string): string {
+  let resolved: string;
+  try {
+    resolved = realpathSync(path.resolve(getLogsDir(), filePath));
+  } catch {
+    throw new Error(`Log file not found: ${filePath}`);
+  }
+
+  const logsDir = realpathSync(getLogsDir());
+  if (isInside(logsDir, resolved)) return resolved;
+
+  const dataDir = getDataDir();
+  const logRoots = [
+    process.env.SERVERS_DIR ?? path.join(dataDir, "servers"),
+    path.join(dataDir, "launcher", "instances"),
+  ];
+  for (const root of logRoots) {
+    let realRoot: string;
+    try {
+      realRoot = realpathSync(root);
+    } catch {
+      continue;
+    }
+    if (
+      isInside(realRoot, resolved) &&
+      path.relative(realRoot, resolved).split(path.sep).includes("logs")
+    ) {
+      return resolved;
+    }
+  }
+
+  throw new Error("Path is outside the log directories");
+}
+
+/**
+ * Bytes of `buffer` up to the last complete UTF-8 character, dropping a
+ * trailing multi-byte sequence that was cut short.
+ */
+function completeUtf8Length(buffer: Buffer): number {
+  // A sequence is at most 4 bytes, so its lead byte is within the last 4
+  for (let i = buffer.length - 1; i >= Math.max(0, buffer.length - 4); i--) {
+    const byte = buffer[i];
+    if ((byte & 0xc0) === 0x80) continue;
+    const needed = byte >= 0xf0 ? 4 : byte >= 0xe0 ? 3 : byte >= 0xc0 ? 2 : 1;
+    return i + needed > buffer.length ? i : buffer.length;
+  }
+  // Only continuation bytes: not valid UTF-8, so nothing to wait for
+  return buffer.length;
+}
+
+/**
+ * Read what was appended to a log since `fromOffset`, for polling viewers.
+ * Without an offset, returns the last chunk of the file. A shrunken file
+ * (truncation), a different `fileId` (rotation) or an offset that isn't a
+ * non-negative integer restarts from the top. A chunk never ends partway
+ * through a UTF-8 character; `nextOffset` points at its first byte instead.
+ */
+export async function tailLog(
+  filePath: string,
+  fromOffset?: number,
+  fileId?: string,
+): Promise<LogChunk> {
+  const resolved = resolveAllowedLogPath(filePath);
+  const stats = statSync(resolved);
+  const currentId = `${stats.dev}:${stats.ino}`;
+
+  let start: number;
+  let reset = false;
+  if (fromOffset === undefined) {
+    start = Math.max(0, stats.size - MAX_TAIL_CHUNK_BYTES);
+  } else if (
+    !isNonNegativeInteger(fromOffset) ||
+    fromOffset > stats.size ||
+    (fileId !== undefined && fileId !== currentId)
+  ) {
+    start = 0;
+    reset = true;
+  } else {
+    start = fromOffset;
+  }
+
+  const buffer = Buffer.alloc(
+    Math.min(stats.size - start, MAX_TAIL_CHUNK_BYTES),
+  );
+  if (buffer.length > 0) {
+    const fd = openSync(resolved, "r");
+    try {
+      readSync(fd, buffer, 0, buffer.length, start);
+    } finally {
+      closeSync(fd);
+    }
+  }
+  // Leave a character split by the chunk end (or a write in progress) for
+  // the next poll rather than decoding half of it
+  const length = completeUtf8Length(buffer);
+
+  return {
+    data: buffer.toString("utf-8", 0, length),
+    nextOffset: start + length,
+    fileId: currentId,
+    reset,
+  };
+}
+
+/** The backend's current log; pino-roll numbers each day's file. */
+function newestBackendLog(): string | null {
+  const logsDir = getLogsDir();
+  let newest: { file: string; mtimeMs: number } | null = null;
+  try {
+    for (const name of readdirSync(logsDir)) {
+      if (!/^app\.log(\.\d+)?$/.test(name)) continue;
+      const file = path.join(logsDir, name);
+      const { mtimeMs } = statSync(file);
+      if (!newest || mtimeMs > newest.mtimeMs) newest = { file, mtimeMs };
+    }
+  } catch {
+    return null;
+  }
+  return newest?.file ?? null;
+}
+
+/**
+ * Why the backend last failed to start or restart, plus the end of its log,
+ * so the UI can show the real cause (port in use, missing migrations)
+ * rather than "backend unavailable". Nothing is redacted — it's the local
+ * user's own log — but only the last 64 KB is returned.
+ */
+export function getBackendStartupLog(): BackendStartupLog {
+  const failure = getLastStartupFailure();
+  const logFile = newestBackendLog();
+  let contents = "";
+  let truncated = false;
+  if (logFile) {
+    const { size } = statSync(logFile);
+    const length = Math.min(size, MAX_STARTUP_LOG_BYTES);
+    const buffer = Buffer.alloc(length);
+    const fd = openSync(logFile, "r");
+    try {
+      readSync(fd, buffer, 0, length, size - length);
+    } finally {
+      closeSync(fd);
+    }
+    truncated = length < size;
+    contents = buffer.toString("utf-8");
+    // Don't start mid-line (or mid-character)
+    if (truncated) contents = contents.slice(contents.indexOf("\n") + 1);
+  }
+  return {
+    error: failure?.error ?? null,
+    failedAt: failure?.failedAt ?? null,
+    logFile,
+    contents,
+    truncated,
+  };
+}
+
+/**
+ * Open a log file in the default viewer. The path must resolve (after
+ * following symlinks) to a file inside the logs directory, so the renderer
+ * can't use this to open arbitrary files.
+ */
+export async function openLogFile(filePath: string): Promise<void> {
+  let logsDir: string;
+  let resolved: string;
+  try {
+    logsDir = realpathSync(getLogsDir());
+    resolved = realpathSync(path.resolve(logsDir, filePath));
+  } catch {
+    throw new Error(`Log file not found: ${filePath}`);
+  }
+
+  if (!isInside(logsDir, resolved)) {
+    throw new Error("Path is outside the logs directory");
+  }
+
+  // openPath resolves to an error message, or "" on success
+  const error = await shell.openPath(resolved);
+  if (error) {
+    throw new Error(`Failed to open log file: ${error}`);
+  }
+}
+
+interface LogFile {
+  path: string;
+  size: number;
+  mtimeMs: number;
+}
+
+function listLogFiles(dir: string, skip: Set<string>): LogFile[] {
+  let names: string[];
+  try {
+    names = readdirSync(dir);
+  } catch {
+    return [];
+  }
+  const files: LogFile[] = [];
+  for (const name of names) {
+    if (skip.has(name)) continue;
+    const filePath = path.join(dir, name);
+    try {
+      const stats = statSync(filePath);
+      if (stats.isFile()) {
+        files.push({
+          path: filePath,
+          size: stats.size,
+          mtimeMs: stats.mtimeMs,
+        });
+      }
+    } catch {
+      // Removed while we were looking
+    }
+  }
+  return files;
+}
+
+/**
+ * In each folder, keep the newest `keepLatest` files and delete the rest,
+ * plus anything older than `maxAgeDays`. Covers each launcher instance's
+ * `logs` folder and exported crash reports. `latest.log`/`debug.log` of a
+ * running instance are never touched. Returns bytes freed. Both thresholds
+ * must be non-negative integers; anything else would delete every log.
+ */
+export async function pruneGameLogs(
+  keepLatest: number,
+  maxAgeDays: number,
+): Promise<number> {
+  if (!isNonNegativeInteger(keepLatest)) {
+    throw new Error(`Invalid keepLatest: ${keepLatest}`);
+  }
+  if (!isNonNegativeInteger(maxAgeDays)) {
+    throw new Error(`Invalid maxAgeDays: ${maxAgeDays}`);
+  }
+  const running = new Set(getRunningGames().map((g) => g.instanceId));
+  const cutoff = Date.now() - maxAgeDays * 24 * 60 * 60 * 1000;
+
+  const folders: Array<{ dir: string; skip: Set<string> }> = [
+    { dir: path.join(getLogsDir(), "crash-reports"), skip: new Set() },
+  ];
+  let instanceIds: string[] = [];
+  try {
+    instanceIds = readdirSync(getInstancesDir());
+  } catch {
+    // No instances yet
+  }
+  for (const instanceId of instanceIds) {
+    folders.push({
+      dir: path.join(getInstancesDir(), instanceId, "logs"),
+      skip: running.has(instanceId) ? LIVE_LOG_FILES : new Set(),
+    });
+  }
+
+  let freed = 0;
+  for (const { dir, skip } of folders) {
+    const files = listLogFiles(dir, skip).sort(
+      (a, b) => b.mtimeMs - a.mtimeMs,
+    );
+    files.forEach((file, index) => {
+      if (index < keepLatest && file.mtimeMs >= cutoff) return;
+      try {
+        rmSync(file.path, { force: true });
+        freed += file.size;
+      } catch (err) {
+        console.warn(`Failed to prune log ${file.path}:`, err);
+      }
+    });
+  }
+  return freed;
+}
+
+/** Startup housekeeping with the default thresholds. Never throws. */
+export async function pruneGameLogsOnStartup(): Promise<void> {
+  try {
+    const freed = await pruneGameLogs(
+      DEFAULT_KEEP_LATEST_LOGS,
+      DEFAULT_MAX_LOG_AGE_DAYS,
+    );
+    if (freed > 0) {
+      console.log(`Pruned ${Math.round(freed / 1024)} KB of old game logs`);
+    }
+  } catch (err) {
+    console.warn("Log prune failed:", err);
+  }
+}
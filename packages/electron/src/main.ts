@@ -1,31 +1,32 @@
 import { app, BrowserWindow, screen } from "electron";
-import type { Server as HttpServer } from "node:http";
-import type { WebSocketServer } from "ws";
 import path from "node:path";
-import { fileURLToPath } from "node:url";
 import { createTray } from "./tray.js";
 import { registerIpcHandlers } from "./ipc.js";
+import { getBackendPort, setBackendPort } from "./backend-port.js";
+import {
+  backendOrigin,
+  discoverDevBackendPort,
+  startBackend,
+  waitForServer,
+} from "./backend.js";
+import { checkBackendPaths, LOG_JSON, logStartup } from "./startup-log.js";
+import { applyPortableMode, ensureWritableDataDir } from "./data-dir.js";
+import { pruneGameLogsOnStartup } from "./logs.js";
+import { shutdownApp } from "./shutdown.js";
+import { startTokenRefreshScheduler } from "./token-refresh.js";
+import { rendererUrl, rendererWebPreferences } from "./windows.js";
 
-const __dirname = path.dirname(fileURLToPath(import.meta.url));
 const isDev = !app.isPackaged;
 
 let mainWindow: BrowserWindow | null = null;
 let isQuitting = false;
 
-let backendHttpServer: HttpServer | null = null;
-let backendWss: WebSocketServer | null = null;
-let backendShutdown:
-  | ((s: HttpServer, w: WebSocketServer) => Promise<void>)
-  | null = null;
-
-const BACKEND_PORT = parseInt(process.env.PORT ?? "3001", 10);
-const BACKEND_HOST = process.env.HOST ?? "localhost";
-
 function setElectronEnv(): void {
   if (!isDev) {
     process.env.NODE_ENV = "production";
   }
 
+  applyPortableMode();
   process.env.MC_DATA_DIR = app.getPath("userData");
 
   if (!isDev) {
@@ -38,23 +39,7 @@ function setElectronEnv(): void {
     process.env.MC_FRONTEND_DIST = path.join(resources, "frontend", "dist");
   }
 
-  process.env.BACKEND_PORT = String(BACKEND_PORT);
-}
-
-async function waitForServer(url: string, timeoutMs = 15_000): Promise<void> {
-  const start = Date.now();
-  while (Date.now() - start < timeoutMs) {
-    try {
-      const response = await fetch(url);
-      if (response.ok) return;
-    } catch {
-      // Server not ready yet
-    }
-    await new Promise((r) => setTimeout(r, 200));
-  }
-  throw new Error(
-    `Server at ${url} did not become ready within ${timeoutMs}ms`,
-  );
+  setBackendPort(getBackendPort());
 }
 
 function createWindow(): BrowserWindow {
@@ -66,12 +51,7 @@ function createWindow(): BrowserWindow {
     minWidth: 800,
     minHeight: 600,
     show: false,
-    webPreferences: {
-      preload: path.join(__dirname, "preload.cjs"),
-      contextIsolation: true,
-      nodeIntegration: false,
-      sandbox: false,
-    },
+    webPreferences: rendererWebPreferences(),
   });
 
   win.on("close", (e: Electron.Event) => {
@@ -88,33 +68,18 @@ function createWindow(): BrowserWindow {
   return win;
 }
 
-// Dynamic import loads the backend without triggering standalone auto-start
-// because process.versions.electron is set, skipping the isStandaloneEntry guard.
-async function startBackend(): Promise<void> {
-  const backend = await import("@mc-server-manager/backend");
-
-  backend.initDatabase();
-
-  const result = await backend.startServer(BACKEND_PORT, BACKEND_HOST);
-  backendHttpServer = result.httpServer;
-  backendWss = result.wss;
-  backendShutdown = backend.shutdownServer;
-
-  backend.autoStartServers();
-}
-
 app.on("before-quit", async (e: Electron.Event) => {
   if (isQuitting) return;
 
   e.preventDefault();
   isQuitting = true;
 
-  if (backendShutdown && backendHttpServer && backendWss) {
-    try {
-      await backendShutdown(backendHttpServer, backendWss);
-    } catch (err) {
-      console.error("Error during shutdown:", err);
-    }
+  // Also the tail of a tray/IPC shutdown: by then everything is stopped and
+  // this finishes immediately.
+  try {
+    await shutdownApp();
+  } catch (err) {
+    console.error("Error during shutdown:", err);
   }
 
   app.quit();
@@ -137,31 +102,51 @@ async function main(): Promise<void> {
 
   await app.whenReady();
 
+  if (!ensureWritableDataDir()) {
+    isQuitting = true;
+    app.quit();
+    return;
+  }
+
+  // Before any network I/O: auth, avatars and Java/game downloads fetch from
+  // this process, and in development the backend isn't in it to set this up.
+  // Imported late because the backend's config reads the env set above.
+  const { configureOutboundProxy } = await import(
+    "@mc-server-manager/backend/proxy"
+  );
+  configureOutboundProxy();
+
   registerIpcHandlers();
+  void pruneGameLogsOnStartup();
 
   if (!isDev) {
     await startBackend();
+    await waitForServer(`${backendOrigin()}/api/health`);
+  } else {
+    // Before any window exists: preload gets the port at creation
+    await discoverDevBackendPort();
   }
 
-  const serverUrl = isDev
-    ? `http://localhost:5173`
-    : `http://${BACKEND_HOST}:${BACKEND_PORT}`;
-
-  if (!isDev) {
-    await waitForServer(`http://${BACKEND_HOST}:${BACKEND_PORT}/api/health`);
-  }
+  startTokenRefreshScheduler();
 
   mainWindow = createWindow();
 
   createTray(mainWindow, () => {
-    isQuitting = true;
-    app.quit();
+    void shutdownApp();
   });
 
-  mainWindow.loadURL(serverUrl);
+  mainWindow.loadURL(rendererUrl());
 }
 
 main().catch((err) => {
-  console.error("Fatal error starting application:", err);
+  process.exitCode = 1;
+  logStartup("error", "startup-failed", {
+    error: err instanceof Error ? err.message : String(err),
+    paths: checkBackendPaths(),
+    exitCode: process.exitCode,
+  });
+  if (!LOG_JSON) {
+    console.error("Fatal error starting application:", err);
+  }
   app.quit();
 });
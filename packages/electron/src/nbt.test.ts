@@ -0,0 +1,205 @@
+import {
+  applyLevelDatChanges,
+  decodeNbt,
+  encodeNbt,
+  parseLevelDat,
+  readLevelDatFields,
+  readNbt,
+  validateLevelDatChanges,
+  type NbtTagCompound,
+} from "./nbt.js";
+
+describe("readNbt / parseLevelDat", () => {
+  // Minimal NBT writer: [tag, name, payload bytes]
+  const bytes: number[] = [];
+  const u16 = (n: number) => bytes.push((n >> 8) & 0xff, n & 0xff);
+  const i32 = (n: number) =>
+    bytes.push(
+      (n >>> 24) & 0xff,
+      (n >>> 16) & 0xff,
+      (n >>> 8) & 0xff,
+      n & 0xff,
+    );
+  const name = (s: string) => {
+    const encoded = new TextEncoder().encode(s);
+    u16(encoded.length);
+    bytes.push(...encoded);
+  };
+  const tag = (type: number, tagName: string) => {
+    bytes.push(type);
+    name(tagName);
+  };
+
+  function levelDat(): Uint8Array {
+    bytes.length = 0;
+    tag(10, "");
+    tag(10, "Data");
+    tag(8, "LevelName");
+    name("My World");
+    tag(4, "LastPlayed");
+    i32(0x00000191);
+    i32(0x5a7c8e00); // 2024-08-16
+    tag(3, "GameType");
+    i32(1);
+    tag(1, "hardcore");
+    bytes.push(0);
+    tag(9, "ServerBrands");
+    bytes.push(8);
+    i32(1);
+    name("vanilla");
+    tag(11, "WanderingTraderId");
+    i32(2);
+    i32(7);
+    i32(-1);
+    tag(10, "Version");
+    tag(8, "Name");
+    name("1.21.4");
+    bytes.push(0); // end Version
+    bytes.push(0); // end Data
+    bytes.push(0); // end root
+    return new Uint8Array(bytes);
+  }
+
+  it("decodes nested compounds, lists and arrays", () => {
+    const root = readNbt(levelDat());
+    const data = root.Data as Record<string, unknown>;
+    expect(data.LevelName).toBe("My World");
+    expect(data.ServerBrands).toEqual(["vanilla"]);
+    expect(data.WanderingTraderId).toEqual([7, -1]);
+  });
+
+  it("extracts world browser fields", () => {
+    const info = parseLevelDat(readNbt(levelDat()));
+    expect(info).toEqual({
+      name: "My World",
+      lastPlayed: new Date(0x191 * 2 ** 32 + 0x5a7c8e00).toISOString(),
+      gameMode: "creative",
+      hardcore: false,
+      version: "1.21.4",
+    });
+  });
+
+  it("throws on truncated data", () => {
+    const data = levelDat();
+    expect(() => readNbt(data.subarray(0, data.length - 12))).toThrow(
+      "truncated",
+    );
+  });
+
+  it("rejects level.dat without a Data compound", () => {
+    expect(() => parseLevelDat({})).toThrow("no Data compound");
+  });
+});
+
+describe("level.dat editing", () => {
+  function world(): NbtTagCompound {
+    return {
+      Data: {
+        type: "compound",
+        value: {
+          LevelName: { type: "string", value: "Caf\u00e9 \u{1f30d}" },
+          GameType: { type: "int", value: 0 },
+          Difficulty: { type: "byte", value: 2 },
+          allowCommands: { type: "byte", value: 0 },
+          hardcore: { type: "byte", value: 0 },
+          LastPlayed: { type: "long", value: 1723766400000n },
+          WorldGenSettings: {
+            type: "compound",
+            value: { seed: { type: "long", value: -4172144997902289642n } },
+          },
+          Player: {
+            type: "compound",
+            value: {
+              playerGameType: { type: "int", value: 0 },
+              Pos: {
+                type: "list",
+                itemType: "double",
+                value: [
+                  { type: "double", value: 0.5 },
+                  { type: "double", value: 64 },
+                  { type: "double", value: -12.25 },
+                ],
+              },
+            },
+          },
+          BlockStates: { type: "long_array", value: [1n, -1n] },
+        },
+      },
+    };
+  }
+
+  it("round-trips every tag losslessly", () => {
+    const root = world();
+    expect(decodeNbt(encodeNbt(root))).toEqual(root);
+  });
+
+  it("encodes strings as modified UTF-8", () => {
+    const bytes = encodeNbt({ s: { type: "string", value: "\0" } });
+    // NUL is written as two bytes, never a raw zero
+    expect(Array.from(bytes.subarray(7, 11))).toEqual([0, 2, 0xc0, 0x80]);
+  });
+
+  it("reads editor fields, keeping the full 64-bit seed", () => {
+    expect(readLevelDatFields(world())).toMatchObject({
+      name: "Caf\u00e9 \u{1f30d}",
+      gameMode: "survival",
+      difficulty: "normal",
+      allowCommands: false,
+      seed: "-4172144997902289642",
+    });
+  });
+
+  it("applies changes, including the player's own game mode", () => {
+    const root = world();
+    applyLevelDatChanges(root, {
+      name: "Renamed",
+      gameMode: "creative",
+      difficulty: "peaceful",
+      allowCommands: true,
+    });
+    const fields = readLevelDatFields(decodeNbt(encodeNbt(root)));
+    expect(fields).toMatchObject({
+      name: "Renamed",
+      gameMode: "creative",
+      difficulty: "peaceful",
+      allowCommands: true,
+    });
+    const data = root.Data.value as NbtTagCompound;
+    expect(data.Player.value).toMatchObject({
+      playerGameType: { type: "int", value: 1 },
+    });
+  });
+
+  it("refuses to change a tag's type", () => {
+    const root = world();
+    (root.Data.value as NbtTagCompound).Difficulty = {
+      type: "int",
+      value: 2,
+    };
+    expect(() =>
+      applyLevelDatChanges(root, { difficulty: "hard" }),
+    ).toThrow("Difficulty has type int");
+  });
+
+  it("validates changes strictly", () => {
+    expect(validateLevelDatChanges({ name: "  Spaced  " })).toEqual({
+      name: "Spaced",
+    });
+    expect(() => validateLevelDatChanges({ name: "   " })).toThrow(
+      "World name",
+    );
+    expect(() => validateLevelDatChanges({ gameMode: "god" })).toThrow(
+      "Invalid game mode",
+    );
+    expect(() => validateLevelDatChanges({ difficulty: 3 })).toThrow(
+      "Invalid difficulty",
+    );
+    expect(() => validateLevelDatChanges({ allowCommands: 1 })).toThrow(
+      "true or false",
+    );
+    expect(() => validateLevelDatChanges({ RandomSeed: "1" })).toThrow(
+      "can't be edited",
+    );
+    expect(() => validateLevelDatChanges(null)).toThrow("object");
+  });
+});
@@ -0,0 +1,514 @@
+/**
+ * Java edition NBT: a lossless codec for files that are edited and written
+ * back (level.dat), a plain-value reader for ones that are only read, and
+ * the level.dat fields the world browser and editor use.
+ * @module nbt
+ */
+
+import type {
+  LevelDatChanges,
+  LevelDatFields,
+  LevelDatSummary,
+  WorldDifficulty,
+  WorldGameMode,
+} from "@mc-server-manager/shared";
+
+/** A decoded NBT value. Longs become numbers (ms timestamps fit). */
+export type NbtValue =
+  | number
+  | string
+  | number[]
+  | NbtValue[]
+  | { [name: string]: NbtValue };
+
+export type NbtCompound = { [name: string]: NbtValue };
+
+/** NBT tag types, indexed by their tag id */
+const NBT_TAG_TYPES = [
+  "end",
+  "byte",
+  "short",
+  "int",
+  "long",
+  "float",
+  "double",
+  "byte_array",
+  "string",
+  "list",
+  "compound",
+  "int_array",
+  "long_array",
+] as const;
+
+export type NbtTagType = (typeof NBT_TAG_TYPES)[number];
+
+/**
+ * A lossless NBT tag, for files that are read and written back. Longs stay
+ * bigints (world seeds don't fit in a double).
+ */
+export type NbtTag =
+  | { type: "byte" | "short" | "int" | "float" | "double"; value: number }
+  | { type: "long"; value: bigint }
+  | { type: "string"; value: string }
+  | { type: "byte_array" | "int_array"; value: number[] }
+  | { type: "long_array"; value: bigint[] }
+  | { type: "list"; itemType: NbtTagType; value: NbtTag[] }
+  | { type: "compound"; value: NbtTagCompound };
+
+export type NbtTagCompound = { [name: string]: NbtTag };
+
+/** Nesting deeper than this is treated as a corrupt file. */
+const NBT_MAX_DEPTH = 512;
+
+/** Java's DataInput "modified UTF-8": UTF-16 units, 1-3 bytes each. */
+function decodeModifiedUtf8(bytes: Uint8Array): string {
+  const units: number[] = [];
+  for (let i = 0; i < bytes.length; ) {
+    const b = bytes[i];
+    if (b < 0x80) {
+      units.push(b);
+      i += 1;
+    } else if ((b & 0xe0) === 0xc0 && i + 1 < bytes.length) {
+      units.push(((b & 0x1f) << 6) | (bytes[i + 1] & 0x3f));
+      i += 2;
+    } else if ((b & 0xf0) === 0xe0 && i + 2 < bytes.length) {
+      units.push(
+        ((b & 0x0f) << 12) |
+          ((bytes[i + 1] & 0x3f) << 6) |
+          (bytes[i + 2] & 0x3f),
+      );
+      i += 3;
+    } else {
+      throw new Error("NBT string is not valid modified UTF-8");
+    }
+  }
+  let result = "";
+  for (let i = 0; i < units.length; i += 4096) {
+    result += String.fromCharCode(...units.slice(i, i + 4096));
+  }
+  return result;
+}
+
+function encodeModifiedUtf8(text: string): number[] {
+  const out: number[] = [];
+  for (let i = 0; i < text.length; i++) {
+    const c = text.charCodeAt(i);
+    if (c !== 0 && c < 0x80) {
+      out.push(c);
+    } else if (c < 0x800) {
+      out.push(0xc0 | (c >> 6), 0x80 | (c & 0x3f));
+    } else {
+      out.push(0xe0 | (c >> 12), 0x80 | ((c >> 6) & 0x3f), 0x80 | (c & 0x3f));
+    }
+  }
+  if (out.length > 0xffff) throw new Error("NBT string is too long");
+  return out;
+}
+
+/**
+ * Decode uncompressed big-endian (Java edition) NBT, keeping every tag's
+ * type. level.dat is gzipped, so gunzip it first. Throws on truncated or
+ * malformed data.
+ */
+export function decodeNbt(bytes: Uint8Array): NbtTagCompound {
+  const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+  let offset = 0;
+
+  const need = (count: number): void => {
+    if (count < 0 || offset + count > bytes.byteLength) {
+      throw new Error("NBT data is truncated");
+    }
+  };
+  const u8 = (): number => {
+    need(1);
+    return view.getUint8(offset++);
+  };
+  const i32 = (): number => {
+    need(4);
+    const value = view.getInt32(offset);
+    offset += 4;
+    return value;
+  };
+  const str = (): string => {
+    need(2);
+    const length = view.getUint16(offset);
+    offset += 2;
+    need(length);
+    const value = decodeModifiedUtf8(bytes.subarray(offset, offset + length));
+    offset += length;
+    return value;
+  };
+  const tagType = (id: number): NbtTagType => {
+    const type = NBT_TAG_TYPES[id];
+    if (!type) throw new Error(`Unknown NBT tag ${id}`);
+    return type;
+  };
+
+  const payload = (type: NbtTagType, depth: number): NbtTag => {
+    if (depth > NBT_MAX_DEPTH) throw new Error("NBT nesting is too deep");
+    switch (type) {
+      case "byte":
+        need(1);
+        return { type, value: view.getInt8(offset++) };
+      case "short": {
+        need(2);
+        const value = view.getInt16(offset);
+        offset += 2;
+        return { type, value };
+      }
+      case "int":
+        return { type, value: i32() };
+      case "long": {
+        need(8);
+        const value = view.getBigInt64(offset);
+        offset += 8;
+        return { type, value };
+      }
+      case "float": {
+        need(4);
+        const value = view.getFloat32(offset);
+        offset += 4;
+        return { type, value };
+      }
+      case "double": {
+        need(8);
+        const value = view.getFloat64(offset);
+        offset += 8;
+        return { type, value };
+      }
+      case "byte_array": {
+        const length = i32();
+        need(length);
+        const value = Array.from(
+          new Int8Array(bytes.buffer, bytes.byteOffset + offset, length),
+        );
+        offset += length;
+        return { type, value };
+      }
+      case "string":
+        return { type, value: str() };
+      case "list": {
+        const itemType = tagType(u8());
+        const length = i32();
+        const value: NbtTag[] = [];
+        for (let i = 0; i < length; i++) {
+          value.push(payload(itemType, depth + 1));
+        }
+        return { type, itemType, value };
+      }
+      case "compound": {
+        const value: NbtTagCompound = {};
+        for (let child = u8(); child !== 0; child = u8()) {
+          const name = str();
+          value[name] = payload(tagType(child), depth + 1);
+        }
+        return { type, value };
+      }
+      case "int_array": {
+        const length = i32();
+        need(length * 4);
+        const value: number[] = [];
+        for (let i = 0; i < length; i++) {
+          value.push(view.getInt32(offset));
+          offset += 4;
+        }
+        return { type, value };
+      }
+      case "long_array": {
+        const length = i32();
+        need(length * 8);
+        const value: bigint[] = [];
+        for (let i = 0; i < length; i++) {
+          value.push(view.getBigInt64(offset));
+          offset += 8;
+        }
+        return { type, value };
+      }
+      case "end":
+        throw new Error("Unexpected NBT end tag");
+    }
+  };
+
+  if (u8() !== 10) throw new Error("NBT root is not a compound");
+  str(); // root name, always empty in level.dat
+  return (payload("compound", 0) as { value: NbtTagCompound }).value;
+}
+
+/** Encode a root compound (unnamed) as uncompressed big-endian NBT. */
+export function encodeNbt(root: NbtTagCompound): Uint8Array {
+  const out: number[] = [];
+  const scratch = new DataView(new ArrayBuffer(8));
+  const push = (count: number): void => {
+    for (let i = 0; i < count; i++) out.push(scratch.getUint8(i));
+  };
+  const i32 = (value: number): void => {
+    scratch.setInt32(0, value);
+    push(4);
+  };
+  const str = (value: string): void => {
+    const encoded = encodeModifiedUtf8(value);
+    scratch.setUint16(0, encoded.length);
+    push(2);
+    out.push(...encoded);
+  };
+
+  const payload = (tag: NbtTag): void => {
+    switch (tag.type) {
+      case "byte":
+        scratch.setInt8(0, tag.value);
+        return push(1);
+      case "short":
+        scratch.setInt16(0, tag.value);
+        return push(2);
+      case "int":
+        return i32(tag.value);
+      case "long":
+        scratch.setBigInt64(0, tag.value);
+        return push(8);
+      case "float":
+        scratch.setFloat32(0, tag.value);
+        return push(4);
+      case "double":
+        scratch.setFloat64(0, tag.value);
+        return push(8);
+      case "byte_array":
+        i32(tag.value.length);
+        for (const b of tag.value) out.push(b & 0xff);
+        return;
+      case "string":
+        return str(tag.value);
+      case "list":
+        out.push(NBT_TAG_TYPES.indexOf(tag.itemType));
+        i32(tag.value.length);
+        for (const item of tag.value) {
+          if (item.type !== tag.itemType) {
+            throw new Error(`NBT list of ${tag.itemType} holds a ${item.type}`);
+          }
+          payload(item);
+        }
+        return;
+      case "compound":
+        for (const [name, child] of Object.entries(tag.value)) {
+          out.push(NBT_TAG_TYPES.indexOf(child.type));
+          str(name);
+          payload(child);
+        }
+        out.push(0);
+        return;
+      case "int_array":
+        i32(tag.value.length);
+        for (const v of tag.value) i32(v);
+        return;
+      case "long_array":
+        i32(tag.value.length);
+        for (const v of tag.value) {
+          scratch.setBigInt64(0, v);
+          push(8);
+        }
+        return;
+    }
+  };
+
+  out.push(10);
+  str("");
+  payload({ type: "compound", value: root });
+  return new Uint8Array(out);
+}
+
+/** Strip types from a decoded tag. */
+function nbtToPlain(tag: NbtTag): NbtValue {
+  switch (tag.type) {
+    case "long":
+      return Number(tag.value);
+    case "long_array":
+      return tag.value.map(Number);
+    case "list":
+      return tag.value.map(nbtToPlain);
+    case "compound":
+      return Object.fromEntries(
+        Object.entries(tag.value).map(([k, v]) => [k, nbtToPlain(v)]),
+      );
+    default:
+      return tag.value;
+  }
+}
+
+/**
+ * Decode uncompressed big-endian (Java edition) NBT into plain values.
+ * level.dat is gzipped, so gunzip it first. Throws on truncated or
+ * malformed data.
+ */
+export function readNbt(bytes: Uint8Array): NbtCompound {
+  const root: NbtTag = { type: "compound", value: decodeNbt(bytes) };
+  return nbtToPlain(root) as NbtCompound;
+}
+
+const WORLD_GAME_MODES: WorldGameMode[] = [
+  "survival",
+  "creative",
+  "adventure",
+  "spectator",
+];
+
+/** Pull the world browser fields out of a decoded level.dat. */
+export function parseLevelDat(root: NbtCompound): LevelDatSummary {
+  const data = root.Data;
+  if (!data || typeof data !== "object" || Array.isArray(data)) {
+    throw new Error("level.dat has no Data compound");
+  }
+  const version = data.Version;
+  const versionName =
+    version && typeof version === "object" && !Array.isArray(version)
+      ? version.Name
+      : undefined;
+
+  return {
+    name: typeof data.LevelName === "string" ? data.LevelName : "",
+    lastPlayed:
+      typeof data.LastPlayed === "number" && data.LastPlayed > 0
+        ? new Date(data.LastPlayed).toISOString()
+        : null,
+    gameMode:
+      typeof data.GameType === "number"
+        ? (WORLD_GAME_MODES[data.GameType] ?? null)
+        : null,
+    hardcore: data.hardcore === 1,
+    version: typeof versionName === "string" ? versionName : null,
+  };
+}
+
+// --- level.dat editing ---
+
+const WORLD_DIFFICULTIES: WorldDifficulty[] = [
+  "peaceful",
+  "easy",
+  "normal",
+  "hard",
+];
+
+export const WORLD_NAME_MAX_LENGTH = 64;
+
+/**
+ * Validate edits from the renderer. Strict: unknown keys, wrong types and
+ * out-of-range values all throw, so nothing unexpected reaches level.dat.
+ */
+export function validateLevelDatChanges(input: unknown): LevelDatChanges {
+  if (!input || typeof input !== "object" || Array.isArray(input)) {
+    throw new Error("level.dat changes must be an object");
+  }
+  const changes: LevelDatChanges = {};
+  for (const [key, value] of Object.entries(input)) {
+    switch (key) {
+      case "name": {
+        const name = typeof value === "string" ? value.trim() : "";
+        if (!name || name.length > WORLD_NAME_MAX_LENGTH) {
+          throw new Error(
+            `World name must be 1-${WORLD_NAME_MAX_LENGTH} characters`,
+          );
+        }
+        changes.name = name;
+        break;
+      }
+      case "gameMode":
+        if (!WORLD_GAME_MODES.includes(value as WorldGameMode)) {
+          throw new Error(`Invalid game mode: ${String(value)}`);
+        }
+        changes.gameMode = value as WorldGameMode;
+        break;
+      case "difficulty":
+        if (!WORLD_DIFFICULTIES.includes(value as WorldDifficulty)) {
+          throw new Error(`Invalid difficulty: ${String(value)}`);
+        }
+        changes.difficulty = value as WorldDifficulty;
+        break;
+      case "allowCommands":
+        if (typeof value !== "boolean") {
+          throw new Error("allowCommands must be true or false");
+        }
+        changes.allowCommands = value;
+        break;
+      default:
+        throw new Error(`level.dat field "${key}" can't be edited`);
+    }
+  }
+  return changes;
+}
+
+function levelData(root: NbtTagCompound): NbtTagCompound {
+  const data = root.Data;
+  if (data?.type !== "compound") {
+    throw new Error("level.dat has no Data compound");
+  }
+  return data.value;
+}
+
+/** The editor fields of a losslessly decoded level.dat. */
+export function readLevelDatFields(root: NbtTagCompound): LevelDatFields {
+  const data = levelData(root);
+  const plain = nbtToPlain({ type: "compound", value: root }) as NbtCompound;
+
+  const difficulty = data.Difficulty;
+  const allowCommands = data.allowCommands;
+  // 1.16+ keeps the seed under WorldGenSettings; older worlds use RandomSeed
+  const worldGen = data.WorldGenSettings;
+  const seed =
+    worldGen?.type === "compound" && worldGen.value.seed?.type === "long"
+      ? worldGen.value.seed
+      : data.RandomSeed;
+
+  return {
+    ...parseLevelDat(plain),
+    difficulty:
+      difficulty?.type === "byte"
+        ? (WORLD_DIFFICULTIES[difficulty.value] ?? null)
+        : null,
+    allowCommands: allowCommands?.type === "byte" && allowCommands.value !== 0,
+    seed: seed?.type === "long" ? seed.value.toString() : null,
+  };
+}
+
+/** Set `name` to a tag of `type`, refusing to change an existing tag's type. */
+function setTag(compound: NbtTagCompound, name: string, tag: NbtTag): void {
+  const existing = compound[name];
+  if (existing && existing.type !== tag.type) {
+    throw new Error(
+      `level.dat ${name} has type ${existing.type}, expected ${tag.type}`,
+    );
+  }
+  compound[name] = tag;
+}
+
+/**
+ * Apply validated changes to a losslessly decoded level.dat in place. A
+ * singleplayer world also stores the player's own game mode, which the game
+ * prefers over GameType once the player exists, so both are updated.
+ */
+export function applyLevelDatChanges(
+  root: NbtTagCompound,
+  changes: LevelDatChanges,
+): void {
+  const data = levelData(root);
+  if (changes.name !== undefined) {
+    setTag(data, "LevelName", { type: "string", value: changes.name });
+  }
+  if (changes.gameMode !== undefined) {
+    const mode = WORLD_GAME_MODES.indexOf(changes.gameMode);
+    setTag(data, "GameType", { type: "int", value: mode });
+    const player = data.Player;
+    if (player?.type === "compound" && player.value.playerGameType) {
+      setTag(player.value, "playerGameType", { type: "int", value: mode });
+    }
+  }
+  if (changes.difficulty !== undefined) {
+    setTag(data, "Difficulty", {
+      type: "byte",
+      value: WORLD_DIFFICULTIES.indexOf(changes.difficulty),
+    });
+  }
+  if (changes.allowCommands !== undefined) {
+    setTag(data, "allowCommands", {
+      type: "byte",
+      value: changes.allowCommands ? 1 : 0,
+    });
+  }
+}
@@ -0,0 +1,71 @@
+/**
+ * Native desktop notifications, gated by the `desktopNotifications` app setting
+ * (or another boolean setting, for notification kinds with their own toggle).
+ * @module notifications
+ */
+
+import { Notification } from "electron";
+import type { AppSettings } from "@mc-server-manager/shared";
+import { backendUrl } from "./backend-port.js";
+
+/** Boolean app settings that can gate a notification. */
+export type NotificationSetting = "desktopNotifications" | "crashNotifications";
+
+export interface NotifyOptions {
+  /** Setting that must be on for this notification; both default to true. */
+  setting?: NotificationSetting;
+  /** Runs when the user clicks the notification. */
+  onClick?: () => void;
+}
+
+/**
+ * Notifications with a click handler are kept here until dismissed; if the
+ * object is garbage collected first, the click never reaches us.
+ */
+const pending = new Set<Notification>();
+
+async function notificationsEnabled(
+  setting: NotificationSetting,
+): Promise<boolean> {
+  try {
+    const res = await fetch(backendUrl("/api/system/settings"));
+    if (!res.ok) return true;
+    const settings = (await res.json()) as Partial<AppSettings>;
+    return settings[setting] ?? true;
+  } catch {
+    // Backend unreachable (e.g. dev without backend) — fall back to the default
+    return true;
+  }
+}
+
+/** Show a notification if supported and enabled. Never throws. */
+export async function notify(
+  title: string,
+  body: string,
+  options: NotifyOptions = {},
+): Promise<void> {
+  try {
+    if (!Notification.isSupported()) return;
+    const setting = options.setting ?? "desktopNotifications";
+    if (!(await notificationsEnabled(setting))) return;
+
+    const notification = new Notification({ title, body });
+    const { onClick } = options;
+    if (onClick) {
+      pending.add(notification);
+      const release = () => pending.delete(notification);
+      notification.once("click", () => {
+        release();
+        try {
+          onClick();
+        } catch (err) {
+          console.error("Notification click handler failed:", err);
+        }
+      });
+      notification.once("close", release);
+    }
+    notification.show();
+  } catch (err) {
+    console.error("Failed to show notification:", err);
+  }
+}
@@ -0,0 +1,36 @@
+import { hasElevatedIntegrity } from "./platform.js";
+
+describe("hasElevatedIntegrity", () => {
+  const groups = (label: string) =>
+    [
+      "GROUP INFORMATION",
+      "-----------------",
+      "BUILTIN\\Administrators  Alias  S-1-5-32-544  Group used for deny only",
+      `Mandatory Label\\${label}`,
+    ].join("\r\n");
+
+  it("is true for High and System integrity", () => {
+    expect(
+      hasElevatedIntegrity(
+        groups("High Mandatory Level  Label  S-1-16-12288"),
+      ),
+    ).toBe(true);
+    expect(
+      hasElevatedIntegrity(
+        groups("System Mandatory Level  Label  S-1-16-16384"),
+      ),
+    ).toBe(true);
+  });
+
+  it("is false for a filtered admin token", () => {
+    expect(
+      hasElevatedIntegrity(
+        groups("Medium Mandatory Level  Label  S-1-16-8192"),
+      ),
+    ).toBe(false);
+  });
+
+  it("doesn't match a longer SID with the same prefix", () => {
+    expect(hasElevatedIntegrity("S-1-16-122880")).toBe(false);
+  });
+});
@@ -0,0 +1,104 @@
+/**
+ * Platform probes: CPU architecture checks, mainly for x64 code running
+ * under Rosetta on Apple Silicon (works, but far slower than native), and
+ * whether the app runs elevated.
+ * @module platform
+ */
+
+import { execFile } from "node:child_process";
+import { promisify } from "node:util";
+
+const execFileAsync = promisify(execFile);
+
+async function sysctlFlag(name: string): Promise<boolean> {
+  try {
+    const { stdout } = await execFileAsync("sysctl", ["-in", name], {
+      timeout: 5_000,
+    });
+    return stdout.trim() === "1";
+  } catch {
+    // Key doesn't exist (Intel Macs, older macOS) — treat as unset
+    return false;
+  }
+}
+
+/** True when this app process itself is an x64 build translated by Rosetta. */
+export async function isRunningUnderRosetta(): Promise<boolean> {
+  if (process.platform !== "darwin") return false;
+  return sysctlFlag("sysctl.proc_translated");
+}
+
+/** True on an M-series Mac, even when this process runs under Rosetta. */
+export async function isAppleSilicon(): Promise<boolean> {
+  if (process.platform !== "darwin") return false;
+  if (process.arch === "arm64") return true;
+  return sysctlFlag("hw.optional.arm64");
+}
+
+/**
+ * The `os.arch` a Java binary reports (e.g. "aarch64", "x86_64", "amd64"),
+ * or null if it couldn't be determined.
+ */
+export async function getJavaArch(javaPath: string): Promise<string | null> {
+  try {
+    // Property listing goes to stderr
+    const { stderr } = await execFileAsync(
+      javaPath,
+      ["-XshowSettings:properties", "-version"],
+      { timeout: 10_000 },
+    );
+    const match = /^\s*os\.arch\s*=\s*(\S+)/m.exec(stderr);
+    return match ? match[1] : null;
+  } catch {
+    return null;
+  }
+}
+
+/** Whether a Java binary would run under Rosetta on this machine. */
+export async function isJavaTranslated(javaPath: string): Promise<boolean> {
+  if (!(await isAppleSilicon())) return false;
+  const arch = await getJavaArch(javaPath);
+  return arch === "x86_64" || arch === "amd64";
+}
+
+/** Windows mandatory-label SIDs for High and System integrity */
+const ELEVATED_INTEGRITY_SIDS = ["S-1-16-12288", "S-1-16-16384"];
+
+/**
+ * Whether `whoami /groups` output shows an elevated token. UAC gives admins
+ * a Medium-integrity token until they "Run as administrator", so admin
+ * group membership alone doesn't count.
+ */
+export function hasElevatedIntegrity(whoamiGroups: string): boolean {
+  return ELEVATED_INTEGRITY_SIDS.some((sid) =>
+    new RegExp(`\\b${sid}\\b`).test(whoamiGroups),
+  );
+}
+
+let elevated: Promise<boolean> | null = null;
+
+async function probeElevation(): Promise<boolean> {
+  if (process.platform !== "win32") {
+    return process.geteuid?.() === 0;
+  }
+  try {
+    // An elevated token carries the High (or System) integrity label
+    const { stdout } = await execFileAsync("whoami", ["/groups"], {
+      timeout: 5_000,
+      windowsHide: true,
+    });
+    return hasElevatedIntegrity(stdout);
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * True when running as root (Unix) or with an elevated admin token
+ * (Windows). Elevation can't change while the process runs, so the probe
+ * runs once.
+ */
+export function isElevated(): Promise<boolean> {
+  elevated ??= probeElevation();
+  return elevated;
+}
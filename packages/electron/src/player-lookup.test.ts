@@ -0,0 +1,29 @@
+import { dashUuid, isValidMinecraftUsername } from "./player-lookup.js";
+
+describe("isValidMinecraftUsername", () => {
+  it("accepts letters, digits and underscores", () => {
+    expect(isValidMinecraftUsername("Notch")).toBe(true);
+    expect(isValidMinecraftUsername("jeb_")).toBe(true);
+  });
+
+  it("rejects bad lengths and characters", () => {
+    expect(isValidMinecraftUsername("ab")).toBe(false);
+    expect(isValidMinecraftUsername("a".repeat(17))).toBe(false);
+    expect(isValidMinecraftUsername("bad name")).toBe(false);
+    expect(isValidMinecraftUsername("op @a")).toBe(false);
+  });
+});
+
+describe("dashUuid", () => {
+  it("dashes an undashed uuid", () => {
+    expect(dashUuid("069a79f444e94726a5befca90e38aaf5")).toBe(
+      "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+    );
+  });
+
+  it("leaves anything else alone", () => {
+    expect(dashUuid("069a79f4-44e9-4726-a5be-fca90e38aaf5")).toBe(
+      "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+    );
+  });
+});
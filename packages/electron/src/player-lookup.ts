@@ -0,0 +1,97 @@
+/**
+ * Username → UUID lookups against Mojang's profile API, for whitelist/op
+ * management and checking a name before it goes into an RCON command.
+ * Results (including "not found") are cached briefly; the API is rate
+ * limited per IP.
+ * @module player-lookup
+ */
+
+import type { PlayerLookup } from "@mc-server-manager/shared";
+
+const PROFILE_URL = "https://api.mojang.com/users/profiles/minecraft/";
+
+const LOOKUP_TIMEOUT_MS = 10_000;
+
+/** Found players rarely change name, so they're kept a little longer. */
+const FOUND_TTL_MS = 10 * 60_000;
+const NOT_FOUND_TTL_MS = 60_000;
+
+interface CacheEntry {
+  player: PlayerLookup | null;
+  expiresAt: number;
+}
+
+/** Keyed by lowercased username. */
+const cache = new Map<string, CacheEntry>();
+
+/** Java Edition usernames: 3–16 letters, digits or underscores */
+export function isValidMinecraftUsername(name: string): boolean {
+  return /^[A-Za-z0-9_]{3,16}$/.test(name);
+}
+
+/** Insert dashes into a 32-character hex UUID; other input is returned as-is */
+export function dashUuid(uuid: string): string {
+  if (!/^[0-9a-f]{32}$/i.test(uuid)) return uuid;
+  return [
+    uuid.slice(0, 8),
+    uuid.slice(8, 12),
+    uuid.slice(12, 16),
+    uuid.slice(16, 20),
+    uuid.slice(20),
+  ].join("-");
+}
+
+function notFound(username: string): Error {
+  return new Error(`No Minecraft player named "${username}"`);
+}
+
+/**
+ * Resolve a username to its UUID and correctly-cased name. Rejects with a
+ * "No Minecraft player named" error for names nobody has registered.
+ */
+export async function lookupPlayer(username: string): Promise<PlayerLookup> {
+  const name = username.trim();
+  if (!isValidMinecraftUsername(name)) {
+    throw new Error(
+      `"${name}" is not a valid username (3-16 letters, digits or _)`,
+    );
+  }
+
+  const key = name.toLowerCase();
+  const cached = cache.get(key);
+  if (cached && cached.expiresAt > Date.now()) {
+    if (!cached.player) throw notFound(name);
+    return cached.player;
+  }
+
+  let res: Response;
+  try {
+    res = await fetch(PROFILE_URL + encodeURIComponent(name), {
+      signal: AbortSignal.timeout(LOOKUP_TIMEOUT_MS),
+    });
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new Error(`Could not reach Mojang to look up "${name}": ${reason}`);
+  }
+
+  // Older API versions answered unknown names with 204 instead of 404
+  if (res.status === 404 || res.status === 204) {
+    cache.set(key, { player: null, expiresAt: Date.now() + NOT_FOUND_TTL_MS });
+    throw notFound(name);
+  }
+  if (res.status === 429) {
+    throw new Error("Mojang is rate limiting lookups; try again in a minute");
+  }
+  if (!res.ok) {
+    throw new Error(`Player lookup failed (${res.status})`);
+  }
+
+  const body = (await res.json()) as { id?: string; name?: string };
+  if (!body.id || !body.name) {
+    throw new Error("Unexpected response from Mojang's profile API");
+  }
+
+  const player: PlayerLookup = { uuid: dashUuid(body.id), name: body.name };
+  cache.set(key, { player, expiresAt: Date.now() + FOUND_TTL_MS });
+  return player;
+}
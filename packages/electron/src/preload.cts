@@ -1,25 +1,193 @@
-import { contextBridge, ipcRenderer } from "electron";
+import {
+  contextBridge,
+  ipcRenderer,
+  type IpcRendererEvent,
+} from "electron";
+
+// Main → renderer event channels (keep in sync with events.ts)
+const EVENT_CHANNELS = new Set([
+  "backend-restarting",
+  "backend-ready",
+  "backend-down",
+  "launch-progress",
+  "launch-failed",
+  "launch-stalled",
+  "game-ready",
+  "game-force-killed",
+  "game-crashed",
+  "game-log",
+  "account-refresh-failed",
+]);
+
+const backendPortArg = process.argv.find((arg) =>
+  arg.startsWith("--backend-port="),
+);
 
 contextBridge.exposeInMainWorld("electronAPI", {
   platform: process.platform,
+  backendPort: backendPortArg
+    ? parseInt(backendPortArg.split("=")[1], 10)
+    : 3001,
+  getBackendPort: () => ipcRenderer.invoke("get-backend-port"),
+  restartBackend: () => ipcRenderer.invoke("restart-backend"),
+  reloadBackendConfig: () => ipcRenderer.invoke("reload-backend-config"),
+  getBackendStartupLog: () => ipcRenderer.invoke("get-backend-startup-log"),
+  stopAllServers: (graceful?: boolean) =>
+    ipcRenderer.invoke("stop-all-servers", { graceful }),
+  checkBackendHealth: () => ipcRenderer.invoke("check-backend-health"),
+  isRunningUnderRosetta: () => ipcRenderer.invoke("is-running-under-rosetta"),
+  isElevated: () => ipcRenderer.invoke("is-elevated"),
+  shutdownApp: () => ipcRenderer.invoke("shutdown-app"),
+
+  on: (channel: string, callback: (payload: unknown) => void) => {
+    if (!EVENT_CHANNELS.has(channel)) {
+      throw new Error(`Unknown event channel: ${channel}`);
+    }
+    const listener = (_event: IpcRendererEvent, payload: unknown) =>
+      callback(payload);
+    ipcRenderer.on(channel, listener);
+    return () => {
+      ipcRenderer.removeListener(channel, listener);
+    };
+  },
+  getAppInfo: () => ipcRenderer.invoke("get-app-info"),
 
   msAuthStart: () => ipcRenderer.invoke("ms-auth-start"),
   msAuthPoll: () => ipcRenderer.invoke("ms-auth-poll"),
+  msAuthResume: () => ipcRenderer.invoke("ms-auth-resume"),
+  openAuthUrl: () => ipcRenderer.invoke("open-auth-url"),
   msAuthCancel: () => ipcRenderer.invoke("ms-auth-cancel"),
   msAuthRefresh: (uuid: string) =>
     ipcRenderer.invoke("ms-auth-refresh", { uuid }),
+  getAccountAvatar: (uuid: string, size: number) =>
+    ipcRenderer.invoke("get-account-avatar", { uuid, size }),
   getMcAccessToken: (uuid: string) =>
     ipcRenderer.invoke("get-mc-access-token", { uuid }),
-  removeAccount: (uuid: string) =>
-    ipcRenderer.invoke("remove-account", { uuid }),
+  importVanillaAccounts: (path: string) =>
+    ipcRenderer.invoke("import-vanilla-accounts", { path }),
+  migrateAccountKeyring: (
+    oldUuid: string,
+    newUuid: string,
+    options?: { verify?: boolean },
+  ) =>
+    ipcRenderer.invoke("migrate-account-keyring", {
+      oldUuid,
+      newUuid,
+      ...options,
+    }),
+  checkCredentialStorage: () => ipcRenderer.invoke("check-credential-storage"),
+  removeAccount: (uuid: string, options?: { signOut?: boolean }) =>
+    ipcRenderer.invoke("remove-account", { uuid, ...options }),
+  repairAccount: (uuid: string) =>
+    ipcRenderer.invoke("repair-account", { uuid }),
 
-   launchGame: (instanceId: string, accountId: string, prepareResult: unknown) =>
-     ipcRenderer.invoke("launch-game", { instanceId, accountId, prepareResult }),
+   launchGame: (
+     instanceId: string,
+     accountId: string | null,
+     prepareResult: unknown,
+     options?: { force?: boolean; prepareMs?: number },
+   ) =>
+     ipcRenderer.invoke("launch-game", {
+       instanceId,
+       accountId,
+       prepareResult,
+       ...options,
+     }),
+  testJavaLaunch: (instanceId: string) =>
+    ipcRenderer.invoke("test-java-launch", { instanceId }),
+  validateInstance: (instanceId: string, accountId?: string) =>
+    ipcRenderer.invoke("validate-instance", { instanceId, accountId }),
   getRunningGames: () => ipcRenderer.invoke("get-running-games"),
-  killGame: (instanceId: string) =>
-    ipcRenderer.invoke("kill-game", { instanceId }),
+  getLastLaunchTimings: (instanceId: string) =>
+    ipcRenderer.invoke("get-last-launch-timings", { instanceId }),
+  openLogWindow: (instanceId: string) =>
+    ipcRenderer.invoke("open-log-window", { instanceId }),
+  getGameOutput: (instanceId: string) =>
+    ipcRenderer.invoke("get-game-output", { instanceId }),
+  getRunningGamesDetailed: () =>
+    ipcRenderer.invoke("get-running-games-detailed"),
+  getInstancePaths: (instanceId: string) =>
+    ipcRenderer.invoke("get-instance-paths", { instanceId }),
+  getEffectiveClasspath: (instanceId: string) =>
+    ipcRenderer.invoke("get-effective-classpath", { instanceId }),
+  previewGameArgs: (instanceId: string) =>
+    ipcRenderer.invoke("preview-game-args", { instanceId }),
+  verifyInstanceFiles: (instanceId: string, prepareResult?: unknown) =>
+    ipcRenderer.invoke("verify-instance-files", { instanceId, prepareResult }),
+  getMcVersions: (includeSnapshots?: boolean) =>
+    ipcRenderer.invoke("get-mc-versions", { includeSnapshots }),
+  listInstances: (refresh?: boolean) =>
+    ipcRenderer.invoke("list-instances", { refresh }),
+  getMaxConcurrentGames: () => ipcRenderer.invoke("get-max-concurrent-games"),
+  setMaxConcurrentGames: (max: number) =>
+    ipcRenderer.invoke("set-max-concurrent-games", { max }),
+  cancelLaunch: (instanceId: string) =>
+    ipcRenderer.invoke("cancel-launch", { instanceId }),
+  killGame: (instanceId: string, timeoutSecs?: number) =>
+    ipcRenderer.invoke("kill-game", { instanceId, timeoutSecs }),
+  listWorlds: (instanceId: string) =>
+    ipcRenderer.invoke("list-worlds", { instanceId }),
+  openWorldFolder: (instanceId: string, folder: string) =>
+    ipcRenderer.invoke("open-world-folder", { instanceId, folder }),
+  readLevelDat: (instanceId: string, folder: string) =>
+    ipcRenderer.invoke("read-level-dat", { instanceId, folder }),
+  writeLevelDat: (instanceId: string, folder: string, changes: unknown) =>
+    ipcRenderer.invoke("write-level-dat", { instanceId, folder, changes }),
+  suspendGame: (instanceId: string) =>
+    ipcRenderer.invoke("suspend-game", { instanceId }),
+  resumeGame: (instanceId: string) =>
+    ipcRenderer.invoke("resume-game", { instanceId }),
+
+  clearCache: (what: string[]) => ipcRenderer.invoke("clear-cache", { what }),
+  getInstanceSize: (instanceId: string, refresh?: boolean) =>
+    ipcRenderer.invoke("get-instance-size", { instanceId, refresh }),
+  exportConfig: (destPath: string) =>
+    ipcRenderer.invoke("export-config", { destPath }),
+  importConfig: (srcPath: string, overwrite?: boolean) =>
+    ipcRenderer.invoke("import-config", { srcPath, overwrite }),
+  openLogFile: (path: string) => ipcRenderer.invoke("open-log-file", { path }),
+  pruneGameLogs: (keepLatest: number, maxAgeDays: number) =>
+    ipcRenderer.invoke("prune-game-logs", { keepLatest, maxAgeDays }),
+  tailLog: (path: string, fromOffset?: number, fileId?: string) =>
+    ipcRenderer.invoke("tail-log", { path, fromOffset, fileId }),
+  exportCrashReport: (instanceId: string) =>
+    ipcRenderer.invoke("export-crash-report", { instanceId }),
+
+  setRconPassword: (serverId: string, password: string) =>
+    ipcRenderer.invoke("set-rcon-password", { serverId, password }),
+  clearRconPassword: (serverId: string) =>
+    ipcRenderer.invoke("clear-rcon-password", { serverId }),
+  hasRconPassword: (serverId: string) =>
+    ipcRenderer.invoke("has-rcon-password", { serverId }),
+  rconConnect: (serverId: string, host: string, port: number) =>
+    ipcRenderer.invoke("rcon-connect", { serverId, host, port }),
+  rconCommand: (serverId: string, command: string) =>
+    ipcRenderer.invoke("rcon-command", { serverId, command }),
+  rconDisconnect: (serverId: string) =>
+    ipcRenderer.invoke("rcon-disconnect", { serverId }),
+  listJvmPresets: () => ipcRenderer.invoke("list-jvm-presets"),
+  saveJvmPreset: (name: string, args: string[]) =>
+    ipcRenderer.invoke("save-jvm-preset", { name, args }),
+  deleteJvmPreset: (name: string) =>
+    ipcRenderer.invoke("delete-jvm-preset", { name }),
+  queryServer: (host: string, port: number) =>
+    ipcRenderer.invoke("query-server", { host, port }),
+  lookupPlayer: (username: string) =>
+    ipcRenderer.invoke("lookup-player", { username }),
+  rconTest: (host: string, port: number, password: string, runList?: boolean) =>
+    ipcRenderer.invoke("rcon-test", { host, port, password, runList }),
 
   getJavaInstallations: () => ipcRenderer.invoke("get-java-installations"),
-  downloadJava: (version: number) =>
-    ipcRenderer.invoke("download-java", { version }),
+  getAvailableJavaVersions: () =>
+    ipcRenderer.invoke("get-available-java-versions"),
+  downloadJava: (version: number, options?: { allowEmulated?: boolean }) =>
+    ipcRenderer.invoke("download-java", { version, ...options }),
+  cancelJavaDownload: (version: number) =>
+    ipcRenderer.invoke("cancel-java-download", { version }),
+  diagnoseJava: (instanceId: string) =>
+    ipcRenderer.invoke("diagnose-java", { instanceId }),
+  openJavaDownloadPage: (version: number) =>
+    ipcRenderer.invoke("open-java-download-page", { version }),
+  warmJava: (javaPath: string) =>
+    ipcRenderer.invoke("warm-java", { javaPath }),
 });
@@ -0,0 +1,125 @@
+import dgram from "node:dgram";
+import type { AddressInfo } from "node:net";
+import { parseChallenge, parseFullStat, queryServer } from "./query.js";
+
+/** Handshake reply as sent by a vanilla server, session id 1 */
+const HANDSHAKE_REPLY = Buffer.from(
+  "\x09\x00\x00\x00\x019513307\x00",
+  "latin1",
+);
+
+/** Full stat reply as sent by a Bukkit server, session id 1 */
+const STAT_REPLY = Buffer.from(
+  "\x00\x00\x00\x00\x01" +
+    "splitnum\x00\x80\x00" +
+    [
+      "hostname",
+      "A Minecraft Server",
+      "gametype",
+      "SMP",
+      "game_id",
+      "MINECRAFT",
+      "version",
+      "1.2.5",
+      "plugins",
+      "CraftBukkit on Bukkit 1.2.5-R4.0: WorldEdit 5.3; CommandBook 2.1",
+      "map",
+      "world",
+      "numplayers",
+      "2",
+      "maxplayers",
+      "20",
+      "hostport",
+      "25565",
+      "hostip",
+      "127.0.0.1",
+    ].join("\x00") +
+    "\x00\x00" +
+    "\x01player_\x00\x00" +
+    "barneygale\x00Vivalahelvig\x00\x00",
+  "latin1",
+);
+
+const EXPECTED_STAT = {
+  motd: "A Minecraft Server",
+  gameType: "SMP",
+  version: "1.2.5",
+  serverMod: "CraftBukkit on Bukkit 1.2.5-R4.0",
+  plugins: ["WorldEdit 5.3", "CommandBook 2.1"],
+  map: "world",
+  numPlayers: 2,
+  maxPlayers: 20,
+  hostPort: 25565,
+  hostIp: "127.0.0.1",
+  players: ["barneygale", "Vivalahelvig"],
+};
+
+/** Copy of a captured reply with its session id replaced. */
+function withSession(reply: Buffer, sessionId: number): Buffer {
+  const copy = Buffer.from(reply);
+  copy.writeInt32BE(sessionId, 1);
+  return copy;
+}
+
+describe("parseChallenge", () => {
+  it("reads the decimal token", () => {
+    expect(parseChallenge(HANDSHAKE_REPLY.subarray(5))).toBe(9513307);
+    expect(parseChallenge(Buffer.from("-42\x00", "latin1"))).toBe(-42);
+  });
+
+  it("rejects garbage and values outside an int32", () => {
+    expect(parseChallenge(Buffer.from("abc\x00", "latin1"))).toBeNull();
+    expect(parseChallenge(Buffer.from("12abc\x00", "latin1"))).toBeNull();
+    expect(parseChallenge(Buffer.from("4294967296\x00", "latin1"))).toBeNull();
+  });
+});
+
+describe("parseFullStat", () => {
+  it("reads the key/values, plugins and players", () => {
+    expect(parseFullStat(STAT_REPLY.subarray(5))).toEqual(EXPECTED_STAT);
+  });
+
+  it("handles a vanilla server with no plugins or players", () => {
+    const body = Buffer.from(
+      "splitnum\x00\x80\x00" +
+        "hostname\x00Vanilla\x00plugins\x00\x00numplayers\x000\x00\x00" +
+        "\x01player_\x00\x00\x00",
+      "latin1",
+    );
+    expect(parseFullStat(body)).toMatchObject({
+      motd: "Vanilla",
+      serverMod: null,
+      plugins: [],
+      numPlayers: 0,
+      players: [],
+    });
+  });
+});
+
+describe("queryServer", () => {
+  let server: dgram.Socket;
+  let port: number;
+
+  beforeEach(async () => {
+    server = dgram.createSocket("udp4");
+    server.on("message", (msg, rinfo) => {
+      const type = msg.readUInt8(2);
+      const sessionId = msg.readInt32BE(3);
+      const reply = type === 9 ? HANDSHAKE_REPLY : STAT_REPLY;
+      // A stale reply from another session arrives first and must be ignored
+      server.send(withSession(reply, sessionId ^ 1), rinfo.port, rinfo.address);
+      server.send(withSession(reply, sessionId), rinfo.port, rinfo.address);
+    });
+    await new Promise<void>((resolve) => server.bind(0, "127.0.0.1", resolve));
+    port = (server.address() as AddressInfo).port;
+  });
+
+  afterEach(() => {
+    server.close();
+  });
+
+  it("only accepts replies for its own session", async () => {
+    const result = await queryServer("127.0.0.1", port);
+    expect(result).toMatchObject(EXPECTED_STAT);
+  });
+});
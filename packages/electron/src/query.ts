@@ -0,0 +1,215 @@
+/**
+ * Minecraft UDP Query (GS4) client — richer than Server List Ping: plugins,
+ * full player list and world name. Only answers when the server has
+ * `enable-query=true`.
+ *
+ * Requests are `FE FD`, a type byte, a big-endian session id and a payload.
+ * Replies start with the type byte and the same session id; anything else
+ * on the socket (a late reply to an earlier query, a stray datagram) is
+ * ignored. A handshake (type 9) returns a challenge token as a decimal
+ * string, which the full stat request (type 0) must echo back as an int32.
+ *
+ * @module query
+ */
+
+import dgram from "node:dgram";
+import type { QueryResponse } from "@mc-server-manager/shared";
+
+const TYPE_HANDSHAKE = 9;
+const TYPE_STAT = 0;
+
+/** Per-packet response timeout (ms). Query is usually LAN-fast. */
+const QUERY_TIMEOUT_MS = 3_000;
+
+/** Full stat responses start with this after the type + session header. */
+const STAT_PADDING_LENGTH = 11; // "splitnum\0\x80\0"
+const PLAYER_PADDING_LENGTH = 10; // "\x01player_\0\0"
+
+function encodeRequest(
+  type: number,
+  sessionId: number,
+  payload: Buffer = Buffer.alloc(0),
+): Buffer {
+  const header = Buffer.alloc(7);
+  header.writeUInt8(0xfe, 0);
+  header.writeUInt8(0xfd, 1);
+  header.writeUInt8(type, 2);
+  header.writeInt32BE(sessionId, 3);
+  return Buffer.concat([header, payload]);
+}
+
+/**
+ * Send one packet and wait for the reply to it: the same type and session
+ * id. Resolves with the reply's body, after that 5-byte header.
+ */
+function exchange(
+  socket: dgram.Socket,
+  host: string,
+  port: number,
+  packet: Buffer,
+  expectedType: number,
+  sessionId: number,
+): Promise<Buffer> {
+  return new Promise((resolve, reject) => {
+    const cleanup = () => {
+      clearTimeout(timer);
+      socket.off("message", onMessage);
+      socket.off("error", onError);
+    };
+    const onMessage = (msg: Buffer) => {
+      if (
+        msg.length < 5 ||
+        msg.readUInt8(0) !== expectedType ||
+        msg.readInt32BE(1) !== sessionId
+      ) {
+        return;
+      }
+      cleanup();
+      resolve(msg.subarray(5));
+    };
+    const onError = (err: Error) => {
+      cleanup();
+      reject(new Error(`Query to ${host}:${port} failed: ${err.message}`));
+    };
+    const timer = setTimeout(() => {
+      cleanup();
+      reject(
+        new Error(
+          `No Query response from ${host}:${port} — the server may have enable-query=false`,
+        ),
+      );
+    }, QUERY_TIMEOUT_MS);
+
+    socket.on("message", onMessage);
+    socket.on("error", onError);
+    socket.send(packet, port, host);
+  });
+}
+
+/** Read NUL-terminated latin1 strings starting at `offset`. */
+class StringReader {
+  constructor(
+    private buf: Buffer,
+    public offset = 0,
+  ) {}
+
+  next(): string {
+    const end = this.buf.indexOf(0, this.offset);
+    const stop = end === -1 ? this.buf.length : end;
+    const value = this.buf.toString("latin1", this.offset, stop);
+    this.offset = stop + 1;
+    return value;
+  }
+
+  get done(): boolean {
+    return this.offset >= this.buf.length;
+  }
+}
+
+function parsePlugins(raw: string): {
+  serverMod: string | null;
+  plugins: string[];
+} {
+  if (!raw) return { serverMod: null, plugins: [] };
+  const colon = raw.indexOf(":");
+  if (colon === -1) return { serverMod: raw.trim(), plugins: [] };
+  return {
+    serverMod: raw.slice(0, colon).trim(),
+    plugins: raw
+      .slice(colon + 1)
+      .split(";")
+      .map((p) => p.trim())
+      .filter(Boolean),
+  };
+}
+
+/** The challenge token from a handshake reply body, or null if garbled. */
+export function parseChallenge(body: Buffer): number | null {
+  const token = new StringReader(body).next();
+  if (!/^-?\d+$/.test(token)) return null;
+  const challenge = Number(token);
+  // Echoed back as an int32
+  const fits = challenge >= -0x80000000 && challenge <= 0x7fffffff;
+  return fits ? challenge : null;
+}
+
+/** A full stat reply body: the key/value section, then the player list. */
+export function parseFullStat(body: Buffer): Omit<QueryResponse, "latencyMs"> {
+  const reader = new StringReader(body, STAT_PADDING_LENGTH);
+  const kv: Record<string, string> = {};
+  while (!reader.done) {
+    const key = reader.next();
+    if (!key) break;
+    kv[key] = reader.next();
+  }
+
+  // The empty key that ended the loop was the section terminator
+  reader.offset += PLAYER_PADDING_LENGTH;
+  const players: string[] = [];
+  while (!reader.done) {
+    const name = reader.next();
+    if (!name) break;
+    players.push(name);
+  }
+
+  return {
+    motd: kv.hostname ?? "",
+    gameType: kv.gametype ?? "",
+    version: kv.version ?? "",
+    ...parsePlugins(kv.plugins ?? ""),
+    map: kv.map ?? "",
+    numPlayers: Number(kv.numplayers ?? 0),
+    maxPlayers: Number(kv.maxplayers ?? 0),
+    hostPort: Number(kv.hostport ?? 0),
+    hostIp: kv.hostip ?? "",
+    players,
+  };
+}
+
+/** Run the handshake + full stat exchange against `host:port` (UDP). */
+export async function queryServer(
+  host: string,
+  port: number,
+): Promise<QueryResponse> {
+  if (!Number.isInteger(port) || port < 1 || port > 65535) {
+    throw new Error(`Invalid query port: ${port}`);
+  }
+
+  const socket = dgram.createSocket("udp4");
+  // Only the low nibble of each byte is used by vanilla servers
+  const sessionId = Math.floor(Math.random() * 0x7fffffff) & 0x0f0f0f0f;
+  const started = performance.now();
+
+  try {
+    const handshake = await exchange(
+      socket,
+      host,
+      port,
+      encodeRequest(TYPE_HANDSHAKE, sessionId),
+      TYPE_HANDSHAKE,
+      sessionId,
+    );
+    const challenge = parseChallenge(handshake);
+    if (challenge === null) {
+      throw new Error(`Invalid Query handshake from ${host}:${port}`);
+    }
+
+    const payload = Buffer.alloc(8); // challenge token + 4 bytes padding
+    payload.writeInt32BE(challenge, 0);
+    const stat = await exchange(
+      socket,
+      host,
+      port,
+      encodeRequest(TYPE_STAT, sessionId, payload),
+      TYPE_STAT,
+      sessionId,
+    );
+
+    return {
+      ...parseFullStat(stat),
+      latencyMs: Math.round(performance.now() - started),
+    };
+  } finally {
+    socket.close();
+  }
+}
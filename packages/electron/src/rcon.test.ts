@@ -0,0 +1,78 @@
+import net from "node:net";
+import type { AddressInfo } from "node:net";
+import { RconConnection } from "./rcon.js";
+
+vi.mock("electron", () => ({}));
+
+function packet(length: number, id: number, type: number): Buffer {
+  const buf = Buffer.alloc(14);
+  buf.writeInt32LE(length, 0);
+  buf.writeInt32LE(id, 4);
+  buf.writeInt32LE(type, 8);
+  return buf;
+}
+
+/**
+ * Fake server that accepts any password, then answers every command with
+ * `reply` — a raw buffer, so it can be malformed.
+ */
+async function fakeServer(
+  reply: Buffer,
+): Promise<{ port: number; close: () => void }> {
+  const sockets = new Set<net.Socket>();
+  const server = net.createServer((socket) => {
+    sockets.add(socket);
+    socket.on("error", () => {});
+    let authed = false;
+    socket.on("data", (data) => {
+      const id = data.readInt32LE(4);
+      if (!authed) {
+        authed = true;
+        socket.write(packet(10, id, 2));
+      } else {
+        socket.write(reply);
+      }
+    });
+  });
+  await new Promise<void>((resolve) => server.listen(0, "127.0.0.1", resolve));
+  return {
+    port: (server.address() as AddressInfo).port,
+    close: () => {
+      for (const socket of sockets) socket.destroy();
+      server.close();
+    },
+  };
+}
+
+describe("RconConnection", () => {
+  it.each([
+    ["negative", -4],
+    ["too short", 6],
+    ["oversized", 1 << 20],
+  ])("drops the connection on a %s packet length", async (_label, length) => {
+    const server = await fakeServer(packet(length, 2, 0));
+    try {
+      const conn = await RconConnection.open("127.0.0.1", server.port, "pw");
+      await expect(conn.command("list")).rejects.toThrow(
+        /Invalid RCON packet length/,
+      );
+      expect(conn.isClosed).toBe(true);
+    } finally {
+      server.close();
+    }
+  });
+
+  it("limits commands by UTF-8 bytes, not characters", async () => {
+    const server = await fakeServer(packet(10, 2, 0));
+    try {
+      const conn = await RconConnection.open("127.0.0.1", server.port, "pw");
+      // 800 characters, 1600 bytes
+      await expect(conn.command("é".repeat(800))).rejects.toThrow(
+        "RCON command is too long",
+      );
+      conn.close();
+    } finally {
+      server.close();
+    }
+  });
+});
@@ -0,0 +1,322 @@
+/**
+ * Source RCON client for managed servers.
+ *
+ * Passwords never cross the IPC boundary on connect — they're stored once via
+ * `setRconPassword` in secure storage and looked up by server id.
+ *
+ * Wire format (little-endian): int32 length, int32 request id, int32 type,
+ * ASCII payload, two NUL bytes. Auth failure is signalled by request id -1.
+ *
+ * @module rcon
+ */
+
+import net from "node:net";
+import type { RconErrorCode, RconTestResult } from "@mc-server-manager/shared";
+import { saveSecret, getSecret, deleteSecret } from "./secure-storage.js";
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const PACKET_TYPE_AUTH = 3;
+const PACKET_TYPE_COMMAND = 2;
+
+/** Connect + auth + per-command response timeout (ms). */
+const RCON_TIMEOUT_MS = 10_000;
+
+/** Servers reject payloads above ~1446 bytes. */
+const MAX_COMMAND_LENGTH = 1446;
+
+/** Smallest valid packet: id, type and the two NULs, with an empty payload. */
+const MIN_PACKET_LENGTH = 10;
+
+/** Largest response packet the server sends (4096-byte payload). */
+const MAX_PACKET_LENGTH = 4110;
+
+export class RconError extends Error {
+  constructor(
+    message: string,
+    readonly code: RconErrorCode,
+  ) {
+    super(message);
+    this.name = "RconError";
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Credential storage
+// ---------------------------------------------------------------------------
+
+function rconPasswordKey(serverId: string): string {
+  return `rcon_password_${serverId}`;
+}
+
+export async function setRconPassword(
+  serverId: string,
+  password: string,
+): Promise<void> {
+  saveSecret(rconPasswordKey(serverId), password);
+}
+
+export async function clearRconPassword(serverId: string): Promise<void> {
+  await rconDisconnect(serverId);
+  deleteSecret(rconPasswordKey(serverId));
+}
+
+export async function hasRconPassword(serverId: string): Promise<boolean> {
+  return getSecret(rconPasswordKey(serverId)) !== null;
+}
+
+// ---------------------------------------------------------------------------
+// Connection
+// ---------------------------------------------------------------------------
+
+interface PendingRequest {
+  resolve: (payload: string) => void;
+  reject: (err: Error) => void;
+  timer: ReturnType<typeof setTimeout>;
+}
+
+function encodePacket(id: number, type: number, payload: string): Buffer {
+  const body = Buffer.from(payload, "utf-8");
+  const packet = Buffer.alloc(14 + body.length);
+  packet.writeInt32LE(10 + body.length, 0);
+  packet.writeInt32LE(id, 4);
+  packet.writeInt32LE(type, 8);
+  body.copy(packet, 12);
+  // Trailing two bytes are already zeroed by alloc
+  return packet;
+}
+
+export class RconConnection {
+  private buffer = Buffer.alloc(0);
+  private nextId = 1;
+  private pending = new Map<number, PendingRequest>();
+  private closed = false;
+
+  private constructor(private socket: net.Socket) {
+    socket.on("data", (chunk) => this.onData(chunk));
+    socket.on("close", () => this.failAll(new Error("RCON connection closed")));
+    socket.on("error", (err) => this.failAll(err));
+  }
+
+  /** Open a TCP connection and authenticate. Rejects on bad password. */
+  static async open(
+    host: string,
+    port: number,
+    password: string,
+  ): Promise<RconConnection> {
+    const socket = await new Promise<net.Socket>((resolve, reject) => {
+      const s = net.createConnection({ host, port });
+      const timer = setTimeout(() => {
+        s.destroy();
+        reject(
+          new RconError(
+            `Timed out connecting to RCON at ${host}:${port}`,
+            "timeout",
+          ),
+        );
+      }, RCON_TIMEOUT_MS);
+      s.once("connect", () => {
+        clearTimeout(timer);
+        resolve(s);
+      });
+      s.once("error", (err: NodeJS.ErrnoException) => {
+        clearTimeout(timer);
+        reject(
+          new RconError(
+            `Could not connect to RCON at ${host}:${port}: ${err.message}`,
+            err.code === "ECONNREFUSED"
+              ? "connection_refused"
+              : "connection_failed",
+          ),
+        );
+      });
+    });
+
+    const conn = new RconConnection(socket);
+    try {
+      await conn.send(PACKET_TYPE_AUTH, password);
+    } catch (err) {
+      conn.close();
+      throw err;
+    }
+    return conn;
+  }
+
+  /** Run a console command and return the server's response text. */
+  command(command: string): Promise<string> {
+    if (Buffer.byteLength(command, "utf-8") > MAX_COMMAND_LENGTH) {
+      return Promise.reject(new Error("RCON command is too long"));
+    }
+    return this.send(PACKET_TYPE_COMMAND, command);
+  }
+
+  close(): void {
+    if (this.closed) return;
+    this.closed = true;
+    this.socket.destroy();
+    this.failAll(new Error("RCON connection closed"));
+  }
+
+  get isClosed(): boolean {
+    return this.closed;
+  }
+
+  private send(type: number, payload: string): Promise<string> {
+    if (this.closed) {
+      return Promise.reject(new Error("RCON connection closed"));
+    }
+    const id = this.nextId++;
+    return new Promise((resolve, reject) => {
+      const timer = setTimeout(() => {
+        this.pending.delete(id);
+        reject(new RconError("Timed out waiting for RCON response", "timeout"));
+      }, RCON_TIMEOUT_MS);
+      this.pending.set(id, { resolve, reject, timer });
+      this.socket.write(encodePacket(id, type, payload));
+    });
+  }
+
+  private onData(chunk: Buffer): void {
+    this.buffer = Buffer.concat([this.buffer, chunk]);
+
+    while (this.buffer.length >= 4) {
+      const length = this.buffer.readInt32LE(0);
+      if (length < MIN_PACKET_LENGTH || length > MAX_PACKET_LENGTH) {
+        // Not an RCON server, or a corrupt stream we can't resync
+        this.abort(
+          new RconError(
+            `Invalid RCON packet length ${length}`,
+            "connection_failed",
+          ),
+        );
+        return;
+      }
+      if (this.buffer.length < 4 + length) break;
+
+      const id = this.buffer.readInt32LE(4);
+      const payload = this.buffer.toString("utf-8", 12, 4 + length - 2);
+      this.buffer = this.buffer.subarray(4 + length);
+
+      if (id === -1) {
+        // Auth rejected — the server doesn't echo our id, so fail the auth request
+        this.abort(
+          new RconError(
+            "RCON authentication failed: wrong password",
+            "auth_failed",
+          ),
+        );
+        return;
+      }
+
+      const request = this.pending.get(id);
+      if (!request) continue; // e.g. the empty RESPONSE_VALUE sent before an auth reply
+      clearTimeout(request.timer);
+      this.pending.delete(id);
+      request.resolve(payload);
+    }
+  }
+
+  /** Fail everything pending with `err` and drop the socket. */
+  private abort(err: Error): void {
+    this.failAll(err);
+    this.socket.destroy();
+  }
+
+  private failAll(err: Error): void {
+    for (const request of this.pending.values()) {
+      clearTimeout(request.timer);
+      request.reject(err);
+    }
+    this.pending.clear();
+    this.closed = true;
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Per-server connections
+// ---------------------------------------------------------------------------
+
+const connections = new Map<string, RconConnection>();
+
+/**
+ * Connect to a server's RCON using its stored password.
+ * Replaces any existing connection for the same server.
+ */
+export async function rconConnect(
+  serverId: string,
+  host: string,
+  port: number,
+): Promise<void> {
+  const password = getSecret(rconPasswordKey(serverId));
+  if (password === null) {
+    throw new Error("No RCON password saved for this server");
+  }
+
+  await rconDisconnect(serverId);
+  const conn = await RconConnection.open(host, port, password);
+  connections.set(serverId, conn);
+}
+
+export async function rconCommand(
+  serverId: string,
+  command: string,
+): Promise<string> {
+  const conn = connections.get(serverId);
+  if (!conn || conn.isClosed) {
+    connections.delete(serverId);
+    throw new Error("Not connected to RCON for this server");
+  }
+  return conn.command(command);
+}
+
+export async function rconDisconnect(serverId: string): Promise<void> {
+  connections.get(serverId)?.close();
+  connections.delete(serverId);
+}
+
+/**
+ * Connect, authenticate and (optionally) run `list`, then disconnect.
+ * For a "Test connection" button before credentials are saved — nothing is
+ * stored and no session is registered.
+ */
+export async function rconTest(
+  host: string,
+  port: number,
+  password: string,
+  runList = true,
+): Promise<RconTestResult> {
+  const result: RconTestResult = {
+    connected: false,
+    authenticated: false,
+    latencyMs: null,
+    listResponse: null,
+    errorCode: null,
+    error: null,
+  };
+
+  const started = performance.now();
+  let conn: RconConnection | null = null;
+  try {
+    conn = await RconConnection.open(host, port, password);
+    result.latencyMs = Math.round(performance.now() - started);
+    result.connected = true;
+    result.authenticated = true;
+    if (runList) {
+      result.listResponse = await conn.command("list");
+    }
+  } catch (err) {
+    if (err instanceof RconError) {
+      result.errorCode = err.code;
+      // A wrong password still means the port answered
+      result.connected = err.code === "auth_failed" || result.connected;
+    } else {
+      result.errorCode = "connection_failed";
+    }
+    result.error = err instanceof Error ? err.message : String(err);
+  } finally {
+    conn?.close();
+  }
+  return result;
+}
@@ -4,15 +4,18 @@
  * @module secure-storage
  */
 
-import { safeStorage, app } from "electron";
+import { safeStorage } from "electron";
+import { randomUUID } from "node:crypto";
 import { readFileSync, writeFileSync, mkdirSync } from "node:fs";
 import path from "node:path";
+import type { CredentialStorageStatus } from "@mc-server-manager/shared";
+import { getDataDir } from "./data-dir.js";
 
 let storageFilePath: string | null = null;
 
 function getStorageFilePath(): string {
   if (!storageFilePath) {
-    storageFilePath = path.join(app.getPath("userData"), "secure-storage.json");
+    storageFilePath = path.join(getDataDir(), "secure-storage.json");
   }
   return storageFilePath;
 }
@@ -89,6 +92,71 @@ export function getSecret(key: string): string | null {
   }
 }
 
+const PROBE_KEY = "credential_storage_probe";
+
+function storageBackend(): string {
+  if (process.platform === "darwin") return "keychain";
+  if (process.platform === "win32") return "dpapi";
+  return safeStorage.getSelectedStorageBackend();
+}
+
+/**
+ * Round-trip a throwaway secret to check that credentials are really stored
+ * encrypted. On Linux without a secret service Electron either has no
+ * encryption or falls back to a fixed key ("basic_text"); both count as
+ * unavailable, since secrets would then be readable by anyone with the file.
+ */
+export function checkCredentialStorage(): CredentialStorageStatus {
+  let backend = "unknown";
+  try {
+    backend = storageBackend();
+    if (!safeStorage.isEncryptionAvailable()) {
+      return {
+        available: false,
+        backend,
+        error: "OS encryption is unavailable; secrets are stored unencrypted",
+      };
+    }
+    if (backend === "basic_text") {
+      return {
+        available: false,
+        backend,
+        error:
+          "No secret service (GNOME Keyring, KWallet) found; secrets are " +
+          "encrypted with a fixed key",
+      };
+    }
+
+    const probe = randomUUID();
+    saveSecret(PROBE_KEY, probe);
+    if (getSecret(PROBE_KEY) !== probe) {
+      return {
+        available: false,
+        backend,
+        error: "A test secret could not be read back",
+      };
+    }
+    return { available: true, backend, error: null };
+  } catch (err) {
+    return {
+      available: false,
+      backend,
+      error: err instanceof Error ? err.message : String(err),
+    };
+  } finally {
+    try {
+      deleteSecret(PROBE_KEY);
+    } catch {
+      // Leftover probe is harmless
+    }
+  }
+}
+
+/** Whether anything is stored under `key`, even if it no longer decrypts. */
+export function hasSecret(key: string): boolean {
+  return key in readStorageFile();
+}
+
 /** Remove a secret from storage. No-op if key doesn't exist. Requires `app.whenReady()`. */
 export function deleteSecret(key: string): void {
   const data = readStorageFile();
@@ -0,0 +1,122 @@
+/**
+ * Ordered, time-boxed app shutdown: managed servers, then client games,
+ * then the backend, then exit. Used by the tray's Quit and scripted
+ * shutdown via IPC.
+ * @module shutdown
+ */
+
+import { app } from "electron";
+import type {
+  ShutdownPhase,
+  ShutdownPhaseResult,
+  ShutdownReport,
+  ServerStopOutcome,
+} from "@mc-server-manager/shared";
+import { beginShutdown, stopBackend, stopManagedServers } from "./backend.js";
+import { killAllGames } from "./launcher.js";
+import { stopTokenRefreshScheduler } from "./token-refresh.js";
+
+/** Servers get the longest budget — world saves can take a while. */
+const SERVERS_TIMEOUT_MS = 60_000;
+const GAMES_TIMEOUT_MS = 5_000;
+const BACKEND_TIMEOUT_MS = 15_000;
+
+let shutdownInProgress: Promise<ShutdownReport> | null = null;
+
+class PhaseTimeout extends Error {}
+
+async function runPhase(
+  phase: ShutdownPhase,
+  timeoutMs: number,
+  fn: () => Promise<Omit<ShutdownPhaseResult, "phase" | "durationMs">>,
+): Promise<ShutdownPhaseResult> {
+  const started = Date.now();
+  let timer: ReturnType<typeof setTimeout> | undefined;
+  const timeout = new Promise<never>((_, reject) => {
+    timer = setTimeout(() => reject(new PhaseTimeout()), timeoutMs);
+  });
+
+  try {
+    const result = await Promise.race([fn(), timeout]);
+    return { phase, durationMs: Date.now() - started, ...result };
+  } catch (err) {
+    const timedOut = err instanceof PhaseTimeout;
+    return {
+      phase,
+      status: timedOut ? "timed_out" : "failed",
+      durationMs: Date.now() - started,
+      detail: timedOut
+        ? `Gave up after ${timeoutMs}ms`
+        : err instanceof Error
+          ? err.message
+          : String(err),
+    };
+  } finally {
+    clearTimeout(timer);
+  }
+}
+
+function summarizeStopOutcomes(outcomes: ServerStopOutcome[]): string {
+  const count = (outcome: ServerStopOutcome["outcome"]) =>
+    outcomes.filter((o) => o.outcome === outcome).length;
+  return `${count("stopped")} stopped, ${count("killed")} killed, ${count("failed")} failed`;
+}
+
+async function runShutdown(): Promise<ShutdownReport> {
+  // Keep the supervisor from restarting the backend we're about to stop
+  beginShutdown();
+
+  await stopTokenRefreshScheduler();
+
+  const phases: ShutdownPhaseResult[] = [];
+
+  phases.push(
+    await runPhase("servers", SERVERS_TIMEOUT_MS, async () => {
+      const outcomes = await stopManagedServers();
+      if (!outcomes) {
+        return {
+          status: "skipped",
+          detail: "Backend is not managed by the app",
+        };
+      }
+      return { status: "done", detail: summarizeStopOutcomes(outcomes) };
+    }),
+  );
+
+  phases.push(
+    await runPhase("games", GAMES_TIMEOUT_MS, async () => {
+      const killed = killAllGames();
+      return { status: "done", detail: `Killed ${killed} game(s)` };
+    }),
+  );
+
+  phases.push(
+    await runPhase("backend", BACKEND_TIMEOUT_MS, async () => {
+      await stopBackend();
+      return { status: "done" };
+    }),
+  );
+
+  for (const result of phases) {
+    const detail = result.detail ? ` (${result.detail})` : "";
+    console.log(
+      `Shutdown ${result.phase}: ${result.status} in ${result.durationMs}ms${detail}`,
+    );
+  }
+
+  return { phases };
+}
+
+/**
+ * Stop everything in order and quit. Resolves with the report before the
+ * app exits so IPC callers receive it. Concurrent calls share one run.
+ */
+export function shutdownApp(): Promise<ShutdownReport> {
+  if (!shutdownInProgress) {
+    shutdownInProgress = runShutdown().then((report) => {
+      setImmediate(() => app.quit());
+      return report;
+    });
+  }
+  return shutdownInProgress;
+}
@@ -0,0 +1,80 @@
+/**
+ * Backend startup diagnostics for the desktop wrapper.
+ * `MC_LOG_FORMAT=json` switches output to one JSON object per line; only the
+ * wrapper's own lines are affected — the backend's pino output is untouched.
+ * @module startup-log
+ */
+
+import { existsSync } from "node:fs";
+
+export const LOG_JSON = process.env.MC_LOG_FORMAT === "json";
+
+let lastFailure: { error: string; failedAt: string } | null = null;
+
+/** Remember a failed start (or health-check timeout) for the UI. */
+export function recordStartupFailure(err: unknown): void {
+  lastFailure = {
+    error: err instanceof Error ? err.message : String(err),
+    failedAt: new Date().toISOString(),
+  };
+}
+
+export function getLastStartupFailure(): typeof lastFailure {
+  return lastFailure;
+}
+
+export interface PathCheck {
+  name: string;
+  path: string | null;
+  exists: boolean;
+}
+
+/** Resolved backend paths with existence checks — the usual culprits when startup fails. */
+export function checkBackendPaths(): PathCheck[] {
+  const entries: [string, string | undefined][] = [
+    ["dataDir", process.env.MC_DATA_DIR],
+    ["migrationsDir", process.env.MC_MIGRATIONS_DIR],
+    ["frontendDist", process.env.MC_FRONTEND_DIST],
+  ];
+  return entries.map(([name, p]) => ({
+    name,
+    path: p ?? null,
+    exists: p ? existsSync(p) : false,
+  }));
+}
+
+function describePath(check: PathCheck): string {
+  if (!check.path) return `  ${check.name}: (unset)`;
+  return `  ${check.name}: ${check.path}${check.exists ? "" : " (missing)"}`;
+}
+
+export function logStartup(
+  level: "info" | "error",
+  event: string,
+  fields: Record<string, unknown>,
+): void {
+  const write = level === "error" ? console.error : console.log;
+
+  if (LOG_JSON) {
+    write(
+      JSON.stringify({
+        time: new Date().toISOString(),
+        level,
+        event,
+        ...fields,
+      }),
+    );
+    return;
+  }
+
+  write(`[startup] ${event}`);
+  for (const [key, value] of Object.entries(fields)) {
+    if (key === "paths") {
+      for (const check of value as PathCheck[]) {
+        write(describePath(check));
+      }
+    } else {
+      write(`  ${key}: ${String(value)}`);
+    }
+  }
+}
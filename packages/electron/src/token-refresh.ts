@@ -0,0 +1,127 @@
+/**
+ * Background refresh of Minecraft access tokens, so launches don't have to
+ * wait on the Microsoft/Xbox auth chain. Every few minutes each signed-in
+ * account whose token expires soon is refreshed; failures are pushed to the
+ * renderer as `account-refresh-failed` so the UI can ask for a new sign-in.
+ * @module token-refresh
+ */
+
+import type {
+  AccountRefreshFailedEvent,
+  LauncherAccount,
+} from "@mc-server-manager/shared";
+import {
+  getAccessTokenExpiry,
+  getAccountTokenStatus,
+  msAuthRefresh,
+} from "./auth.js";
+import { backendUrl } from "./backend-port.js";
+import { emitToRenderers } from "./events.js";
+
+/** How often expiries are checked. */
+const CHECK_INTERVAL_MS = 60_000;
+
+/** Refresh accounts whose token expires within this window. */
+const REFRESH_WINDOW_MS = 5 * 60_000;
+
+/** After a failed refresh, leave the account alone for this long. */
+const RETRY_AFTER_FAILURE_MS = 15 * 60_000;
+
+/** Longest shutdown waits for an in-flight refresh. */
+const STOP_TIMEOUT_MS = 5_000;
+
+let timer: ReturnType<typeof setTimeout> | null = null;
+let running = false;
+let currentRun: Promise<void> | null = null;
+
+/** Account uuid → earliest time to try again after a failure. */
+const backoffUntil = new Map<string, number>();
+
+async function listAccounts(): Promise<LauncherAccount[]> {
+  const res = await fetch(backendUrl("/api/launcher/accounts"));
+  if (!res.ok) {
+    throw new Error(`Failed to load accounts: ${res.status}`);
+  }
+  return (await res.json()) as LauncherAccount[];
+}
+
+function needsRefresh(account: LauncherAccount, now: number): boolean {
+  if ((backoffUntil.get(account.uuid) ?? 0) > now) return false;
+
+  const status = getAccountTokenStatus(account.uuid);
+  if (status === "signed_out") return false;
+  if (status === "refreshable") return true;
+
+  const expiresAt = getAccessTokenExpiry(account.uuid);
+  return expiresAt === null || expiresAt - now <= REFRESH_WINDOW_MS;
+}
+
+async function refreshDueAccounts(): Promise<void> {
+  let accounts: LauncherAccount[];
+  try {
+    accounts = await listAccounts();
+  } catch (err) {
+    // Backend restarting — try again next tick
+    console.warn("Token refresh: could not list accounts:", err);
+    return;
+  }
+
+  for (const account of accounts) {
+    if (!running) return;
+    if (!needsRefresh(account, Date.now())) continue;
+
+    try {
+      await msAuthRefresh(account.uuid);
+      backoffUntil.delete(account.uuid);
+    } catch (err) {
+      backoffUntil.set(account.uuid, Date.now() + RETRY_AFTER_FAILURE_MS);
+      const error = err instanceof Error ? err.message : String(err);
+      console.warn(`Token refresh failed for ${account.username}:`, error);
+      emitToRenderers("account-refresh-failed", {
+        accountUuid: account.uuid,
+        username: account.username,
+        error,
+      } satisfies AccountRefreshFailedEvent);
+    }
+  }
+}
+
+function scheduleNext(delayMs: number): void {
+  timer = setTimeout(() => {
+    timer = null;
+    currentRun = refreshDueAccounts().finally(() => {
+      currentRun = null;
+      if (running) scheduleNext(CHECK_INTERVAL_MS);
+    });
+  }, delayMs);
+}
+
+/** Start checking expiries. Needs the backend to be reachable. */
+export function startTokenRefreshScheduler(): void {
+  if (running) return;
+  running = true;
+  scheduleNext(0);
+}
+
+/**
+ * Stop the scheduler. Waits (up to STOP_TIMEOUT_MS) for an in-flight
+ * refresh of at most one account, so shutdown doesn't cut a token write
+ * in half.
+ */
+export async function stopTokenRefreshScheduler(): Promise<void> {
+  running = false;
+  if (timer) {
+    clearTimeout(timer);
+    timer = null;
+  }
+  if (!currentRun) return;
+
+  let waitTimer: ReturnType<typeof setTimeout> | undefined;
+  await Promise.race([
+    currentRun,
+    new Promise<void>((resolve) => {
+      waitTimer = setTimeout(resolve, STOP_TIMEOUT_MS);
+    }),
+  ]);
+  clearTimeout(waitTimer);
+}
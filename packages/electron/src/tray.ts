@@ -1,11 +1,106 @@
-import { Tray, Menu, nativeImage, type BrowserWindow } from "electron";
+import {
+  Tray,
+  Menu,
+  nativeImage,
+  type BrowserWindow,
+  type MenuItemConstructorOptions,
+} from "electron";
 import path from "node:path";
 import { fileURLToPath } from "node:url";
+import type { InstanceSummary } from "@mc-server-manager/shared";
+import {
+  getLastLaunchedInstanceId,
+  getLastPrepareResult,
+  getRunningGames,
+  launchGame,
+  listInstances,
+  onRunningGamesChanged,
+} from "./launcher.js";
+import { notify } from "./notifications.js";
 
 const __dirname = path.dirname(fileURLToPath(import.meta.url));
 
 let tray: Tray | null = null;
 
+/** The last-launched instance, if it still exists. */
+async function findLastInstance(): Promise<InstanceSummary | null> {
+  const lastId = getLastLaunchedInstanceId();
+  if (!lastId) return null;
+  try {
+    const instances = await listInstances();
+    return instances.find((i) => i.id === lastId) ?? null;
+  } catch {
+    // Backend not up yet; the menu is rebuilt once a game starts or exits
+    return null;
+  }
+}
+
+/**
+ * Launch with the default account and the files the last launch used.
+ * The user is looking at the tray, not the window, so the outcome is a
+ * notification either way.
+ */
+async function launchFromTray(
+  mainWindow: BrowserWindow,
+  instance: InstanceSummary,
+): Promise<void> {
+  const showWindow = () => {
+    mainWindow.show();
+    mainWindow.focus();
+  };
+  const prepared = getLastPrepareResult(instance.id);
+  if (!prepared || prepared.versionId !== instance.mcVersion) {
+    void notify(
+      `Couldn't launch ${instance.name}`,
+      "Its game files need updating; launch it from the app once.",
+      { onClick: showWindow },
+    );
+    return;
+  }
+  try {
+    await launchGame(instance.id, null, prepared);
+    void notify(`${instance.name} launched`, "Using the default account");
+  } catch (err) {
+    void notify(
+      `Couldn't launch ${instance.name}`,
+      err instanceof Error ? err.message : String(err),
+      { onClick: showWindow },
+    );
+  }
+}
+
+async function refreshMenu(
+  mainWindow: BrowserWindow,
+  onQuit: () => void,
+): Promise<void> {
+  const last = await findLastInstance();
+  const running =
+    last !== null && getRunningGames().some((g) => g.instanceId === last.id);
+
+  const template: MenuItemConstructorOptions[] = [
+    {
+      label: "Show Window",
+      click: () => {
+        mainWindow.show();
+        mainWindow.focus();
+      },
+    },
+    {
+      label: last ? `Launch ${last.name}` : "Launch Last Instance",
+      enabled: last !== null && !running,
+      click: () => {
+        if (last) void launchFromTray(mainWindow, last);
+      },
+    },
+    { type: "separator" },
+    {
+      label: "Quit",
+      click: onQuit,
+    },
+  ];
+  tray?.setContextMenu(Menu.buildFromTemplate(template));
+}
+
 export function createTray(
   mainWindow: BrowserWindow,
   onQuit: () => void,
@@ -24,23 +119,10 @@ export function createTray(
     tray = new Tray(icon);
   }
 
-  const contextMenu = Menu.buildFromTemplate([
-    {
-      label: "Show Window",
-      click: () => {
-        mainWindow.show();
-        mainWindow.focus();
-      },
-    },
-    { type: "separator" },
-    {
-      label: "Quit",
-      click: onQuit,
-    },
-  ]);
-
   tray.setToolTip("MC Server Manager");
-  tray.setContextMenu(contextMenu);
+  void refreshMenu(mainWindow, onQuit);
+  // Launches (from anywhere) and exits change what quick launch offers
+  onRunningGamesChanged(() => void refreshMenu(mainWindow, onQuit));
 
   tray.on("click", () => {
     if (mainWindow.isVisible()) {
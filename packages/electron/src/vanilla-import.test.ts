@@ -0,0 +1,65 @@
+import { parseVanillaLauncherAccounts } from "./vanilla-import.js";
+
+vi.mock("electron", () => ({}));
+
+describe("parseVanillaLauncherAccounts", () => {
+  const UUID = "069a79f444e94726a5befca90e38aaf5";
+
+  it("extracts profiles and ignores tokens", () => {
+    const result = parseVanillaLauncherAccounts({
+      accounts: {
+        abc: {
+          accessToken: "secret",
+          type: "Xbox",
+          username: "me@example.com",
+          minecraftProfile: { id: UUID, name: "Notch" },
+        },
+      },
+    });
+    expect(result.profiles).toEqual([
+      { uuid: UUID, username: "Notch", accountType: "msa" },
+    ]);
+    expect(result.skipped).toEqual([]);
+  });
+
+  it("normalizes dashed UUIDs and drops duplicates", () => {
+    const dashed = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+    const result = parseVanillaLauncherAccounts({
+      accounts: {
+        a: { minecraftProfile: { id: dashed, name: "Notch" } },
+        b: { minecraftProfile: { id: UUID, name: "Notch" } },
+      },
+    });
+    expect(result.profiles).toHaveLength(1);
+    expect(result.profiles[0].uuid).toBe(UUID);
+  });
+
+  it("skips accounts without a Java profile", () => {
+    const result = parseVanillaLauncherAccounts({
+      accounts: {
+        a: { username: "nogame@example.com", type: "Xbox" },
+        b: { username: "bad", minecraftProfile: { id: "nope", name: "x" } },
+      },
+    });
+    expect(result.profiles).toEqual([]);
+    expect(result.skipped).toEqual([
+      { name: "nogame@example.com", reason: "No Minecraft Java profile" },
+      { name: "bad", reason: "Malformed profile" },
+    ]);
+  });
+
+  it("marks Mojang accounts as legacy", () => {
+    const result = parseVanillaLauncherAccounts({
+      accounts: {
+        a: { type: "Mojang", minecraftProfile: { id: UUID, name: "Notch" } },
+      },
+    });
+    expect(result.profiles[0].accountType).toBe("legacy");
+  });
+
+  it("rejects files without an accounts object", () => {
+    expect(() => parseVanillaLauncherAccounts({ profiles: {} })).toThrow(
+      /no accounts object/,
+    );
+  });
+});
@@ -0,0 +1,131 @@
+/**
+ * Import accounts from the official Minecraft launcher's
+ * `launcher_accounts.json`. Only profiles are copied; the tokens in that
+ * file belong to Mojang's client id, so imported accounts need a fresh
+ * sign-in before they can launch.
+ * @module vanilla-import
+ */
+
+import { readFile } from "node:fs/promises";
+import type {
+  LauncherAccount,
+  VanillaAccountImportResult,
+} from "@mc-server-manager/shared";
+import { getAccountTokenStatus } from "./auth.js";
+import { backendUrl } from "./backend-port.js";
+
+/** A profile found in the official launcher's `launcher_accounts.json` */
+export interface VanillaLauncherProfile {
+  /** Undashed profile UUID, as used for our account index */
+  uuid: string;
+  username: string;
+  accountType: LauncherAccount["accountType"];
+}
+
+/**
+ * Extract usable profiles from the official launcher's
+ * `launcher_accounts.json`. Tokens are ignored — they're bound to Mojang's
+ * client id and can't be reused. Entries without a Java profile (e.g. the
+ * account doesn't own the game) are returned in `skipped`.
+ */
+export function parseVanillaLauncherAccounts(data: unknown): {
+  profiles: VanillaLauncherProfile[];
+  skipped: Array<{ name: string; reason: string }>;
+} {
+  const profiles: VanillaLauncherProfile[] = [];
+  const skipped: Array<{ name: string; reason: string }> = [];
+
+  const accounts =
+    data && typeof data === "object" && "accounts" in data
+      ? (data as { accounts: unknown }).accounts
+      : null;
+  if (!accounts || typeof accounts !== "object" || Array.isArray(accounts)) {
+    throw new Error("Not a launcher_accounts.json file: no accounts object");
+  }
+
+  const seen = new Set<string>();
+  for (const [localId, raw] of Object.entries(accounts)) {
+    const entry = (raw ?? {}) as {
+      username?: unknown;
+      type?: unknown;
+      minecraftProfile?: { id?: unknown; name?: unknown };
+    };
+    const label = typeof entry.username === "string" ? entry.username : localId;
+    const profile = entry.minecraftProfile;
+    const uuid =
+      typeof profile?.id === "string" ? profile.id.replace(/-/g, "") : "";
+    const username = typeof profile?.name === "string" ? profile.name : "";
+
+    if (!profile) {
+      skipped.push({ name: label, reason: "No Minecraft Java profile" });
+    } else if (!/^[0-9a-f]{32}$/i.test(uuid) || !username) {
+      skipped.push({ name: label, reason: "Malformed profile" });
+    } else if (!seen.has(uuid)) {
+      seen.add(uuid);
+      profiles.push({
+        uuid: uuid.toLowerCase(),
+        username,
+        accountType: entry.type === "Mojang" ? "legacy" : "msa",
+      });
+    }
+  }
+
+  return { profiles, skipped };
+}
+
+async function listAccounts(): Promise<LauncherAccount[]> {
+  const res = await fetch(backendUrl("/api/launcher/accounts"));
+  if (!res.ok) {
+    throw new Error(`Failed to load accounts: ${await res.text()}`);
+  }
+  return (await res.json()) as LauncherAccount[];
+}
+
+export async function importVanillaAccounts(
+  filePath: string,
+): Promise<VanillaAccountImportResult> {
+  let data: unknown;
+  try {
+    data = JSON.parse(await readFile(filePath, "utf-8"));
+  } catch (err) {
+    const reason = err instanceof Error ? err.message : String(err);
+    throw new Error(`Could not read ${filePath}: ${reason}`);
+  }
+  const { profiles, skipped } = parseVanillaLauncherAccounts(data);
+
+  const known = new Map((await listAccounts()).map((a) => [a.uuid, a]));
+  const result: VanillaAccountImportResult = {
+    imported: [],
+    needsReauth: [],
+    existing: [],
+    skipped,
+  };
+
+  for (const profile of profiles) {
+    let account = known.get(profile.uuid);
+    if (account) {
+      result.existing.push(account);
+    } else {
+      const res = await fetch(backendUrl("/api/launcher/accounts"), {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify(profile),
+      });
+      if (!res.ok) {
+        result.skipped.push({
+          name: profile.username,
+          reason: `Could not save account: ${await res.text()}`,
+        });
+        continue;
+      }
+      account = (await res.json()) as LauncherAccount;
+      result.imported.push(account);
+    }
+
+    if (getAccountTokenStatus(account.uuid) === "signed_out") {
+      result.needsReauth.push(account);
+    }
+  }
+
+  return result;
+}
@@ -0,0 +1,28 @@
+/**
+ * What every app window shares: the renderer URL and the preload setup.
+ * @module windows
+ */
+
+import { app, type WebPreferences } from "electron";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+import { getBackendPort } from "./backend-port.js";
+import { backendOrigin } from "./backend.js";
+
+const __dirname = path.dirname(fileURLToPath(import.meta.url));
+
+/** Vite's dev server in development, the backend's static build otherwise. */
+export function rendererUrl(): string {
+  return app.isPackaged ? backendOrigin() : "http://localhost:5173";
+}
+
+export function rendererWebPreferences(): WebPreferences {
+  return {
+    preload: path.join(__dirname, "preload.cjs"),
+    // Read synchronously by preload so the renderer never guesses the port
+    additionalArguments: [`--backend-port=${getBackendPort()}`],
+    contextIsolation: true,
+    nodeIntegration: false,
+    sandbox: false,
+  };
+}
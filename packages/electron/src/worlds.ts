@@ -0,0 +1,230 @@
+/**
+ * Singleplayer world browser: lists the worlds in an instance's `saves`
+ * folder with what level.dat says about them, opens a world's folder and
+ * edits the few level.dat fields that are safe to change offline.
+ * @module worlds
+ */
+
+import { shell } from "electron";
+import type { Dirent } from "node:fs";
+import {
+  copyFile,
+  open,
+  readdir,
+  readFile,
+  rename,
+  stat,
+  unlink,
+  writeFile,
+} from "node:fs/promises";
+import path from "node:path";
+import { gunzipSync, gzipSync } from "node:zlib";
+import type {
+  LevelDatFields,
+  LevelDatSummary,
+  WorldInfo,
+} from "@mc-server-manager/shared";
+import { getInstancePaths, getRunningGames } from "./launcher.js";
+import {
+  applyLevelDatChanges,
+  decodeNbt,
+  encodeNbt,
+  parseLevelDat,
+  readLevelDatFields,
+  readNbt,
+  validateLevelDatChanges,
+  type NbtTagCompound,
+} from "./nbt.js";
+
+function gunzipIfNeeded(raw: Buffer): Buffer {
+  // level.dat is gzipped; tolerate the odd tool that writes it raw
+  return raw[0] === 0x1f && raw[1] === 0x8b ? gunzipSync(raw) : raw;
+}
+
+async function readLevelSummary(filePath: string): Promise<LevelDatSummary> {
+  return parseLevelDat(readNbt(gunzipIfNeeded(await readFile(filePath))));
+}
+
+async function readWorld(
+  savesDir: string,
+  folder: string,
+): Promise<WorldInfo> {
+  const worldDir = path.join(savesDir, folder);
+  const fallback: WorldInfo = {
+    folder,
+    path: worldDir,
+    name: folder,
+    lastPlayed: null,
+    gameMode: null,
+    hardcore: false,
+    version: null,
+    damaged: false,
+  };
+
+  try {
+    const level = await readLevelSummary(path.join(worldDir, "level.dat"));
+    return { ...fallback, ...level, name: level.name || folder };
+  } catch (err) {
+    // The game keeps the previous save as level.dat_old; a world whose
+    // level.dat was cut off mid-write usually still has that
+    const error = err instanceof Error ? err.message : String(err);
+    try {
+      const level = await readLevelSummary(
+        path.join(worldDir, "level.dat_old"),
+      );
+      return {
+        ...fallback,
+        ...level,
+        name: level.name || folder,
+        damaged: true,
+        error: `level.dat unreadable (${error}); showing level.dat_old`,
+      };
+    } catch {
+      // Last resort: the folder's mtime is close to when it was last saved
+      const lastPlayed = await stat(worldDir)
+        .then((info) => info.mtime.toISOString())
+        .catch(() => null);
+      return { ...fallback, lastPlayed, damaged: true, error };
+    }
+  }
+}
+
+/** The instance's worlds, most recently played first. */
+export async function listWorlds(instanceId: string): Promise<WorldInfo[]> {
+  const savesDir = path.join(
+    (await getInstancePaths(instanceId)).gameDir,
+    "saves",
+  );
+
+  let entries: Dirent[];
+  try {
+    entries = await readdir(savesDir, { withFileTypes: true });
+  } catch {
+    // Never launched, or no world created yet
+    return [];
+  }
+
+  const worlds = await Promise.all(
+    entries
+      .filter((entry) => entry.isDirectory())
+      .map((entry) => readWorld(savesDir, entry.name)),
+  );
+  return worlds.sort((a, b) =>
+    (b.lastPlayed ?? "").localeCompare(a.lastPlayed ?? ""),
+  );
+}
+
+/** A world's directory; throws unless `folder` names an existing world. */
+async function resolveWorldDir(
+  instanceId: string,
+  folder: string,
+): Promise<string> {
+  // A folder name, never a path — keep the renderer inside saves/
+  if (
+    !folder ||
+    folder !== path.basename(folder) ||
+    folder === "." ||
+    folder === ".."
+  ) {
+    throw new Error(`Invalid world folder: ${folder}`);
+  }
+  const worldDir = path.join(
+    (await getInstancePaths(instanceId)).gameDir,
+    "saves",
+    folder,
+  );
+  try {
+    if (!(await stat(worldDir)).isDirectory()) throw new Error();
+  } catch {
+    throw new Error(`World "${folder}" not found`);
+  }
+  return worldDir;
+}
+
+/** Open a world's folder in the file manager. */
+export async function openWorldFolder(
+  instanceId: string,
+  folder: string,
+): Promise<void> {
+  const worldDir = await resolveWorldDir(instanceId, folder);
+  const error = await shell.openPath(worldDir);
+  if (error) {
+    throw new Error(`Failed to open world folder: ${error}`);
+  }
+}
+
+async function decodeLevelDat(worldDir: string): Promise<NbtTagCompound> {
+  const raw = await readFile(path.join(worldDir, "level.dat"));
+  return decodeNbt(gunzipIfNeeded(raw));
+}
+
+/** The editable (and a few read-only) fields of a world's level.dat. */
+export async function readLevelDat(
+  instanceId: string,
+  folder: string,
+): Promise<LevelDatFields> {
+  const worldDir = await resolveWorldDir(instanceId, folder);
+  return readLevelDatFields(await decodeLevelDat(worldDir));
+}
+
+/**
+ * The game holds session.lock while a world is open. Games we launched are
+ * checked directly; on Windows the lock is exclusive, so failing to open the
+ * file also catches worlds opened by another launcher. (Java's locks are
+ * advisory on macOS/Linux and invisible from here.)
+ */
+async function assertWorldNotInUse(
+  instanceId: string,
+  worldDir: string,
+): Promise<void> {
+  if (getRunningGames().some((game) => game.instanceId === instanceId)) {
+    throw new Error("Close the game before editing this world");
+  }
+  if (process.platform !== "win32") return;
+  try {
+    const handle = await open(path.join(worldDir, "session.lock"), "r+");
+    await handle.close();
+  } catch (err) {
+    const code = (err as NodeJS.ErrnoException).code;
+    if (code === "EBUSY" || code === "EPERM" || code === "EACCES") {
+      throw new Error("The world is open in another game; close it first");
+    }
+    // ENOENT: never opened by a version that writes session.lock
+  }
+}
+
+/**
+ * Change a world's name, game mode, difficulty or cheats setting. `changes`
+ * is validated strictly; the world must not be open. The original is kept
+ * as level.dat.<timestamp>.bak and the new file is written to a temp file
+ * first, so a failure never leaves a half-written level.dat.
+ */
+export async function writeLevelDat(
+  instanceId: string,
+  folder: string,
+  changes: unknown,
+): Promise<LevelDatFields> {
+  const validated = validateLevelDatChanges(changes);
+  const worldDir = await resolveWorldDir(instanceId, folder);
+  await assertWorldNotInUse(instanceId, worldDir);
+
+  const levelDat = path.join(worldDir, "level.dat");
+  const root = await decodeLevelDat(worldDir);
+  applyLevelDatChanges(root, validated);
+  const encoded = encodeNbt(root);
+  // Catch encoder bugs before they can cost someone a world
+  const fields = readLevelDatFields(decodeNbt(encoded));
+
+  const stamp = new Date().toISOString().replace(/[:.]/g, "-");
+  await copyFile(levelDat, `${levelDat}.${stamp}.bak`);
+
+  const temp = `${levelDat}.tmp`;
+  try {
+    await writeFile(temp, gzipSync(encoded));
+    await rename(temp, levelDat);
+  } catch (err) {
+    await unlink(temp).catch(() => {});
+    throw err;
+  }
+  return fields;
+}
@@ -10,6 +10,7 @@ import { AppSettings } from "./pages/AppSettings";
 import { Mods } from "./pages/Mods";
 import Launcher from "./pages/Launcher";
 import InstanceDetail from "./pages/InstanceDetail";
+import LogWindow from "./pages/LogWindow";
 import Setup from "./pages/Setup";
 import Login from "./pages/Login";
 import Register from "./pages/Register";
@@ -34,6 +35,14 @@ export function App() {
           <Route path="setup" element={<Setup />} />
           <Route path="login" element={<Login />} />
           <Route path="register" element={<Register />} />
+          <Route
+            path="log-window/:id"
+            element={
+              <ProtectedRoute>
+                <LogWindow />
+              </ProtectedRoute>
+            }
+          />
 
           <Route
             element={
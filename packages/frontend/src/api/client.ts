@@ -1,13 +1,17 @@
 import type {
   ServerWithStatus,
+  ServerStopOutcome,
   Server,
   ServerType,
   CreateServerRequest,
   UpdateServerRequest,
   McVersion,
+  McVersionSummary,
   VersionInfo,
   JavaInfo,
   SystemInfo,
+  DiskSpaceInfo,
+  RamRecommendation,
   DownloadJob,
   DownloadRequest,
   ServerPropertiesResponse,
@@ -143,6 +147,13 @@ export const api = {
     return request<ServerWithStatus>(`/api/servers/${id}`);
   },
 
+  /** First free port from `start`, for pre-filling a new server's port */
+  findFreePort(start = 25565, count = 100): Promise<{ port: number }> {
+    return request<{ port: number }>(
+      `/api/servers/free-port?start=${start}&count=${count}`,
+    );
+  },
+
   createServer(data: CreateServerRequest): Promise<Server> {
     return request<Server>("/api/servers", {
       method: "POST",
@@ -162,8 +173,18 @@ export const api = {
     return request("/api/servers/" + id + "/start", { method: "POST" });
   },
 
-  stopServer(id: string): Promise<{ status: string }> {
-    return request("/api/servers/" + id + "/stop", { method: "POST" });
+  stopServer(id: string, timeoutSecs?: number): Promise<{ status: string }> {
+    return request("/api/servers/" + id + "/stop", {
+      method: "POST",
+      body: JSON.stringify({ timeoutSecs }),
+    });
+  },
+
+  stopAllServers(graceful = true): Promise<ServerStopOutcome[]> {
+    return request("/api/servers/stop-all", {
+      method: "POST",
+      body: JSON.stringify({ graceful }),
+    });
   },
 
   restartServer(id: string): Promise<{ status: string }> {
@@ -273,6 +294,23 @@ export const api = {
     return request<SystemInfo>("/api/system/info");
   },
 
+  getRamRecommendation(
+    mcVersion?: string,
+    loader?: string,
+  ): Promise<RamRecommendation> {
+    const params = new URLSearchParams();
+    if (mcVersion) params.set("mcVersion", mcVersion);
+    if (loader) params.set("loader", loader);
+    const qs = params.toString();
+    return request<RamRecommendation>(
+      `/api/system/ram-recommendation${qs ? `?${qs}` : ""}`,
+    );
+  },
+
+  getDiskSpace(): Promise<DiskSpaceInfo> {
+    return request<DiskSpaceInfo>("/api/system/disk-space");
+  },
+
   // Settings
   getSettings(): Promise<AppSettings> {
     return request<AppSettings>("/api/system/settings");
@@ -501,6 +539,12 @@ export const api = {
     return request<MinecraftVersion[]>(`/api/launcher/versions${qs}`);
   },
 
+  getMcVersions(includeSnapshots = false): Promise<McVersionSummary[]> {
+    return request<McVersionSummary[]>(
+      `/api/launcher/versions/summary?includeSnapshots=${includeSnapshots}`,
+    );
+  },
+
   prepareLaunch(instanceId: string): Promise<PrepareJob> {
     return request<PrepareJob>(`/api/launcher/prepare/${instanceId}`, {
       method: "POST",
@@ -610,4 +654,17 @@ export const api = {
       method: "DELETE",
     });
   },
+
+  getDefaultLauncherAccount(): Promise<LauncherAccount | null> {
+    return request<LauncherAccount | null>("/api/launcher/accounts/default");
+  },
+
+  setDefaultLauncherAccount(
+    accountId: string | null,
+  ): Promise<LauncherAccount | null> {
+    return request<LauncherAccount | null>("/api/launcher/accounts/default", {
+      method: "PUT",
+      body: JSON.stringify({ accountId }),
+    });
+  },
 };
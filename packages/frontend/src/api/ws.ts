@@ -27,7 +27,7 @@ class WsClient {
     if (!this.url) {
       if (isDesktop()) {
         const base = getBackendBaseUrlSync().replace(/^http/, "ws");
-        this.url = `${base || "ws://localhost:3001"}/ws`;
+        this.url = `${base}/ws`;
       } else {
         const proto = window.location.protocol === "https:" ? "wss:" : "ws:";
         this.url = `${proto}//${window.location.host}/ws`;
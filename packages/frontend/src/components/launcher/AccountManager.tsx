@@ -7,10 +7,13 @@ import {
   Plus,
   CheckCircle2,
   AlertCircle,
+  AlertTriangle,
   Monitor,
 } from "lucide-react";
 import { toast } from "sonner";
 import type {
+  AccountRefreshFailedEvent,
+  CredentialStorageStatus,
   LauncherAccount,
   MSAuthDeviceCode,
 } from "@mc-server-manager/shared";
@@ -54,6 +57,8 @@ export function AccountManager({
   const [authPhase, setAuthPhase] = useState<AuthPhase>("idle");
   const [deviceCode, setDeviceCode] = useState<MSAuthDeviceCode | null>(null);
   const [authError, setAuthError] = useState<string | null>(null);
+  const [credentialStorage, setCredentialStorage] =
+    useState<CredentialStorageStatus | null>(null);
   const pollTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
   const expiryTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
   const flowIdRef = useRef(0);
@@ -74,6 +79,14 @@ export function AccountManager({
     fetchAccounts();
   }, [fetchAccounts]);
 
+  useEffect(() => {
+    if (!isDesktop()) return;
+    window
+      .electronAPI!.checkCredentialStorage()
+      .then(setCredentialStorage)
+      .catch(() => {});
+  }, []);
+
   const clearTimers = useCallback(() => {
     if (pollTimerRef.current) {
       clearTimeout(pollTimerRef.current);
@@ -89,105 +102,131 @@ export function AccountManager({
     return clearTimers;
   }, [clearTimers]);
 
-  const startAuth = async () => {
-    if (!isDesktop()) {
-      toast.error("Authentication requires the desktop app");
-      return;
-    }
+  const runAuthFlow = (code: MSAuthDeviceCode) => {
+    const thisFlow = ++flowIdRef.current;
+    intervalRef.current = (code.interval ?? 5) * 1000;
 
-    setAuthPhase("requesting");
-    setAuthError(null);
+    setDeviceCode(code);
+    setAuthPhase("awaiting");
 
-    try {
-      const code = await window.electronAPI!.msAuthStart();
-      const thisFlow = ++flowIdRef.current;
-      intervalRef.current = (code.interval ?? 5) * 1000;
+    expiryTimerRef.current = setTimeout(
+      () => {
+        if (flowIdRef.current !== thisFlow) return;
+        clearTimers();
+        setAuthPhase("error");
+        setAuthError("Code expired. Please try again.");
+      },
+      (code.expiresIn ?? 900) * 1000,
+    );
+
+    const poll = async () => {
+      if (flowIdRef.current !== thisFlow) return;
 
-      setDeviceCode(code);
-      setAuthPhase("awaiting");
+      try {
+        const status = await window.electronAPI!.msAuthPoll();
+        if (flowIdRef.current !== thisFlow) return;
 
-      expiryTimerRef.current = setTimeout(
-        () => {
-          if (flowIdRef.current !== thisFlow) return;
+        if (status.status === "pending") {
+          pollTimerRef.current = setTimeout(poll, intervalRef.current);
+        } else if (status.status === "slow_down") {
+          intervalRef.current += 5000;
+          pollTimerRef.current = setTimeout(poll, intervalRef.current);
+        } else if (status.status === "complete" && status.account) {
           clearTimers();
-          setAuthPhase("error");
-          setAuthError("Code expired. Please try again.");
-        },
-        (code.expiresIn ?? 900) * 1000,
-      );
 
-      const poll = async () => {
-        if (flowIdRef.current !== thisFlow) return;
+          let savedAccount: LauncherAccount | null = null;
+          try {
+            savedAccount = await api.createLauncherAccount({
+              username: status.account.username,
+              uuid: status.account.uuid,
+              accountType: status.account.accountType,
+            });
+          } catch {
+            // Non-fatal: tokens are saved on the Electron side.
+            // Account will be invisible until re-added, but no data loss.
+          }
 
-        try {
-          const status = await window.electronAPI!.msAuthPoll();
-          if (flowIdRef.current !== thisFlow) return;
-
-          if (status.status === "pending") {
-            pollTimerRef.current = setTimeout(poll, intervalRef.current);
-          } else if (status.status === "slow_down") {
-            intervalRef.current += 5000;
-            pollTimerRef.current = setTimeout(poll, intervalRef.current);
-          } else if (status.status === "complete" && status.account) {
-            clearTimers();
-
-            let savedAccount: LauncherAccount | null = null;
-            try {
-              savedAccount = await api.createLauncherAccount({
-                username: status.account.username,
-                uuid: status.account.uuid,
-                accountType: status.account.accountType,
-              });
-            } catch {
-              // Non-fatal: tokens are saved on the Electron side.
-              // Account will be invisible until re-added, but no data loss.
-            }
-
-            setAuthPhase("success");
-            toast.success(`Signed in as ${status.account.username}`);
-            await fetchAccounts();
-            if (savedAccount) {
-              onSelectAccount(savedAccount.id);
-            }
-
-            setTimeout(() => {
-              setAuthPhase("idle");
-              setDeviceCode(null);
-            }, 2000);
-          } else if (status.status === "expired") {
-            clearTimers();
-            setAuthPhase("error");
-            setAuthError("Code expired. Please try again.");
-          } else if (status.status === "error") {
-            clearTimers();
-            setAuthPhase("error");
-            setAuthError(status.error ?? "Authentication failed");
+          setAuthPhase("success");
+          toast.success(`Signed in as ${status.account.username}`);
+          await fetchAccounts();
+          if (savedAccount) {
+            onSelectAccount(savedAccount.id);
           }
-        } catch (err) {
+
+          setTimeout(() => {
+            setAuthPhase("idle");
+            setDeviceCode(null);
+          }, 2000);
+        } else if (status.status === "expired") {
+          clearTimers();
+          setAuthPhase("error");
+          setAuthError("Code expired. Please try again.");
+        } else if (status.status === "error") {
           clearTimers();
           setAuthPhase("error");
-          const msg = err instanceof Error ? err.message : "Unknown error";
-          setAuthError(msg);
+          setAuthError(status.error ?? "Authentication failed");
         }
-      };
+      } catch (err) {
+        clearTimers();
+        setAuthPhase("error");
+        const msg = err instanceof Error ? err.message : "Unknown error";
+        setAuthError(msg);
+      }
+    };
 
-      poll();
+    poll();
+  };
+
+  const startAuth = async () => {
+    if (!isDesktop()) {
+      toast.error("Authentication requires the desktop app");
+      return;
+    }
+
+    setAuthPhase("requesting");
+    setAuthError(null);
+
+    try {
+      const code = await window.electronAPI!.msAuthStart();
+      runAuthFlow(code);
     } catch {
       setAuthPhase("error");
       setAuthError("Failed to start authentication");
     }
   };
 
+  // Background token refresh failed — the user has to sign in again
+  useEffect(() => {
+    const electronAPI = window.electronAPI;
+    if (!electronAPI) return;
+    return electronAPI.on("account-refresh-failed", (payload) => {
+      const event = payload as AccountRefreshFailedEvent;
+      toast.error(`${event.username} needs to sign in again`, {
+        description: event.error,
+      });
+    });
+  }, []);
+
+  // A sign-in interrupted by an app restart may still be valid — pick it up
+  useEffect(() => {
+    if (!isDesktop()) return;
+    window
+      .electronAPI!.msAuthResume()
+      .then((code) => {
+        if (code) runAuthFlow(code);
+      })
+      .catch(() => {});
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, []);
+
   const removeAccount = async (account: LauncherAccount) => {
     try {
       if (isDesktop()) {
-        try {
-          await window.electronAPI!.removeAccount(account.uuid);
-        } catch {
-          /* noop */
-        }
+        // Also removes the backend entry, so nothing is left orphaned
+        await window.electronAPI!.removeAccount(account.uuid);
+      } else {
+        await api.deleteLauncherAccount(account.id);
       }
-      await api.deleteLauncherAccount(account.id);
       setAccounts((prev) => prev.filter((a) => a.id !== account.id));
       toast.success(`Removed ${account.username}`);
     } catch {
@@ -245,6 +284,17 @@ export function AccountManager({
         </div>
       )}
 
+      {credentialStorage && !credentialStorage.available && (
+        <div className="flex items-start gap-3 rounded-lg border border-amber-500/20 bg-amber-500/5 p-3">
+          <AlertTriangle className="mt-0.5 h-4 w-4 shrink-0 text-amber-400" />
+          <p className="text-sm text-amber-300/80">
+            Secure credential storage isn&apos;t working, so sign-in tokens
+            won&apos;t be protected by your system keychain.{" "}
+            {credentialStorage.error}
+          </p>
+        </div>
+      )}
+
       {(authPhase === "awaiting" ||
         authPhase === "requesting" ||
         authPhase === "polling") &&
@@ -411,6 +461,14 @@ function DeviceCodeCard({ deviceCode, phase, onCancel }: DeviceCodeCardProps) {
           href={deviceCode.verificationUri}
           target="_blank"
           rel="noopener noreferrer"
+          onClick={(e) => {
+            // Open in the system browser rather than a new Electron window
+            if (!window.electronAPI) return;
+            e.preventDefault();
+            window.electronAPI.openAuthUrl().catch(() => {
+              window.open(deviceCode.verificationUri, "_blank");
+            });
+          }}
           className="inline-flex items-center gap-1.5 rounded-md bg-sky-600 px-4 py-2 text-sm font-medium text-white transition-colors hover:bg-sky-500"
         >
           <ExternalLink className="h-3.5 w-3.5" />
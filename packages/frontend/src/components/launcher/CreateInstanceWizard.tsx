@@ -2,11 +2,12 @@ import { useState, useEffect, useCallback } from "react";
 import { ArrowLeft, ArrowRight, Check, Loader2, Search, X } from "lucide-react";
 import type {
   CreateInstanceRequest,
-  MinecraftVersion,
   LoaderType,
+  McVersionSummary,
 } from "@mc-server-manager/shared";
 import { api } from "@/api/client";
 import { cn } from "@/lib/utils";
+import { logger } from "@/utils/logger";
 
 type WizardStep = "version" | "loader" | "configure" | "review";
 
@@ -78,6 +79,27 @@ export function CreateInstanceWizard({
     if (prevIdx >= 0) setStep(STEPS[prevIdx]);
   }, [stepIndex]);
 
+  // Re-suggest heap sizes whenever the version or loader changes
+  useEffect(() => {
+    if (!mcVersion) return;
+    let cancelled = false;
+    api
+      .getRamRecommendation(mcVersion, loader)
+      .then((rec) => {
+        if (cancelled) return;
+        setRamMin(rec.minGb);
+        setRamMax(rec.maxGb);
+      })
+      .catch((err) => {
+        logger.warn("Failed to get RAM recommendation", {
+          error: err instanceof Error ? err.message : String(err),
+        });
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [mcVersion, loader]);
+
   useEffect(() => {
     if (step === "configure" && !name && mcVersion) {
       const loaderLabel =
@@ -223,14 +245,14 @@ function VersionStep({
   onFilterChange: (f: "release" | "snapshot") => void;
   onNext: () => void;
 }) {
-  const [versions, setVersions] = useState<MinecraftVersion[]>([]);
+  const [versions, setVersions] = useState<McVersionSummary[]>([]);
   const [loading, setLoading] = useState(true);
   const [search, setSearch] = useState("");
 
   useEffect(() => {
     setLoading(true);
     api
-      .getLauncherVersions(filter === "snapshot" ? undefined : "release")
+      .getMcVersions(filter === "snapshot")
       .then(setVersions)
       .catch(() => setVersions([]))
       .finally(() => setLoading(false));
@@ -1,10 +1,17 @@
-import { useState } from "react";
+import { useEffect, useRef, useState } from "react";
 import { Play, Loader2 } from "lucide-react";
 import { toast } from "sonner";
 import { cn } from "@/lib/utils";
 import { isDesktop } from "@/utils/desktop";
 import { api } from "@/api/client";
 import { logger } from "@/utils/logger";
+import {
+  decodeLaunchError,
+  type GameReadyEvent,
+  type LaunchProgressEvent,
+  type LaunchStage,
+  type LaunchStalledEvent,
+} from "@mc-server-manager/shared";
 
 interface LaunchButtonProps {
   instanceId: string;
@@ -14,12 +21,46 @@ interface LaunchButtonProps {
 
 type LaunchState = "ready" | "launching" | "running";
 
+const STAGE_LABELS: Record<LaunchStage, string> = {
+  fetching_instance: "Loading instance...",
+  resolving_java: "Finding Java...",
+  creating_natives: "Extracting natives...",
+  spawning: "Starting game...",
+  // Process is up but the client hasn't logged in-game yet
+  running: "Loading game...",
+};
+
 export function LaunchButton({
   instanceId,
   accountId,
   disabled = false,
 }: LaunchButtonProps) {
   const [state, setState] = useState<LaunchState>("ready");
+  const [stage, setStage] = useState<LaunchStage | null>(null);
+  const prepareJobIdRef = useRef<string | null>(null);
+  const cancelledRef = useRef(false);
+
+  useEffect(() => {
+    const electronAPI = window.electronAPI;
+    if (!electronAPI) return;
+
+    const unsubscribers = [
+      electronAPI.on("launch-progress", (payload) => {
+        const event = payload as LaunchProgressEvent;
+        if (event.instanceId === instanceId) setStage(event.stage);
+      }),
+      electronAPI.on("game-ready", (payload) => {
+        const event = payload as GameReadyEvent;
+        if (event.instanceId === instanceId) setState("running");
+      }),
+      // No readiness marker (e.g. old versions) — stop showing a spinner
+      electronAPI.on("launch-stalled", (payload) => {
+        const event = payload as LaunchStalledEvent;
+        if (event.instanceId === instanceId) setState("running");
+      }),
+    ];
+    return () => unsubscribers.forEach((unsubscribe) => unsubscribe());
+  }, [instanceId]);
 
   const noAccount = !accountId;
   const isDisabled = disabled || noAccount || state !== "ready";
@@ -33,19 +74,30 @@ export function LaunchButton({
     }
 
     setState("launching");
+    setStage(null);
+    cancelledRef.current = false;
+    prepareJobIdRef.current = null;
 
     try {
+      const prepareStarted = Date.now();
       const job = await api.prepareLaunch(instanceId);
+      prepareJobIdRef.current = job.id;
 
       const poll = async (): Promise<void> => {
         const j = await api.getPrepareStatus(job.id);
-        if (j.phase === "completed" && j.result) {
-          await window.electronAPI!.launchGame(
+        if (cancelledRef.current) {
+          setState("ready");
+          toast("Launch cancelled");
+        } else if (j.phase === "completed" && j.result) {
+          prepareJobIdRef.current = null;
+          const game = await window.electronAPI!.launchGame(
             instanceId,
             accountId!,
             j.result,
+            { prepareMs: Date.now() - prepareStarted },
           );
-          setState("running");
+          // Otherwise stay "launching" until the game-ready event
+          if (game.ready) setState("running");
           toast.success("Game launched");
         } else if (j.phase === "failed") {
           setState("ready");
@@ -58,16 +110,32 @@ export function LaunchButton({
       await poll();
     } catch (err) {
       setState("ready");
-      const message =
-        err instanceof Error ? err.message : "Failed to launch game";
-      logger.warn("LaunchButton failed", { error: message });
-      toast.error(message);
+      const raw = err instanceof Error ? err.message : "Failed to launch game";
+      const launchError = decodeLaunchError(raw);
+      if (launchError?.code === "cancelled") {
+        toast("Launch cancelled");
+        return;
+      }
+      logger.warn("LaunchButton failed", {
+        error: raw,
+        code: launchError?.code,
+      });
+      toast.error(launchError?.message ?? raw);
     }
   };
 
+  const handleCancel = () => {
+    cancelledRef.current = true;
+    const jobId = prepareJobIdRef.current;
+    if (jobId) api.cancelPrepare(jobId).catch(() => {});
+    window.electronAPI?.cancelLaunch(instanceId).catch(() => {});
+  };
+
   const label =
     state === "launching"
-      ? "Launching..."
+      ? stage
+        ? STAGE_LABELS[stage]
+        : "Preparing..."
       : state === "running"
         ? "Running"
         : "Play";
@@ -106,6 +174,15 @@ export function LaunchButton({
         )}
         {label}
       </button>
+      {state === "launching" && (
+        <button
+          type="button"
+          onClick={handleCancel}
+          className="mt-2 w-full text-center text-xs font-medium text-zinc-400 transition-colors hover:text-zinc-200"
+        >
+          Cancel
+        </button>
+      )}
     </div>
   );
 }
@@ -1,6 +1,7 @@
 import { useEffect, useState, useCallback } from "react";
 import {
   AlertTriangle,
+  Bell,
   Check,
   Coffee,
   Cpu,
@@ -223,7 +224,8 @@ export function AppSettings() {
                   ) : (
                     <>
                       <AlertTriangle className="h-4 w-4 shrink-0" />
-                      Java not found at this path
+                      {javaValidation.errorMessage ??
+                        "Java not found at this path"}
                     </>
                   )}
                 </div>
@@ -354,6 +356,112 @@ export function AppSettings() {
             </label>
           </SettingGroup>
 
+          {/* ── Desktop Settings ─────────────────────────────────── */}
+          {window.electronAPI && (
+            <SettingGroup
+              icon={Bell}
+              title="Desktop"
+              description="Options for the desktop app"
+            >
+              <label className="flex items-center gap-3 cursor-pointer">
+                <button
+                  type="button"
+                  role="switch"
+                  aria-checked={form.desktopNotifications ?? true}
+                  onClick={() =>
+                    updateField(
+                      "desktopNotifications",
+                      !(form.desktopNotifications ?? true),
+                    )
+                  }
+                  className={cn(
+                    "relative inline-flex h-5 w-9 shrink-0 rounded-full border-2 border-transparent transition-colors",
+                    (form.desktopNotifications ?? true)
+                      ? "bg-emerald-600"
+                      : "bg-zinc-700",
+                  )}
+                >
+                  <span
+                    className={cn(
+                      "pointer-events-none inline-block h-4 w-4 transform rounded-full bg-white shadow transition-transform",
+                      (form.desktopNotifications ?? true)
+                        ? "translate-x-4"
+                        : "translate-x-0",
+                    )}
+                  />
+                </button>
+                <div>
+                  <span className="text-sm text-zinc-200">
+                    Desktop notifications
+                  </span>
+                  <p className="text-xs text-zinc-500">
+                    Show a system notification when Microsoft sign-in
+                    finishes, expires, or fails.
+                  </p>
+                </div>
+              </label>
+              <label className="mt-4 flex items-center gap-3 cursor-pointer">
+                <button
+                  type="button"
+                  role="switch"
+                  aria-checked={form.crashNotifications ?? true}
+                  onClick={() =>
+                    updateField(
+                      "crashNotifications",
+                      !(form.crashNotifications ?? true),
+                    )
+                  }
+                  className={cn(
+                    "relative inline-flex h-5 w-9 shrink-0 rounded-full border-2 border-transparent transition-colors",
+                    (form.crashNotifications ?? true)
+                      ? "bg-emerald-600"
+                      : "bg-zinc-700",
+                  )}
+                >
+                  <span
+                    className={cn(
+                      "pointer-events-none inline-block h-4 w-4 transform rounded-full bg-white shadow transition-transform",
+                      (form.crashNotifications ?? true)
+                        ? "translate-x-4"
+                        : "translate-x-0",
+                    )}
+                  />
+                </button>
+                <div>
+                  <span className="text-sm text-zinc-200">
+                    Crash notifications
+                  </span>
+                  <p className="text-xs text-zinc-500">
+                    Show a system notification when a game or server crashes.
+                    Click it to open the crash report.
+                  </p>
+                </div>
+              </label>
+              <div className="mt-4 space-y-2">
+                <span className="text-sm text-zinc-200">
+                  Max running games
+                </span>
+                <input
+                  type="number"
+                  value={form.maxConcurrentGames ?? 0}
+                  onChange={(e) => {
+                    const val = parseInt(e.target.value, 10);
+                    if (!isNaN(val) && val >= 0) {
+                      updateField("maxConcurrentGames", val);
+                    }
+                  }}
+                  min={0}
+                  max={64}
+                  className="block w-48 rounded-md border border-zinc-700 bg-zinc-950 px-3 py-2 text-sm text-zinc-200 outline-none transition-colors focus:border-zinc-500 focus:ring-1 focus:ring-zinc-500"
+                />
+                <p className="text-xs text-zinc-500">
+                  Launches are refused once this many games are running. 0
+                  means no limit.
+                </p>
+              </div>
+            </SettingGroup>
+          )}
+
           {/* ── System Info ────────────────────────────────────────── */}
           {systemInfo && (
             <SettingGroup
@@ -141,6 +141,17 @@ export function CreateServer() {
           error: err instanceof Error ? err.message : String(err),
         });
       });
+    api
+      .findFreePort()
+      .then(({ port }) => {
+        // Keep a port the user has already typed
+        setState((s) => (s.port === 25565 ? { ...s, port } : s));
+      })
+      .catch((err) => {
+        logger.warn("Failed to find a free port", {
+          error: err instanceof Error ? err.message : String(err),
+        });
+      });
   }, []);
 
   // Auto-set java path from detected info
@@ -921,7 +932,8 @@ function ConfigureStep({
             )}
           />
           <p className="mt-1 text-xs text-zinc-500">
-            Default is 25565. Use a different port for multiple servers.
+            Pre-filled with the first free port from 25565. Each server needs
+            its own port.
           </p>
           {portError && (
             <p className="mt-1 text-xs text-red-400">{portError}</p>
@@ -12,12 +12,18 @@ import {
   Play,
   Save,
   RotateCcw,
+  ScrollText,
 } from "lucide-react";
 import { toast } from "sonner";
-import type {
-  LauncherInstance,
-  UpdateInstanceRequest,
-  PrepareJob,
+import {
+  decodeLaunchError,
+  type GpuPreference,
+  type InstanceIsolation,
+  type JavaAgent,
+  type JvmArgsPreset,
+  type LauncherInstance,
+  type UpdateInstanceRequest,
+  type PrepareJob,
 } from "@mc-server-manager/shared";
 import { api } from "@/api/client";
 import { ModList } from "@/components/ModList";
@@ -206,6 +212,54 @@ function SettingsForm({
   const [javaPath, setJavaPath] = useState(instance.javaPath ?? "");
   const [jvmArgs, setJvmArgs] = useState(instance.jvmArgs.join(" "));
   const [gameArgs, setGameArgs] = useState(instance.gameArgs.join(" "));
+  const [javaAgents, setJavaAgents] = useState<JavaAgent[]>(
+    instance.javaAgents,
+  );
+  const [pathPrepend, setPathPrepend] = useState(
+    instance.pathPrepend.join("\n"),
+  );
+  const [restartOnCrash, setRestartOnCrash] = useState(
+    instance.restartOnCrash,
+  );
+  const [gpuPreference, setGpuPreference] = useState(instance.gpuPreference);
+  const [isolation, setIsolation] = useState(instance.isolation);
+  const [jvmPresets, setJvmPresets] = useState<JvmArgsPreset[]>([]);
+
+  const loadJvmPresets = useCallback(() => {
+    if (!isDesktop()) return;
+    window
+      .electronAPI!.listJvmPresets()
+      .then(setJvmPresets)
+      .catch((err) => {
+        logger.warn("Failed to load JVM presets", {
+          error: err instanceof Error ? err.message : String(err),
+        });
+      });
+  }, []);
+
+  useEffect(() => {
+    loadJvmPresets();
+  }, [loadJvmPresets]);
+
+  const applyJvmPreset = (name: string) => {
+    const preset = jvmPresets.find((p) => p.name === name);
+    if (preset) setJvmArgs(preset.args.join(" "));
+  };
+
+  const saveJvmPreset = async () => {
+    const name = window.prompt("Save current JVM arguments as preset:");
+    if (!name?.trim()) return;
+    try {
+      await window.electronAPI!.saveJvmPreset(
+        name,
+        jvmArgs.trim() ? jvmArgs.trim().split(/\s+/) : [],
+      );
+      toast.success(`Saved preset "${name.trim()}"`);
+      loadJvmPresets();
+    } catch (err) {
+      toast.error(err instanceof Error ? err.message : "Failed to save preset");
+    }
+  };
 
   useEffect(() => {
     setName(instance.name);
@@ -216,6 +270,11 @@ function SettingsForm({
     setJavaPath(instance.javaPath ?? "");
     setJvmArgs(instance.jvmArgs.join(" "));
     setGameArgs(instance.gameArgs.join(" "));
+    setJavaAgents(instance.javaAgents);
+    setPathPrepend(instance.pathPrepend.join("\n"));
+    setRestartOnCrash(instance.restartOnCrash);
+    setGpuPreference(instance.gpuPreference);
+    setIsolation(instance.isolation);
   }, [instance]);
 
   const resetForm = () => {
@@ -227,8 +286,20 @@ function SettingsForm({
     setJavaPath(instance.javaPath ?? "");
     setJvmArgs(instance.jvmArgs.join(" "));
     setGameArgs(instance.gameArgs.join(" "));
+    setJavaAgents(instance.javaAgents);
+    setPathPrepend(instance.pathPrepend.join("\n"));
+    setRestartOnCrash(instance.restartOnCrash);
+    setGpuPreference(instance.gpuPreference);
+    setIsolation(instance.isolation);
   };
 
+  const updateJavaAgent = (index: number, changes: Partial<JavaAgent>) =>
+    setJavaAgents((agents) =>
+      agents.map((agent, i) =>
+        i === index ? { ...agent, ...changes } : agent,
+      ),
+    );
+
   const handleSave = async () => {
     setSaving(true);
     try {
@@ -241,6 +312,19 @@ function SettingsForm({
         javaPath: javaPath.trim() || null,
         jvmArgs: jvmArgs.trim() ? jvmArgs.trim().split(/\s+/) : [],
         gameArgs: gameArgs.trim() ? gameArgs.trim().split(/\s+/) : [],
+        javaAgents: javaAgents
+          .map((agent) => ({
+            path: agent.path.trim(),
+            options: agent.options.trim(),
+          }))
+          .filter((agent) => agent.path),
+        pathPrepend: pathPrepend
+          .split("\n")
+          .map((dir) => dir.trim())
+          .filter(Boolean),
+        restartOnCrash,
+        gpuPreference,
+        isolation,
       };
       await api.updateLauncherInstance(instance.id, data);
       toast.success("Settings saved");
@@ -380,7 +464,33 @@ function SettingsForm({
           </div>
 
           <div>
-            <label className={labelCls}>JVM Arguments</label>
+            <div className="flex items-center justify-between">
+              <label className={labelCls}>JVM Arguments</label>
+              {isDesktop() && (
+                <div className="flex items-center gap-2">
+                  <select
+                    value=""
+                    onChange={(e) => applyJvmPreset(e.target.value)}
+                    className="rounded-md border border-zinc-700 bg-zinc-800 px-2 py-1 text-xs text-zinc-300 hover:border-zinc-600 focus:outline-none"
+                  >
+                    <option value="">Apply preset…</option>
+                    {jvmPresets.map((preset) => (
+                      <option key={preset.name} value={preset.name}>
+                        {preset.name}
+                      </option>
+                    ))}
+                  </select>
+                  <button
+                    type="button"
+                    onClick={saveJvmPreset}
+                    disabled={!jvmArgs.trim()}
+                    className="text-xs font-medium text-zinc-400 transition-colors hover:text-zinc-200 disabled:opacity-50"
+                  >
+                    Save as preset
+                  </button>
+                </div>
+              )}
+            </div>
             <textarea
               value={jvmArgs}
               onChange={(e) => setJvmArgs(e.target.value)}
@@ -399,7 +509,143 @@ function SettingsForm({
               placeholder="--width 1920 --height 1080"
               className={cn(inputCls, "mt-1.5 resize-none font-mono text-xs")}
             />
+            <p className="mt-1 text-xs text-zinc-500">
+              JVM and game arguments can use{" "}
+              <code>{"{instanceDir}"}</code>, <code>{"{assetsDir}"}</code>,{" "}
+              <code>{"{nativesDir}"}</code>, <code>{"{logsDir}"}</code> and{" "}
+              <code>{"{ram}"}</code> (max RAM, e.g. 4G), filled in at launch.
+            </p>
+          </div>
+
+          <div>
+            <div className="flex items-center justify-between">
+              <label className={labelCls}>Java Agents</label>
+              <button
+                type="button"
+                onClick={() =>
+                  setJavaAgents((agents) => [
+                    ...agents,
+                    { path: "", options: "" },
+                  ])
+                }
+                className="text-xs font-medium text-zinc-400 transition-colors hover:text-zinc-200"
+              >
+                Add agent
+              </button>
+            </div>
+            {javaAgents.map((agent, index) => (
+              <div key={index} className="mt-1.5 flex items-center gap-2">
+                <input
+                  type="text"
+                  value={agent.path}
+                  onChange={(e) =>
+                    updateJavaAgent(index, { path: e.target.value })
+                  }
+                  placeholder="/path/to/agent.jar"
+                  className={cn(inputCls, "flex-[2] font-mono text-xs")}
+                />
+                <input
+                  type="text"
+                  value={agent.options}
+                  onChange={(e) =>
+                    updateJavaAgent(index, { options: e.target.value })
+                  }
+                  placeholder="options (optional)"
+                  className={cn(inputCls, "flex-1 font-mono text-xs")}
+                />
+                <button
+                  type="button"
+                  onClick={() =>
+                    setJavaAgents((agents) =>
+                      agents.filter((_, i) => i !== index),
+                    )
+                  }
+                  className="text-zinc-500 transition-colors hover:text-red-400"
+                  aria-label="Remove agent"
+                >
+                  <Trash2 className="h-4 w-4" />
+                </button>
+              </div>
+            ))}
+            <p className="mt-1 text-xs text-zinc-500">
+              Profilers and mod dev tools, passed as{" "}
+              <code>-javaagent:path=options</code>. A missing jar stops the
+              launch.
+            </p>
+          </div>
+
+          <div>
+            <label className={labelCls}>Extra PATH Directories</label>
+            <textarea
+              value={pathPrepend}
+              onChange={(e) => setPathPrepend(e.target.value)}
+              rows={2}
+              placeholder="/path/to/native/libs"
+              className={cn(inputCls, "mt-1.5 resize-none font-mono text-xs")}
+            />
+            <p className="mt-1 text-xs text-zinc-500">
+              One per line, put in front of the game&apos;s PATH so mods can
+              find native libraries. Your system PATH isn&apos;t changed.
+            </p>
+          </div>
+
+          <div>
+            <label className={labelCls}>Graphics Card</label>
+            <select
+              value={gpuPreference ?? ""}
+              onChange={(e) =>
+                setGpuPreference(
+                  e.target.value ? (e.target.value as GpuPreference) : null,
+                )
+              }
+              className={cn(inputCls, "mt-1.5")}
+            >
+              <option value="">System default</option>
+              <option value="integrated">Integrated (power saving)</option>
+              <option value="discrete">Discrete (high performance)</option>
+            </select>
+            <p className="mt-1 text-xs text-zinc-500">
+              For laptops with two GPUs. On Windows this also sets the
+              graphics preference for the instance&apos;s Java; macOS picks
+              the GPU itself and ignores this.
+            </p>
           </div>
+
+          <div>
+            <label className={labelCls}>Game Files</label>
+            <select
+              value={isolation}
+              onChange={(e) =>
+                setIsolation(e.target.value as InstanceIsolation)
+              }
+              className={cn(inputCls, "mt-1.5")}
+            >
+              <option value="shared">Shared with other instances</option>
+              <option value="isolated">Isolated (own copy)</option>
+            </select>
+            <p className="mt-1 text-xs text-zinc-500">
+              Isolated instances download their own assets and libraries
+              (usually 0.5&ndash;1 GB each) instead of sharing them, for
+              setups that conflict with other instances. Takes effect on
+              the next launch.
+            </p>
+          </div>
+
+          <label className="flex items-start gap-2 text-sm text-zinc-300">
+            <input
+              type="checkbox"
+              checked={restartOnCrash}
+              onChange={(e) => setRestartOnCrash(e.target.checked)}
+              className="mt-0.5 h-4 w-4 rounded border-zinc-600 bg-zinc-800 text-emerald-500 focus:ring-emerald-500/50"
+            />
+            <span>
+              Restart on crash
+              <span className="block text-xs text-zinc-500">
+                Relaunch with the same account after a crash, up to 3 times
+                in 10 minutes. Stopping the game never triggers a restart.
+              </span>
+            </span>
+          </label>
         </section>
 
         <div className="flex items-center gap-3 border-t border-zinc-800 pt-6">
@@ -488,6 +734,23 @@ export default function InstanceDetail() {
     fetchInstance();
   }, [fetchInstance]);
 
+  // Nothing picked on this device yet — pre-select the default account
+  useEffect(() => {
+    if (selectedAccountId) return;
+    api
+      .getDefaultLauncherAccount()
+      .then((account) => {
+        if (account) setSelectedAccountId(account.id);
+      })
+      .catch((err) => {
+        logger.warn("Failed to load default account", {
+          error: err instanceof Error ? err.message : String(err),
+        });
+      });
+    // Only on mount: clearing the selection later shouldn't re-apply it
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, []);
+
   const handleRemoveLoader = async () => {
     if (!id) return;
     setRemovingLoader(true);
@@ -518,6 +781,7 @@ export default function InstanceDetail() {
     setPrepareProgress({ phase: "version", current: 0, total: 0 });
 
     try {
+      const prepareStarted = Date.now();
       const job = await api.prepareLaunch(id);
       prepareJobIdRef.current = job.id;
 
@@ -547,12 +811,19 @@ export default function InstanceDetail() {
 
             if (j.result) {
               if (isDesktop()) {
-                await window.electronAPI!.launchGame(
-                  id,
-                  selectedAccountId,
-                  j.result,
-                );
-                toast.success("Game launched!");
+                try {
+                  await window.electronAPI!.launchGame(
+                    id,
+                    selectedAccountId,
+                    j.result,
+                    { prepareMs: Date.now() - prepareStarted },
+                  );
+                  toast.success("Game launched!");
+                } catch (err) {
+                  const raw =
+                    err instanceof Error ? err.message : "Failed to launch";
+                  toast.error(decodeLaunchError(raw)?.message ?? raw);
+                }
               } else {
                 toast.info(
                   "Game files prepared. Launch requires the desktop app.",
@@ -784,6 +1055,23 @@ export default function InstanceDetail() {
             )}
             {preparing ? "Preparing..." : "Play"}
           </button>
+          {isDesktop() && (
+            <button
+              onClick={() => {
+                window.electronAPI!.openLogWindow(id!).catch((err) => {
+                  toast.error(
+                    err instanceof Error
+                      ? err.message
+                      : "Failed to open log window",
+                  );
+                });
+              }}
+              className="inline-flex w-full items-center justify-center gap-2 rounded-lg border border-zinc-700 px-4 py-2 text-sm font-medium text-zinc-300 transition-colors hover:border-zinc-600 hover:text-zinc-100"
+            >
+              <ScrollText className="h-4 w-4" />
+              Open log window
+            </button>
+          )}
         </div>
       </div>
 
@@ -0,0 +1,89 @@
+import { useEffect, useRef, useState } from "react";
+import { useParams } from "react-router";
+import type { GameLogEvent } from "@mc-server-manager/shared";
+import { logger } from "@/utils/logger";
+
+/** Lines kept on screen; older ones scroll away for good. */
+const MAX_LINES = 5000;
+
+/**
+ * How many lines at the start of `live` are already at the end of
+ * `backlog`, i.e. were printed after subscribing but before the backlog
+ * was read.
+ */
+function overlap(backlog: string[], live: string[]): number {
+  for (let n = Math.min(backlog.length, live.length); n > 0; n--) {
+    const tail = backlog.slice(backlog.length - n);
+    if (tail.every((line, i) => line === live[i])) return n;
+  }
+  return 0;
+}
+
+/**
+ * Standalone page shown in a pop-out log window (desktop only). Streams the
+ * instance's game output via `game-log` events.
+ */
+export default function LogWindow() {
+  const { id } = useParams<{ id: string }>();
+  const [lines, setLines] = useState<string[]>([]);
+  const [follow, setFollow] = useState(true);
+  const bottomRef = useRef<HTMLSpanElement>(null);
+
+  useEffect(() => {
+    const electronAPI = window.electronAPI;
+    if (!electronAPI || !id) return;
+
+    const append = (next: string[]) =>
+      setLines((prev) => [...prev, ...next].slice(-MAX_LINES));
+
+    // Lines that arrive while the backlog is loading are held back, since
+    // the backlog may already include some of them
+    let pending: string[] | null = [];
+    const unsubscribe = electronAPI.on("game-log", (payload) => {
+      const event = payload as GameLogEvent;
+      if (event.instanceId !== id) return;
+      if (pending) pending.push(...event.lines);
+      else append(event.lines);
+    });
+    const flush = (backlog: string[]) => {
+      const live = pending ?? [];
+      pending = null;
+      append([...backlog, ...live.slice(overlap(backlog, live))]);
+    };
+    electronAPI
+      .getGameOutput(id)
+      .then(flush)
+      .catch((err) => {
+        logger.warn("Failed to load game output", {
+          error: err instanceof Error ? err.message : String(err),
+        });
+        flush([]);
+      });
+    return unsubscribe;
+  }, [id]);
+
+  useEffect(() => {
+    if (follow) bottomRef.current?.scrollIntoView();
+  }, [lines, follow]);
+
+  return (
+    <div className="flex h-screen flex-col bg-zinc-950 text-zinc-300">
+      <div className="flex items-center justify-between border-b border-zinc-800 px-4 py-2">
+        <span className="text-sm font-medium text-zinc-400">Game log</span>
+        <label className="flex items-center gap-2 text-xs text-zinc-400">
+          <input
+            type="checkbox"
+            checked={follow}
+            onChange={(e) => setFollow(e.target.checked)}
+            className="accent-emerald-500"
+          />
+          Follow output
+        </label>
+      </div>
+      <pre className="flex-1 overflow-auto px-4 py-2 font-mono text-xs leading-relaxed whitespace-pre-wrap">
+        {lines.length > 0 ? lines.join("\n") : "Waiting for output…"}
+        <span ref={bottomRef} />
+      </pre>
+    </div>
+  );
+}
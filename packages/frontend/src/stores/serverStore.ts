@@ -44,6 +44,7 @@ interface ServerStore {
   // --- Console lines per server ---
   consoleLines: Record<string, ConsoleLine[]>;
   appendConsole: (serverId: string, line: string, timestamp: string) => void;
+  appendConsoleBatch: (serverId: string, lines: ConsoleLine[]) => void;
   setConsoleHistory: (serverId: string, lines: ConsoleLine[]) => void;
   clearConsole: (serverId: string) => void;
 
@@ -117,6 +118,18 @@ export const useServerStore = create<ServerStore>((set, get) => ({
     });
   },
 
+  appendConsoleBatch(serverId, lines) {
+    const prev = get().consoleLines[serverId] ?? [];
+    const next = prev.concat(lines);
+    const capped =
+      next.length > MAX_CONSOLE_LINES
+        ? next.slice(next.length - MAX_CONSOLE_LINES)
+        : next;
+    set({
+      consoleLines: { ...get().consoleLines, [serverId]: capped },
+    });
+  },
+
   setConsoleHistory(serverId, lines) {
     const capped =
       lines.length > MAX_CONSOLE_LINES
@@ -202,6 +215,10 @@ function handleWsMessage(msg: WsServerMessage): void {
       store.appendConsole(msg.serverId, msg.line, msg.timestamp);
       break;
 
+    case "console:batch":
+      store.appendConsoleBatch(msg.serverId, msg.lines);
+      break;
+
     case "console:history":
       store.setConsoleHistory(msg.serverId, msg.lines);
       break;
@@ -14,6 +14,7 @@ export function buildServer(
     jvmArgs: "-Xmx2G -Xms2G",
     port: 25565,
     autoStart: false,
+    autoRestart: false,
     createdAt: new Date().toISOString(),
     updatedAt: new Date().toISOString(),
     status: "stopped",
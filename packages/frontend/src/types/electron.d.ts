@@ -1,35 +1,316 @@
 import type {
+  AccountRepairResult,
+  AppInfo,
+  AvailableJavaVersion,
+  BackendHealth,
+  BackendReloadResult,
+  BackendStartupLog,
+  CacheCategory,
+  CacheClearResult,
+  ClasspathEntry,
+  ConfigImportResult,
+  CredentialStorageStatus,
   MSAuthDeviceCode,
   MSAuthStatus,
   LauncherAccount,
+  GameArgsPreview,
   GameProcess,
+  InstanceIssue,
+  InstancePaths,
+  InstanceSummary,
+  JavaDiagnosis,
   JavaInstallation,
+  JavaWarmupResult,
+  JvmArgsPreset,
+  LaunchTimings,
+  LevelDatChanges,
+  LevelDatFields,
+  LogChunk,
+  McVersionSummary,
+  PlayerLookup,
   PrepareResponse,
+  QueryResponse,
+  RconTestResult,
+  RunningGameDetails,
+  ServerStopOutcome,
+  ShutdownReport,
+  VanillaAccountImportResult,
+  VerifyReport,
+  WorldInfo,
 } from "@mc-server-manager/shared";
 
+/** Events pushed from the Electron main process */
+export type ElectronEventChannel =
+  | "backend-restarting"
+  | "backend-ready"
+  /** Supervisor gave up restarting the backend */
+  | "backend-down"
+  /** Payload: LaunchProgressEvent */
+  | "launch-progress"
+  /** Payload: LaunchFailedEvent */
+  | "launch-failed"
+  /** Payload: LaunchStalledEvent */
+  | "launch-stalled"
+  /** Payload: GameReadyEvent */
+  | "game-ready"
+  /** Payload: GameForceKilledEvent */
+  | "game-force-killed"
+  /** Payload: GameCrashedEvent */
+  | "game-crashed"
+  /** Payload: GameLogEvent — only delivered to that instance's log window */
+  | "game-log"
+  /** Payload: AccountRefreshFailedEvent — prompt the user to sign in again */
+  | "account-refresh-failed";
+
 export interface ElectronAPI {
   platform: string;
+  /** Backend port at window creation; prefer getBackendPort() if it may have changed */
+  backendPort: number;
+  getBackendPort(): Promise<number>;
+  /** Production only: stops managed servers and restarts the in-process backend */
+  restartBackend(): Promise<void>;
+  /**
+   * Production only: re-read proxy and CA settings without a restart. Falls
+   * back to restartBackend (method "restart") if reload isn't supported
+   */
+  reloadBackendConfig(): Promise<BackendReloadResult>;
+  /** Last failed backend start/restart and the tail of its log (64 KB max) */
+  getBackendStartupLog(): Promise<BackendStartupLog>;
+  /**
+   * Production only: stop every managed server (graceful by default) and
+   * report each outcome. Client games are left running
+   */
+  stopAllServers(graceful?: boolean): Promise<ServerStopOutcome[]>;
+  /** Diagnostics: probe /api/health with a short timeout */
+  checkBackendHealth(): Promise<BackendHealth>;
+  /** Diagnostics: macOS only — the app is an x64 build on Apple Silicon */
+  isRunningUnderRosetta(): Promise<boolean>;
+  /** Diagnostics: running as root (Unix) or elevated admin (Windows) */
+  isElevated(): Promise<boolean>;
+  /** Stop servers, games and the backend in order, then quit */
+  shutdownApp(): Promise<ShutdownReport>;
+
+  /** Subscribe to a main-process event. Returns an unsubscribe function. */
+  on(
+    channel: ElectronEventChannel,
+    callback: (payload: unknown) => void,
+  ): () => void;
+  getAppInfo(): Promise<AppInfo>;
 
   // Auth
   msAuthStart(): Promise<MSAuthDeviceCode>;
   msAuthPoll(): Promise<MSAuthStatus>;
+  /** Device code still valid from before an app restart, if any */
+  msAuthResume(): Promise<MSAuthDeviceCode | null>;
+  openAuthUrl(): Promise<void>;
   msAuthCancel(): Promise<void>;
   msAuthRefresh(uuid: string): Promise<LauncherAccount>;
+  /**
+   * PNG of the account's skin face, `size` (8-512) pixels square. Falls
+   * back to the default Steve/Alex face without a skin or when offline
+   */
+  getAccountAvatar(uuid: string, size: number): Promise<Uint8Array>;
   getMcAccessToken(uuid: string): Promise<string>;
-  removeAccount(uuid: string): Promise<void>;
+  /**
+   * Copy profiles from the official launcher's launcher_accounts.json.
+   * Its tokens can't be reused, so new accounts land in `needsReauth`.
+   */
+  importVanillaAccounts(path: string): Promise<VanillaAccountImportResult>;
+  /**
+   * Move stored tokens to a migrated profile id and update the account list.
+   * `verify` (default true) refreshes first to confirm the new profile.
+   */
+  migrateAccountKeyring(
+    oldUuid: string,
+    newUuid: string,
+    options?: { verify?: boolean },
+  ): Promise<LauncherAccount>;
+  /** Whether sign-ins can be stored encrypted; check before starting auth */
+  checkCredentialStorage(): Promise<CredentialStorageStatus>;
+  /**
+   * Deletes the account's secrets and its backend entry. `signOut` also
+   * ends the Microsoft browser session (best-effort)
+   */
+  removeAccount(uuid: string, options?: { signOut?: boolean }): Promise<void>;
+  /**
+   * Clears stored tokens that are empty or malformed; a bad refresh token
+   * leaves the account signed out rather than half-broken
+   */
+  repairAccount(uuid: string): Promise<AccountRepairResult>;
 
   // Game launcher
+  /**
+   * A null accountId launches with the user's default account. `force`
+   * allows a second copy of a running instance (shares its saves — see
+   * GameProcess.warning). `prepareMs` is how long prepare took, recorded in
+   * the launch timings. Rejects with a LaunchErrorInfo encoded in the
+   * error message — unpack it with decodeLaunchError
+   */
   launchGame(
     instanceId: string,
-    accountId: string,
+    accountId: string | null,
     prepareResult: PrepareResponse,
+    options?: { force?: boolean; prepareMs?: number },
   ): Promise<GameProcess>;
+  /**
+   * Run the instance's Java with its JVM args and `-version` (no game).
+   * Resolves with the output; rejects with it if the JVM fails to start
+   */
+  testJavaLaunch(instanceId: string): Promise<string>;
+  /** Pre-flight checks; empty array means launchable */
+  validateInstance(
+    instanceId: string,
+    accountId?: string,
+  ): Promise<InstanceIssue[]>;
   getRunningGames(): Promise<GameProcess[]>;
-  killGame(instanceId: string): Promise<void>;
+  /** Stage durations of the instance's last launch attempt this session */
+  getLastLaunchTimings(instanceId: string): Promise<LaunchTimings | null>;
+  /** Open (or focus) a separate window streaming the game's output */
+  openLogWindow(instanceId: string): Promise<void>;
+  /** Output of the instance's current or last game, up to 200 lines */
+  getGameOutput(instanceId: string): Promise<string[]>;
+  getRunningGamesDetailed(): Promise<RunningGameDetails[]>;
+  /** Game, assets, natives and logs dirs that a launch of the instance uses */
+  getInstancePaths(instanceId: string): Promise<InstancePaths>;
+  /** Runs prepare; the launch classpath in order, missing jars flagged */
+  getEffectiveClasspath(instanceId: string): Promise<ClasspathEntry[]>;
+  /** Game args a launch would use, token redacted; unknown `{names}` listed */
+  previewGameArgs(instanceId: string): Promise<GameArgsPreview>;
+  /**
+   * Check the game jar, libraries, asset index and natives without
+   * re-downloading. Without `prepareResult`, checks the last launch's files
+   */
+  verifyInstanceFiles(
+    instanceId: string,
+    prepareResult?: PrepareResponse,
+  ): Promise<VerifyReport>;
+  /** Releases only unless includeSnapshots (then every manifest type) */
+  getMcVersions(includeSnapshots?: boolean): Promise<McVersionSummary[]>;
+  /** Cached for a few seconds unless `refresh` */
+  listInstances(refresh?: boolean): Promise<InstanceSummary[]>;
+  /** 0 means unlimited; launches past the limit fail `too_many_running` */
+  getMaxConcurrentGames(): Promise<number>;
+  /** Resolves to the stored value */
+  setMaxConcurrentGames(max: number): Promise<number>;
+  /**
+   * Abort a launch that hasn't spawned the game yet (launchGame then rejects
+   * with `cancelled`). False if there was nothing to cancel
+   */
+  cancelLaunch(instanceId: string): Promise<boolean>;
+  /** Rejects with an encoded `not_running` LaunchErrorInfo if not running */
+  killGame(instanceId: string, timeoutSecs?: number): Promise<void>;
+  /**
+   * Worlds in the instance's saves folder, most recently played first.
+   * Unreadable level.dat files come back with `damaged` set
+   */
+  listWorlds(instanceId: string): Promise<WorldInfo[]>;
+  /** `folder` is a WorldInfo.folder, not a path */
+  openWorldFolder(instanceId: string, folder: string): Promise<void>;
+  readLevelDat(instanceId: string, folder: string): Promise<LevelDatFields>;
+  /**
+   * Rejects while the world is open. Backs up level.dat first and resolves
+   * with the fields as written
+   */
+  writeLevelDat(
+    instanceId: string,
+    folder: string,
+    changes: LevelDatChanges,
+  ): Promise<LevelDatFields>;
+  /**
+   * Freeze a running game (SIGSTOP). Rejects with an encoded `saving` error
+   * mid-world-save and `unsupported` on Windows
+   */
+  suspendGame(instanceId: string): Promise<void>;
+  resumeGame(instanceId: string): Promise<void>;
+
+  // Maintenance (entries used by running games are skipped)
+  clearCache(what: CacheCategory[]): Promise<CacheClearResult>;
+  /**
+   * Bytes used by an instance's directory, worlds included. Cached per
+   * directory; `refresh` re-stats every file (e.g. after playing)
+   */
+  getInstanceSize(instanceId: string, refresh?: boolean): Promise<number>;
+  /**
+   * Zip settings, accounts (no tokens), instance configs and JVM presets
+   * for moving to another machine
+   */
+  exportConfig(destPath: string): Promise<void>;
+  /**
+   * Restore an exportConfig backup. Rejects without changing anything if the
+   * archive is invalid or would replace existing data and !overwrite
+   */
+  importConfig(
+    srcPath: string,
+    overwrite?: boolean,
+  ): Promise<ConfigImportResult>;
+
+  // Logs (path must be inside the app logs directory)
+  openLogFile(path: string): Promise<void>;
+  /**
+   * Delete instance logs and crash reports beyond the newest `keepLatest`
+   * per folder or older than `maxAgeDays`; returns bytes freed. Live logs of
+   * running instances are kept.
+   */
+  pruneGameLogs(keepLatest: number, maxAgeDays: number): Promise<number>;
+  /**
+   * Incremental log read for polling; pass back nextOffset/fileId. Also
+   * allowed: `logs` folders of managed servers and launcher instances
+   */
+  tailLog(path: string, fromOffset?: number, fileId?: string): Promise<LogChunk>;
+  /**
+   * Write a redacted crash report for the instance's last launch; returns its
+   * path (inside the logs dir, so it can be passed to openLogFile)
+   */
+  exportCrashReport(instanceId: string): Promise<string>;
+
+  // RCON (password is stored in OS-encrypted storage, looked up by server id)
+  setRconPassword(serverId: string, password: string): Promise<void>;
+  clearRconPassword(serverId: string): Promise<void>;
+  hasRconPassword(serverId: string): Promise<boolean>;
+  rconConnect(serverId: string, host: string, port: number): Promise<void>;
+  rconCommand(serverId: string, command: string): Promise<string>;
+  rconDisconnect(serverId: string): Promise<void>;
+  /** Built-in presets first, then user presets by name */
+  listJvmPresets(): Promise<JvmArgsPreset[]>;
+  /** Rejects built-in names and unsafe flags (agents, OnError hooks, -cp) */
+  saveJvmPreset(name: string, args: string[]): Promise<JvmArgsPreset>;
+  deleteJvmPreset(name: string): Promise<void>;
+  /** UDP Query (GS4) full stat; rejects if enable-query is off */
+  queryServer(host: string, port: number): Promise<QueryResponse>;
+  /** Mojang username → dashed UUID and canonical casing (cached briefly) */
+  lookupPlayer(username: string): Promise<PlayerLookup>;
+  /** One-off connect/auth/`list` probe; stores nothing */
+  rconTest(
+    host: string,
+    port: number,
+    password: string,
+    runList?: boolean,
+  ): Promise<RconTestResult>;
 
   // Java management
   getJavaInstallations(): Promise<JavaInstallation[]>;
-  downloadJava(version: number): Promise<JavaInstallation>;
+  /** Versions Adoptium can download, newest first */
+  getAvailableJavaVersions(): Promise<AvailableJavaVersion[]>;
+  /**
+   * On ARM without a native build this fails with NO_NATIVE_BUILD unless
+   * `allowEmulated` is set, in which case the x64 build is installed and the
+   * result carries `emulated`/`note`. macOS falls back without asking and
+   * also sets `translated` (Rosetta)
+   */
+  downloadJava(
+    version: number,
+    options?: { allowEmulated?: boolean },
+  ): Promise<JavaInstallation>;
+  cancelJavaDownload(version: number): Promise<void>;
+  /**
+   * Compare the login shell's PATH java and JAVA_HOME with the Java the
+   * instance launches with, for "works in the terminal" reports
+   */
+  diagnoseJava(instanceId: string): Promise<JavaDiagnosis>;
+  /** Manual fallback when downloadJava can't reach Adoptium */
+  openJavaDownloadPage(version: number): Promise<void>;
+  /** Run the binary once to fill OS caches; call in the background */
+  warmJava(javaPath: string): Promise<JavaWarmupResult>;
 }
 
 declare global {
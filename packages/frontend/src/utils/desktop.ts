@@ -3,9 +3,13 @@ export function isDesktop(): boolean {
 }
 
 export async function getBackendBaseUrl(): Promise<string> {
-  return isDesktop() ? "http://localhost:3001" : "";
+  if (!isDesktop()) return "";
+  const port = await window.electronAPI!.getBackendPort();
+  return `http://localhost:${port}`;
 }
 
 export function getBackendBaseUrlSync(): string {
-  return isDesktop() ? "http://localhost:3001" : "";
+  return isDesktop()
+    ? `http://localhost:${window.electronAPI!.backendPort}`
+    : "";
 }
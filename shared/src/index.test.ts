@@ -4,6 +4,9 @@ import {
   getJavaMajorVersion,
   checkJavaMcCompat,
   MC_JAVA_COMPAT,
+  LAUNCH_ERROR_PREFIX,
+  decodeLaunchError,
+  summarizeMcVersions,
 } from "./index.js";
 
 describe("compareMcVersions", () => {
@@ -171,3 +174,47 @@ describe("MC_JAVA_COMPAT constant", () => {
     expect(MC_JAVA_COMPAT[2].label).toBe("Java 8+");
   });
 });
+
+describe("decodeLaunchError", () => {
+  const info = {
+    code: "java_not_found" as const,
+    message: "Java 21 not found",
+    stage: "resolving_java" as const,
+  };
+
+  it("finds the payload in an Electron-wrapped message", () => {
+    const encoded = LAUNCH_ERROR_PREFIX + JSON.stringify(info);
+    const wrapped = `Error invoking remote method 'launch-game': Error: ${encoded}`;
+    expect(decodeLaunchError(wrapped)).toEqual(info);
+  });
+
+  it("returns null for plain messages", () => {
+    expect(decodeLaunchError("Something broke")).toBeNull();
+  });
+
+  it("returns null for a corrupt payload", () => {
+    expect(decodeLaunchError("LAUNCH_ERROR:{not json")).toBeNull();
+  });
+});
+
+describe("summarizeMcVersions", () => {
+  const versions = [
+    { id: "24w14a", type: "snapshot" as const, releaseTime: "2024-04-03" },
+    { id: "1.20.4", type: "release" as const, releaseTime: "2023-12-07" },
+    { id: "b1.7.3", type: "old_beta" as const, releaseTime: "2011-07-08" },
+  ];
+
+  it("keeps only releases without snapshots", () => {
+    expect(summarizeMcVersions(versions, false)).toEqual([
+      { id: "1.20.4", type: "release", releaseTime: "2023-12-07" },
+    ]);
+  });
+
+  it("keeps every type, in order, with snapshots", () => {
+    expect(summarizeMcVersions(versions, true).map((v) => v.id)).toEqual([
+      "24w14a",
+      "1.20.4",
+      "b1.7.3",
+    ]);
+  });
+});
@@ -26,6 +26,8 @@ export interface Server {
   jvmArgs: string;
   port: number;
   autoStart: boolean;
+  /** Relaunch automatically (with capped retries) after a crash */
+  autoRestart: boolean;
   createdAt: string;
   updatedAt: string;
 }
@@ -54,6 +56,7 @@ export interface UpdateServerRequest {
   jvmArgs?: string;
   javaPath?: string;
   autoStart?: boolean;
+  autoRestart?: boolean;
   jarPath?: string;
 }
 
@@ -91,6 +94,15 @@ export interface WsConsoleLine extends WsMessage {
   timestamp: string;
 }
 
+/** Console lines coalesced over a short window (see ConsoleBatcher) */
+export interface WsConsoleBatch extends WsMessage {
+  type: "console:batch";
+  serverId: string;
+  lines: Array<{ line: string; timestamp: string }>;
+  /** Lines dropped because the server outran the queue; a marker line is included */
+  dropped: number;
+}
+
 export interface WsConsoleHistory extends WsMessage {
   type: "console:history";
   serverId: string;
@@ -134,22 +146,64 @@ export interface WsModpackProgress extends WsMessage {
   error?: string;
 }
 
+/** Likely cause of a server crash, derived from the tail of its console output */
+export type ServerCrashCause =
+  | "out_of_memory"
+  | "port_in_use"
+  | "corrupt_world"
+  | "unknown";
+
+/** Sent when a server process exits with a failure outside of an intentional stop */
+export interface WsServerExit extends WsMessage {
+  type: "server-exit";
+  serverId: string;
+  exitCode: number | null;
+  signal: string | null;
+  cause: ServerCrashCause;
+  /** True if an auto-restart has been scheduled for this crash */
+  restartScheduled: boolean;
+  /** 1-based attempt number within the current retry window, if scheduled */
+  restartAttempt: number | null;
+}
+
+/** Sent when a graceful stop timed out and the server had to be killed */
+export interface WsServerForceKilled extends WsMessage {
+  type: "server-force-killed";
+  serverId: string;
+  /** Grace period the server was given before escalation */
+  timeoutSecs: number;
+}
+
 export type WsServerMessage =
   | WsConsoleLine
+  | WsConsoleBatch
   | WsConsoleHistory
   | WsStatusChange
   | WsStats
   | WsCommandAck
   | WsError
   | WsModpackProgress
-  | WsModpackUpdateAvailable;
+  | WsModpackUpdateAvailable
+  | WsServerExit
+  | WsServerForceKilled;
 
 // --- System ---
 
+/** Why a specific Java path failed validation */
+export type JavaProbeError =
+  | "not_found"
+  | "not_executable"
+  | "nonzero_exit"
+  | "unparseable";
+
 export interface JavaInfo {
   found: boolean;
   path: string | null;
   version: string | null;
+  /** Set by path validation when `found` is false */
+  error?: JavaProbeError;
+  /** Human-readable explanation with a suggested fix */
+  errorMessage?: string;
 }
 
 export interface SystemInfo {
@@ -160,6 +214,22 @@ export interface SystemInfo {
   cpus: number;
 }
 
+/** Suggested heap sizes for a new instance (GET /api/system/ram-recommendation) */
+export interface RamRecommendation {
+  minGb: number;
+  maxGb: number;
+  totalGb: number;
+  /** Less memory is free right now than the recommended max */
+  constrained: boolean;
+}
+
+/** Response for GET /api/system/disk-space */
+export interface DiskSpaceInfo {
+  /** Directory whose volume was queried (the app data dir) */
+  path: string;
+  freeBytes: number;
+}
+
 // --- Versions / Downloads ---
 
 export interface McVersion {
@@ -364,6 +434,22 @@ export function checkJavaMcCompat(
   return null;
 }
 
+/** A `-javaagent` attached at launch, e.g. a profiler or mod dev tool */
+export interface JavaAgent {
+  /** Absolute path to the agent jar, used as written (no placeholders) */
+  path: string;
+  /** Passed to the agent after `=`; may use launch arg placeholders */
+  options: string;
+}
+
+/** A named set of JVM flags for launcher instances (RAM comes from ramMin/ramMax) */
+export interface JvmArgsPreset {
+  name: string;
+  args: string[];
+  /** Bundled with the app; can't be overwritten or deleted */
+  builtIn: boolean;
+}
+
 // --- Server Properties ---
 
 export type PropertyType = "string" | "number" | "boolean" | "select";
@@ -400,6 +486,13 @@ export interface ServerPropertiesResponse {
   serverRunning: boolean;
 }
 
+/** A rejected value from PUT /api/servers/:id/properties (returned as `details`) */
+export interface PropertyValidationIssue {
+  key: string;
+  value: string;
+  message: string;
+}
+
 /** Request body for PUT /api/servers/:id/properties */
 export interface UpdateServerPropertiesRequest {
   properties: Record<string, string>;
@@ -458,6 +551,12 @@ export interface AppSettings {
   curseforgeApiKey: string;
   /** When true, modpack install shows file-by-file override preview */
   showOverridePreview: boolean;
+  /** Desktop app only: show native notifications (e.g. sign-in finished) */
+  desktopNotifications: boolean;
+  /** Desktop app only: max games running at once (0 = unlimited) */
+  maxConcurrentGames: number;
+  /** Desktop app only: notify when a game or server crashes */
+  crashNotifications: boolean;
 }
 
 // --- Mod Management ---
@@ -786,6 +885,44 @@ export type LoaderType = ModLoader | "quilt";
 
 export type VersionType = "release" | "snapshot" | "old_beta" | "old_alpha";
 
+/** A Minecraft version as offered when creating an instance */
+export interface McVersionSummary {
+  id: string;
+  type: VersionType;
+  releaseTime: string;
+}
+
+/**
+ * Trim manifest entries down to what the instance editor shows. Without
+ * snapshots only full releases are kept; with them, every type is (including
+ * old betas and alphas). Manifest order (newest first) is preserved.
+ */
+export function summarizeMcVersions(
+  versions: ReadonlyArray<
+    Pick<MojangVersionEntry, "id" | "type" | "releaseTime">
+  >,
+  includeSnapshots: boolean,
+): McVersionSummary[] {
+  return versions
+    .filter((v) => includeSnapshots || v.type === "release")
+    .map((v) => ({ id: v.id, type: v.type, releaseTime: v.releaseTime }));
+}
+
+/** Which GPU a launched game should use on hybrid-graphics machines */
+export type GpuPreference = "integrated" | "discrete";
+
+/**
+ * Where an instance's downloaded game files live. Every instance has its
+ * own game directory (saves, mods, options) either way.
+ * - `shared`: assets, libraries and natives are shared by all instances, so
+ *   each Minecraft version's files are downloaded once (roughly 0.5–1 GB).
+ * - `isolated`: the instance gets its own copies under
+ *   `launcher/isolated/<id>`, for setups that conflict with the shared
+ *   files (patched libraries, modified asset indexes). Each isolated
+ *   instance re-downloads everything and costs that disk space again.
+ */
+export type InstanceIsolation = "shared" | "isolated";
+
 export interface LauncherInstance {
   id: string;
   name: string;
@@ -801,13 +938,38 @@ export interface LauncherInstance {
   resolutionHeight: number | null;
   jvmArgs: string[];
   gameArgs: string[];
+  javaAgents: JavaAgent[];
+  /** Directories put in front of the game's PATH, for native libraries */
+  pathPrepend: string[];
   icon: string | null;
   lastPlayed: string | null;
   totalPlaytime: number;
+  /** Relaunch automatically (desktop app) after a crash, with a retry cap */
+  restartOnCrash: boolean;
+  /** Null lets the OS decide. Desktop app only; ignored on macOS */
+  gpuPreference: GpuPreference | null;
+  isolation: InstanceIsolation;
   createdAt: string;
   updatedAt: string;
 }
 
+/** Enough of an instance for menus (tray quick launch) and bulk checks */
+export interface InstanceSummary {
+  id: string;
+  name: string;
+  mcVersion: string;
+  loader: LoaderType | null;
+  lastPlayed: string | null;
+}
+
+/** What an instance's launch would pass to the game, for the editor */
+export interface GameArgsPreview {
+  /** Full game args with the access token redacted */
+  args: string[];
+  /** `{name}` placeholders in the instance's game args left unsubstituted */
+  unknownPlaceholders: string[];
+}
+
 export interface CreateInstanceRequest {
   name: string;
   mcVersion: string;
@@ -826,8 +988,13 @@ export interface UpdateInstanceRequest {
   resolutionHeight?: number | null;
   jvmArgs?: string[];
   gameArgs?: string[];
+  javaAgents?: JavaAgent[];
+  pathPrepend?: string[];
   icon?: string | null;
   javaPath?: string | null;
+  restartOnCrash?: boolean;
+  gpuPreference?: GpuPreference | null;
+  isolation?: InstanceIsolation;
 }
 
 export interface LauncherAccount {
@@ -839,6 +1006,57 @@ export interface LauncherAccount {
   createdAt: string;
 }
 
+/** Result of importing the official launcher's accounts */
+export interface VanillaAccountImportResult {
+  imported: LauncherAccount[];
+  /** Accounts (imported or existing) with no usable tokens here yet */
+  needsReauth: LauncherAccount[];
+  /** Already in our account list — left untouched */
+  existing: LauncherAccount[];
+  /** Entries without a usable Java profile */
+  skipped: Array<{ name: string; reason: string }>;
+}
+
+/** Result of round-tripping a test secret through desktop credential storage */
+export interface CredentialStorageStatus {
+  /** Secrets are encrypted by the OS and read back correctly */
+  available: boolean;
+  /** "keychain", "dpapi", or on Linux e.g. "gnome_libsecret", "basic_text" */
+  backend: string;
+  error: string | null;
+}
+
+// --- Stored credential checks ---
+
+/** What repairAccount found and did about an account's stored tokens */
+export interface AccountRepairResult {
+  /**
+   * "healthy": nothing was wrong. "repaired": a bad access token was
+   * cleared and the next launch refreshes it. "signed_out": the refresh
+   * token was bad (or missing), so everything was cleared and the account
+   * needs signing in again.
+   */
+  status: "healthy" | "repaired" | "signed_out";
+  /** Secure-storage keys that were deleted */
+  cleared: string[];
+}
+
+// --- Config backup (desktop) ---
+
+/** Result of restoring a config backup */
+export interface ConfigImportResult {
+  settingsApplied: boolean;
+  accountsImported: LauncherAccount[];
+  /** Already in our account list — left untouched */
+  accountsExisting: LauncherAccount[];
+  instancesCreated: LauncherInstance[];
+  /** Matched an existing instance and overwrote its settings */
+  instancesUpdated: LauncherInstance[];
+  jvmPresets: string[];
+  /** Accounts need a fresh sign-in; backups never carry tokens */
+  needsReauth: LauncherAccount[];
+}
+
 /** Launcher-side version entry (subset of MojangVersionEntry, no complianceLevel) */
 export type MinecraftVersion = Omit<MojangVersionEntry, "complianceLevel">;
 
@@ -855,6 +1073,51 @@ export interface JavaInstallation {
   path: string;
   vendor: string;
   fullVersion: string;
+  /** Set by a download that fell back to x64 on an ARM machine */
+  emulated?: boolean;
+  /** The emulated build runs under Rosetta (macOS on Apple Silicon) */
+  translated?: boolean;
+  /** User-facing explanation accompanying `emulated` */
+  note?: string;
+}
+
+/** Where a Java binary in a JavaDiagnosis was found */
+export type JavaSource = "path" | "java_home" | "instance";
+
+export interface JavaCandidate {
+  source: JavaSource;
+  /** Binary as found; null when this source has no Java */
+  path: string | null;
+  /** `path` with symlinks resolved, used to compare candidates */
+  realPath: string | null;
+  /** Full version from `java -version`; null if it couldn't be read */
+  version: string | null;
+  error: string | null;
+}
+
+/** PATH vs JAVA_HOME vs an instance's Java, as seen from a login shell */
+export interface JavaDiagnosis {
+  instanceId: string;
+  /** In order: PATH, JAVA_HOME, instance */
+  candidates: JavaCandidate[];
+  /** Every Java that was found is the same binary */
+  agree: boolean;
+  /** All found share a major version, even if they're different binaries */
+  sameMajorVersion: boolean;
+  summary: string;
+}
+
+/** A Java feature version Adoptium currently offers for download */
+export interface AvailableJavaVersion {
+  version: number;
+  lts: boolean;
+}
+
+/** Result of running a Java binary once to page it into OS caches */
+export interface JavaWarmupResult {
+  path: string;
+  /** Wall-clock time of `java -version`, in ms */
+  coldStartMs: number;
 }
 
 export interface MSAuthDeviceCode {
@@ -865,21 +1128,387 @@ export interface MSAuthDeviceCode {
   interval: number;
 }
 
+/** Categories the desktop `clear-cache` call can remove */
+export type CacheCategory = "natives" | "logs" | "partial_downloads" | "runtimes";
+
+export interface CacheCategoryResult {
+  category: CacheCategory;
+  bytesFreed: number;
+  removed: number;
+  /** Entries left in place because they're in use (running game, live log, active download) */
+  skipped: string[];
+}
+
+export interface CacheClearResult {
+  categories: CacheCategoryResult[];
+  totalBytesFreed: number;
+}
+
+/** Desktop app version and build metadata */
+export interface AppInfo {
+  version: string;
+  /** Git commit baked in at build time (null for unbuilt/dev trees) */
+  commit: string | null;
+  builtAt: string | null;
+  /** `${platform}-${arch}`, e.g. "win32-x64" */
+  target: string;
+  nodeVersion: string;
+  electronVersion: string;
+  packaged: boolean;
+}
+
+export type ShutdownPhase = "servers" | "games" | "backend";
+
+export interface ShutdownPhaseResult {
+  phase: ShutdownPhase;
+  status: "done" | "skipped" | "timed_out" | "failed";
+  durationMs: number;
+  detail?: string;
+}
+
+/** How one server ended up after a stop-all */
+export interface ServerStopOutcome {
+  serverId: string;
+  name: string;
+  /** "killed": didn't stop in time, or a non-graceful stop was asked for */
+  outcome: "stopped" | "killed" | "failed";
+  durationMs: number;
+  error?: string;
+}
+
+/** What the ordered app shutdown did, phase by phase */
+export interface ShutdownReport {
+  phases: ShutdownPhaseResult[];
+}
+
+export type RconErrorCode =
+  | "connection_refused"
+  | "connection_failed"
+  | "auth_failed"
+  | "timeout";
+
+/** Result of a one-off RCON connection test (nothing is stored) */
+export interface RconTestResult {
+  connected: boolean;
+  authenticated: boolean;
+  /** Connect + auth round trip, in ms */
+  latencyMs: number | null;
+  /** Output of the harmless `list` command, when requested and successful */
+  listResponse: string | null;
+  errorCode: RconErrorCode | null;
+  error: string | null;
+}
+
+/** A Java Edition player, as resolved from a username by Mojang */
+export interface PlayerLookup {
+  /** Dashed UUID, as used in whitelist.json and ops.json */
+  uuid: string;
+  /** Name with Mojang's casing, which may differ from what was typed */
+  name: string;
+}
+
+/** Full stat from a server's UDP Query (GS4) endpoint (`enable-query=true`) */
+export interface QueryResponse {
+  motd: string;
+  gameType: string;
+  version: string;
+  /** Server software from the plugins field, e.g. "Paper on Bukkit 1.20.4" */
+  serverMod: string | null;
+  plugins: string[];
+  map: string;
+  numPlayers: number;
+  maxPlayers: number;
+  hostPort: number;
+  hostIp: string;
+  players: string[];
+  latencyMs: number;
+}
+
+/** Incremental read of a log file for polling viewers (desktop `tail-log`) */
+export interface LogChunk {
+  /** Text appended since the requested offset (UTF-8) */
+  data: string;
+  /** Pass back as `fromOffset` on the next poll */
+  nextOffset: number;
+  /** Identifies the file on disk; pass back so rotation is detected */
+  fileId: string;
+  /** The file was truncated or replaced — `data` starts from the beginning */
+  reset: boolean;
+}
+
+/** Result of probing the backend's /api/health endpoint */
+export interface BackendHealth {
+  reachable: boolean;
+  /**
+   * Whether the in-process backend is running. null in development, where
+   * the backend is a separate process the app doesn't manage.
+   */
+  processRunning: boolean | null;
+  port: number;
+  latencyMs: number | null;
+  version: string | null;
+  /** Why the backend was unreachable */
+  error: string | null;
+}
+
+/** Outcome of the desktop `reload-backend-config` call */
+export interface BackendReloadResult {
+  /** "restart" when the backend couldn't reload in place */
+  method: "reload" | "restart";
+  /** What was re-read */
+  reloaded: string[];
+  warning?: string;
+}
+
+/** Why the backend last failed to start, with the end of its log */
+export interface BackendStartupLog {
+  /** Null if the backend hasn't failed to start this session */
+  error: string | null;
+  failedAt: string | null;
+  /** Newest backend log file, or null if there isn't one yet */
+  logFile: string | null;
+  /** The end of that file */
+  contents: string;
+  /** Whether earlier parts of the file were left out */
+  truncated: boolean;
+}
+
 export interface MSAuthStatus {
   status: "pending" | "complete" | "expired" | "error" | "slow_down";
   account?: LauncherAccount;
   error?: string;
 }
 
+/** A pre-flight problem found by the desktop `validate-instance` call */
+export interface InstanceIssue {
+  /** "error" blocks launching; "warning" is advisory */
+  severity: "error" | "warning";
+  code:
+    | "java_not_found"
+    | "java_rosetta"
+    | "java_agent_missing"
+    | "path_dir_missing"
+    | "ram_exceeds_system"
+    | "ram_high"
+    | "ram_min_exceeds_max"
+    | "account_not_found"
+    | "account_signed_out"
+    | "token_expired";
+  message: string;
+}
+
+/** Desktop launch steps, in order, reported via the `launch-progress` event */
+export type LaunchStage =
+  | "fetching_instance"
+  | "resolving_java"
+  | "creating_natives"
+  | "spawning"
+  | "running";
+
+/** Where a desktop launch spent its time, for tuning slow launches */
+export interface LaunchTimings {
+  instanceId: string;
+  startedAt: string;
+  /** Backend prepare as measured by the renderer; null if not reported */
+  prepareMs: number | null;
+  /** Stages in the order they ran; a failed launch ends at the failing one */
+  stages: { stage: LaunchStage; ms: number }[];
+  /** prepareMs plus every stage */
+  totalMs: number;
+  /** False when the launch failed or was cancelled */
+  succeeded: boolean;
+}
+
+export interface LaunchProgressEvent {
+  instanceId: string;
+  stage: LaunchStage;
+}
+
+export interface LaunchFailedEvent {
+  instanceId: string;
+  /** Stage that was in progress when the launch failed */
+  stage: LaunchStage;
+  code: LaunchErrorCode;
+  error: string;
+}
+
+/** Machine-readable reason a desktop launcher call failed */
+export type LaunchErrorCode =
+  | "backend_unreachable"
+  | "account_not_found"
+  | "token_expired"
+  | "java_not_found"
+  | "java_agent_missing"
+  | "path_dir_missing"
+  | "already_running"
+  | "too_many_running"
+  | "spawn_failed"
+  | "not_running"
+  | "cancelled"
+  | "saving"
+  | "unsupported"
+  | "unknown";
+
+/** Structured error from launchGame / killGame / resolveJavaPath */
+export interface LaunchErrorInfo {
+  code: LaunchErrorCode;
+  /** Human-readable, safe to show as-is */
+  message: string;
+  /** Launch stage in progress, when the error came from a launch */
+  stage: LaunchStage | null;
+}
+
+/**
+ * Electron only carries an error's message across IPC, so structured
+ * launcher errors travel as this prefix plus JSON inside it.
+ */
+export const LAUNCH_ERROR_PREFIX = "LAUNCH_ERROR:";
+
+/**
+ * Recover a LaunchErrorInfo from an IPC error message (Electron prepends
+ * its own "Error invoking remote method …" text). Null if there is none.
+ */
+export function decodeLaunchError(message: string): LaunchErrorInfo | null {
+  const start = message.indexOf(LAUNCH_ERROR_PREFIX);
+  if (start === -1) return null;
+  try {
+    const info = JSON.parse(
+      message.slice(start + LAUNCH_ERROR_PREFIX.length),
+    ) as LaunchErrorInfo;
+    return typeof info.code === "string" && typeof info.message === "string"
+      ? info
+      : null;
+  } catch {
+    return null;
+  }
+}
+
+/** GameProcess plus derived state, for dashboards (desktop `get-running-games-detailed`) */
+export interface RunningGameDetails extends GameProcess {
+  accountId: string;
+  username: string;
+  uptimeMs: number;
+  /** Resident memory of the game process; null if it couldn't be read */
+  memoryBytes: number | null;
+}
+
+/** Payload of the desktop `game-ready` event */
+export interface GameReadyEvent {
+  instanceId: string;
+}
+
+/** Payload of the desktop `game-log` event, sent only to log windows */
+export interface GameLogEvent {
+  instanceId: string;
+  /** Output lines since the previous event, oldest first */
+  lines: string[];
+}
+
+/** Payload of the desktop `game-crashed` event (exit not caused by a stop) */
+export interface GameCrashedEvent {
+  instanceId: string;
+  exitCode: number | null;
+  signal: string | null;
+  /** True if a restart-on-crash relaunch has been scheduled */
+  restartScheduled: boolean;
+  /** 1-based attempt number within the current retry window, if scheduled */
+  restartAttempt: number | null;
+}
+
+/** Payload of the desktop `game-force-killed` event */
+export interface GameForceKilledEvent {
+  instanceId: string;
+  pid: number;
+  timeoutSecs: number;
+}
+
+/** Payload of the desktop `account-refresh-failed` event */
+export interface AccountRefreshFailedEvent {
+  accountUuid: string;
+  username: string;
+  error: string;
+}
+
+/** Game is alive but hasn't reached a readiness marker within the watchdog delay */
+export interface LaunchStalledEvent {
+  instanceId: string;
+  elapsedMs: number;
+}
+
 export interface LaunchGameRequest {
   instanceId: string;
   accountId: string;
 }
 
+/** On-disk locations the desktop launcher uses for an instance */
+export interface InstancePaths {
+  /** Working directory: saves, options, mods, screenshots */
+  gameDir: string;
+  assetsDir: string;
+  /** Per-launch natives are extracted into subfolders of this */
+  nativesBase: string;
+  logsDir: string;
+}
+
+// --- World saves ---
+
+export type WorldGameMode = "survival" | "creative" | "adventure" | "spectator";
+
+/** A singleplayer world in an instance's `saves` folder */
+export interface WorldInfo {
+  /** Folder name under saves/ — what quick play and backups refer to */
+  folder: string;
+  path: string;
+  /** Display name from level.dat; the folder name when unreadable */
+  name: string;
+  lastPlayed: string | null;
+  gameMode: WorldGameMode | null;
+  hardcore: boolean;
+  /** Minecraft version that last saved the world, e.g. "1.21.4" */
+  version: string | null;
+  /** level.dat couldn't be read; the other fields may be partial */
+  damaged: boolean;
+  error?: string;
+}
+
+/** level.dat fields shown in the world browser */
+export type LevelDatSummary = Pick<
+  WorldInfo,
+  "name" | "lastPlayed" | "gameMode" | "hardcore" | "version"
+>;
+
+// --- level.dat editing ---
+
+export type WorldDifficulty = "peaceful" | "easy" | "normal" | "hard";
+
+/** The level.dat fields shown in the world editor. */
+export interface LevelDatFields extends LevelDatSummary {
+  difficulty: WorldDifficulty | null;
+  allowCommands: boolean;
+  /** Read-only; decimal string because seeds are 64-bit */
+  seed: string | null;
+}
+
+/** The fields that are safe to change outside the game. */
+export interface LevelDatChanges {
+  name?: string;
+  gameMode?: WorldGameMode;
+  difficulty?: WorldDifficulty;
+  allowCommands?: boolean;
+}
+
 export interface GameProcess {
   instanceId: string;
   pid: number;
   startedAt: string;
+  /** Exact argv used to spawn the game (java binary first), access token redacted */
+  command: string[];
+  /** True once the client logged a startup marker (in-game, not just JVM up) */
+  ready: boolean;
+  /** Set when a forced second launch shares the game dir with a running copy */
+  warning?: string;
+  /** Frozen with suspendGame (SIGSTOP); resumeGame continues it */
+  suspended: boolean;
 }
 
 export type PreparePhase =
@@ -917,6 +1546,28 @@ export interface PrepareResponse {
   nativesDir: string;
 }
 
+/** A jar on an instance's effective classpath */
+export interface ClasspathEntry {
+  path: string;
+  exists: boolean;
+}
+
+/** A file or directory checked by the desktop `verify-instance-files` call */
+export interface VerifyItem {
+  kind: "game_jar" | "library" | "asset_index" | "natives";
+  path: string;
+  status: "ok" | "missing" | "empty" | "corrupt";
+  /** Why a non-ok item failed, when there's more to say than the status */
+  detail?: string;
+}
+
+export interface VerifyReport {
+  instanceId: string;
+  /** True when every item is ok */
+  ok: boolean;
+  items: VerifyItem[];
+}
+
 // ============================================================
 // Multi-User Auth Types (Epic 5)
 // ============================================================
@@ -2,6 +2,11 @@ import { defineConfig } from "vitest/config";
 
 export default defineConfig({
   test: {
-    projects: ["shared", "packages/backend", "packages/frontend"],
+    projects: [
+      "shared",
+      "packages/backend",
+      "packages/frontend",
+      "packages/electron",
+    ],
   },
 });